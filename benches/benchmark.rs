@@ -12,6 +12,8 @@ static LARGE_COLLECTION_SIZE: usize = 50;
 static SELECTION_SIZE: usize = 10;
 static SELECTION_OFFSET: usize = 20;
 static TUPLE_SIZE: usize = 3;
+static MULTISET_COLLECTION_SIZE: usize = 100_000;
+static MULTISET_SELECTION_SIZE: usize = 1_000;
 
 pub fn cartesian_product(c: &mut Criterion) {
   let data = combinations_input();
@@ -49,6 +51,25 @@ pub fn delete_at_multi(c: &mut Criterion) {
   c.bench_function("delete_at_multi", |b| b.iter(|| black_box(data.clone()).delete_at_multi(indices.clone())));
 }
 
+pub fn delete_multi(c: &mut Criterion) {
+  let data = multiset_input();
+  let elements = multiset_selection();
+  let mut group = c.benchmark_group("delete_multi");
+  group.bench_function("position_remove", |b| {
+    b.iter(|| {
+      let mut result = black_box(data.clone());
+      for element in &elements {
+        if let Some(index) = result.iter().position(|x| x == element) {
+          result.remove(index);
+        }
+      }
+      result
+    });
+  });
+  group.bench_function("counted_retain", |b| b.iter(|| black_box(data.clone()).delete_multi(&elements)));
+  group.finish();
+}
+
 #[allow(clippy::cast_possible_wrap)]
 pub fn intersect(c: &mut Criterion) {
   let data = collection_input();
@@ -61,6 +82,21 @@ pub fn joined(c: &mut Criterion) {
   c.bench_function("joined", |b| b.iter(|| black_box(data.clone()).joined(", ")));
 }
 
+pub fn largest_by_key(c: &mut Criterion) {
+  let data = scrambled_input();
+  let mut group = c.benchmark_group("largest_by_key");
+  group.bench_function("sorted_take", |b| {
+    b.iter(|| {
+      let mut result = black_box(data.clone());
+      result.sort_by_key(|x| -x);
+      result.truncate(SELECTION_SIZE);
+      result
+    });
+  });
+  group.bench_function("bounded_heap", |b| b.iter(|| black_box(data.clone()).largest_by_key(SELECTION_SIZE, |&x| x)));
+  group.finish();
+}
+
 pub fn powerset(c: &mut Criterion) {
   let data = combinations_input();
   c.bench_function("powerset", |b| {
@@ -120,8 +156,25 @@ fn combinations_input() -> Vec<i64> {
   (0..SMALL_COLLECTION_SIZE as i64).collect()
 }
 
+#[allow(clippy::cast_possible_wrap)]
+fn multiset_input() -> Vec<i64> {
+  (0..MULTISET_COLLECTION_SIZE as i64).map(|x| x % (MULTISET_COLLECTION_SIZE as i64 / 2)).collect()
+}
+
+#[allow(clippy::cast_possible_wrap)]
+fn multiset_selection() -> Vec<i64> {
+  let step = (MULTISET_COLLECTION_SIZE / MULTISET_SELECTION_SIZE) as i64;
+  (0..MULTISET_SELECTION_SIZE as i64).map(|x| x * step).collect()
+}
+
+#[allow(clippy::cast_possible_wrap)]
+fn scrambled_input() -> Vec<i64> {
+  let size = MULTISET_COLLECTION_SIZE as i64;
+  (0..size).map(|x| (x * 7_919) % size).collect()
+}
+
 criterion_group!(
-  benches, cartesian_product, combinations, combinations_multi, delete_at_multi, intersect, joined, powerset,
-  substitute_multi, variations, windowed, windowed_circular,
+  benches, cartesian_product, combinations, combinations_multi, delete_at_multi, delete_multi, intersect, joined,
+  largest_by_key, powerset, substitute_multi, variations, windowed, windowed_circular,
 );
 criterion_main!(benches);
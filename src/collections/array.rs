@@ -0,0 +1,171 @@
+use std::cmp::Ordering;
+use std::hash::Hash;
+
+use crate::Iterable;
+#[allow(clippy::wildcard_imports)]
+use crate::extensions::*;
+
+impl<Item, const N: usize> Collection<Item> for [Item; N] {}
+
+impl<Item, const N: usize> Sequence<Item> for [Item; N] {
+  #[inline]
+  fn common_prefix_length<'a>(&'a self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> usize
+  where
+    Item: PartialEq + 'a,
+  {
+    common_prefix_length(self.iter(), elements)
+  }
+
+  #[inline]
+  fn common_suffix_length<'a, I>(&'a self, elements: &'a impl Iterable<Item<'a> = &'a Item, Iterator<'a> = I>) -> usize
+  where
+    I: DoubleEndedIterator<Item = &'a Item>,
+    Item: PartialEq + 'a,
+  {
+    common_suffix_length(self.iter().rev(), elements)
+  }
+
+  #[inline]
+  fn equivalent<'a>(&'a self, iterable: &'a impl Iterable<Item<'a> = &'a Item>) -> bool
+  where
+    Item: Eq + Hash + 'a,
+  {
+    equivalent(self.iter(), iterable)
+  }
+
+  #[inline]
+  fn lower_bound(&self, element: &Item) -> usize
+  where
+    Item: Ord,
+  {
+    self.partition_point(|x| x < element)
+  }
+
+  #[inline]
+  fn lower_bound_by_key<K: Ord>(&self, key: &K, mut to_key: impl FnMut(&Item) -> K) -> usize {
+    self.partition_point(|x| to_key(x) < *key)
+  }
+
+  #[inline]
+  fn position_sequence<'a>(&'a self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> Option<usize>
+  where
+    Item: PartialEq + 'a,
+  {
+    position_sequence(self.iter(), elements)
+  }
+
+  #[inline]
+  fn rfind(&self, mut predicate: impl FnMut(&Item) -> bool) -> Option<&Item> {
+    self.iter().rev().find(|&x| predicate(x))
+  }
+
+  #[inline]
+  fn rfold_ref<B>(&self, initial_value: B, function: impl FnMut(B, &Item) -> B) -> B {
+    self.iter().rfold(initial_value, function)
+  }
+
+  #[inline]
+  fn rposition(&self, predicate: impl FnMut(&Item) -> bool) -> Option<usize> {
+    self.iter().rposition(predicate)
+  }
+
+  #[inline]
+  fn rposition_sequence<'a>(&'a self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> Option<usize>
+  where
+    Item: PartialEq + 'a,
+  {
+    rposition_sequence(self.iter().rev(), self.len(), elements)
+  }
+
+  #[inline]
+  fn upper_bound(&self, element: &Item) -> usize
+  where
+    Item: Ord,
+  {
+    self.partition_point(|x| x <= element)
+  }
+
+  #[inline]
+  fn upper_bound_by_key<K: Ord>(&self, key: &K, mut to_key: impl FnMut(&Item) -> K) -> usize {
+    self.partition_point(|x| to_key(x) <= *key)
+  }
+}
+
+impl<Item, const N: usize> Array<Item, N> for [Item; N] {
+  #[inline]
+  fn map_ref<B>(&self, function: impl FnMut(&Item) -> B) -> [B; N] {
+    self.each_ref().map(function)
+  }
+
+  #[inline]
+  fn rev(mut self) -> Self {
+    self.reverse();
+    self
+  }
+
+  #[inline]
+  fn scan<S, B>(self, initial_state: S, mut function: impl FnMut(&mut S, Item) -> B) -> [B; N] {
+    let mut state = initial_state;
+    self.map(|x| function(&mut state, x))
+  }
+
+  #[inline]
+  fn scan_ref<S, B>(&self, initial_state: S, mut function: impl FnMut(&mut S, &Item) -> B) -> [B; N] {
+    let mut state = initial_state;
+    self.each_ref().map(|x| function(&mut state, x))
+  }
+
+  #[inline]
+  fn sorted(mut self) -> Self
+  where
+    Item: Ord,
+  {
+    self.sort();
+    self
+  }
+
+  #[inline]
+  fn sorted_by(mut self, compare: impl FnMut(&Item, &Item) -> Ordering) -> Self {
+    self.sort_by(compare);
+    self
+  }
+
+  #[inline]
+  fn sorted_by_key<K: Ord>(mut self, to_key: impl FnMut(&Item) -> K) -> Self {
+    self.sort_by_key(to_key);
+    self
+  }
+
+  #[inline]
+  fn sorted_unstable(mut self) -> Self
+  where
+    Item: Ord,
+  {
+    self.sort_unstable();
+    self
+  }
+
+  #[inline]
+  fn sorted_unstable_by(mut self, compare: impl FnMut(&Item, &Item) -> Ordering) -> Self {
+    self.sort_unstable_by(compare);
+    self
+  }
+
+  #[inline]
+  fn sorted_unstable_by_key<K: Ord>(mut self, to_key: impl FnMut(&Item) -> K) -> Self {
+    self.sort_unstable_by_key(to_key);
+    self
+  }
+
+  #[inline]
+  fn substitute_at(mut self, index: usize, replacement: Item) -> Self {
+    self[index] = replacement;
+    self
+  }
+
+  #[inline]
+  fn swap_at(mut self, source_index: usize, target_index: usize) -> Self {
+    self.swap(source_index, target_index);
+    self
+  }
+}
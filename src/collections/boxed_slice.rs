@@ -0,0 +1,247 @@
+use std::cmp::Ordering;
+use std::hash::Hash;
+
+use crate::Iterable;
+#[allow(clippy::wildcard_imports)]
+use crate::extensions::*;
+
+impl<Item> Collection<Item> for Box<[Item]> {}
+
+impl<Item> CollectionTo<Item> for Box<[Item]> {
+  type This<I> = Box<[I]>;
+
+  #[inline]
+  fn add(self, element: Item) -> Self
+  where
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+  {
+    self.into_vec().add(element).into_boxed_slice()
+  }
+
+  #[inline]
+  fn add_multi(self, elements: impl IntoIterator<Item = Item>) -> Self
+  where
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+  {
+    self.into_vec().add_multi(elements).into_boxed_slice()
+  }
+
+  #[inline]
+  fn delete(self, element: &Item) -> Self
+  where
+    Item: PartialEq,
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+  {
+    self.into_vec().delete(element).into_boxed_slice()
+  }
+
+  #[inline]
+  fn delete_multi<'a>(self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> Self
+  where
+    Item: Eq + Hash + 'a,
+    Self: FromIterator<Item>,
+  {
+    self.into_vec().delete_multi(elements).into_boxed_slice()
+  }
+
+  #[inline]
+  fn substitute(mut self, element: &Item, replacement: Item) -> Self
+  where
+    Item: PartialEq,
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+  {
+    if let Some(index) = self.iter().position(|x| x == element) {
+      self[index] = replacement;
+    }
+    self
+  }
+}
+
+impl<Item> Sequence<Item> for Box<[Item]> {
+  #[inline]
+  fn common_prefix_length<'a>(&'a self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> usize
+  where
+    Item: PartialEq + 'a,
+  {
+    common_prefix_length(self.iter(), elements)
+  }
+
+  #[inline]
+  fn common_suffix_length<'a, I>(&'a self, elements: &'a impl Iterable<Item<'a> = &'a Item, Iterator<'a> = I>) -> usize
+  where
+    I: DoubleEndedIterator<Item = &'a Item>,
+    Item: PartialEq + 'a,
+  {
+    common_suffix_length(self.iter().rev(), elements)
+  }
+
+  #[inline]
+  fn equivalent<'a>(&'a self, iterable: &'a impl Iterable<Item<'a> = &'a Item>) -> bool
+  where
+    Item: Eq + Hash + 'a,
+  {
+    equivalent(self.iter(), iterable)
+  }
+
+  #[inline]
+  fn lower_bound(&self, element: &Item) -> usize
+  where
+    Item: Ord,
+  {
+    self.partition_point(|x| x < element)
+  }
+
+  #[inline]
+  fn lower_bound_by_key<K: Ord>(&self, key: &K, mut to_key: impl FnMut(&Item) -> K) -> usize {
+    self.partition_point(|x| to_key(x) < *key)
+  }
+
+  #[inline]
+  fn position_sequence<'a>(&'a self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> Option<usize>
+  where
+    Item: PartialEq + 'a,
+  {
+    position_sequence(self.iter(), elements)
+  }
+
+  #[inline]
+  fn rfind(&self, mut predicate: impl FnMut(&Item) -> bool) -> Option<&Item> {
+    self.iter().rev().find(|&x| predicate(x))
+  }
+
+  #[inline]
+  fn rfold_ref<B>(&self, initial_value: B, function: impl FnMut(B, &Item) -> B) -> B {
+    self.iter().rfold(initial_value, function)
+  }
+
+  #[inline]
+  fn rposition(&self, predicate: impl FnMut(&Item) -> bool) -> Option<usize> {
+    self.iter().rposition(predicate)
+  }
+
+  #[inline]
+  fn rposition_sequence<'a>(&'a self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> Option<usize>
+  where
+    Item: PartialEq + 'a,
+  {
+    rposition_sequence(self.iter().rev(), self.len(), elements)
+  }
+
+  #[inline]
+  fn upper_bound(&self, element: &Item) -> usize
+  where
+    Item: Ord,
+  {
+    self.partition_point(|x| x <= element)
+  }
+
+  #[inline]
+  fn upper_bound_by_key<K: Ord>(&self, key: &K, mut to_key: impl FnMut(&Item) -> K) -> usize {
+    self.partition_point(|x| to_key(x) <= *key)
+  }
+}
+
+impl<Item> SequenceTo<Item> for Box<[Item]> {
+  type This<I> = Box<[I]>;
+
+  #[inline]
+  fn add_at(self, index: usize, element: Item) -> Self {
+    self.into_vec().add_at(index, element).into_boxed_slice()
+  }
+
+  #[inline]
+  fn add_at_multi(self, index: usize, elements: impl IntoIterator<Item = Item>) -> Self {
+    self.into_vec().add_at_multi(index, elements).into_boxed_slice()
+  }
+
+  #[inline]
+  fn delete_at(self, index: usize) -> Self {
+    self.into_vec().delete_at(index).into_boxed_slice()
+  }
+
+  #[inline]
+  fn delete_at_multi(self, indices: impl IntoIterator<Item = usize>) -> Self {
+    self.into_vec().delete_at_multi(indices).into_boxed_slice()
+  }
+
+  #[inline]
+  fn init(self) -> Self {
+    self.into_vec().init().into_boxed_slice()
+  }
+
+  #[inline]
+  fn move_at(self, source_index: usize, target_index: usize) -> Self {
+    self.into_vec().move_at(source_index, target_index).into_boxed_slice()
+  }
+
+  #[inline]
+  fn sorted(mut self) -> Self
+  where
+    Item: Ord,
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+  {
+    self.sort();
+    self
+  }
+
+  #[inline]
+  fn sorted_by(mut self, compare: impl FnMut(&Item, &Item) -> Ordering) -> Self
+  where
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+  {
+    self.sort_by(compare);
+    self
+  }
+
+  #[inline]
+  fn sorted_unstable(mut self) -> Self
+  where
+    Item: Ord,
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+  {
+    self.sort_unstable();
+    self
+  }
+
+  #[inline]
+  fn sorted_unstable_by(mut self, compare: impl FnMut(&Item, &Item) -> Ordering) -> Self
+  where
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+  {
+    self.sort_unstable_by(compare);
+    self
+  }
+
+  #[inline]
+  fn substitute_at(mut self, index: usize, replacement: Item) -> Self
+  where
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+  {
+    self[index] = replacement;
+    self
+  }
+
+  #[inline]
+  fn substitute_at_multi(
+    mut self, indices: impl IntoIterator<Item = usize>, replacements: impl IntoIterator<Item = Item>,
+  ) -> Self
+  where
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+  {
+    for (index, replacement) in indices.into_iter().zip(replacements) {
+      self[index] = replacement;
+    }
+    self
+  }
+
+  #[inline]
+  fn swap_at(mut self, source_index: usize, target_index: usize) -> Self {
+    self.swap(source_index, target_index);
+    self
+  }
+
+  #[inline]
+  fn tail(self) -> Self {
+    self.into_vec().tail().into_boxed_slice()
+  }
+}
@@ -1,13 +1,12 @@
 use std::collections::HashMap;
-use std::hash::Hash;
+use std::hash::{BuildHasher, Hash};
 
 use crate::Iterable;
 #[allow(clippy::wildcard_imports)]
 use crate::extensions::*;
 
-#[allow(clippy::implicit_hasher)]
-impl<Key: Eq + Hash, Value> Map<Key, Value> for HashMap<Key, Value> {
-  type This<X, V> = HashMap<X, V>;
+impl<Key: Eq + Hash, Value, S: BuildHasher + Default> Map<Key, Value> for HashMap<Key, Value, S> {
+  type This<X, V> = HashMap<X, V, S>;
 
   #[inline]
   fn add(mut self, key: Key, value: Value) -> Self
@@ -1,16 +1,14 @@
 use std::collections::{HashSet, LinkedList};
-use std::hash::Hash;
+use std::hash::{BuildHasher, Hash};
 
 use crate::Iterable;
 #[allow(clippy::wildcard_imports)]
 use crate::extensions::*;
 
-#[allow(clippy::implicit_hasher)]
-impl<Item> Collection<Item> for HashSet<Item> {}
+impl<Item, S> Collection<Item> for HashSet<Item, S> {}
 
-#[allow(clippy::implicit_hasher)]
-impl<Item: Eq + Hash> CollectionTo<Item> for HashSet<Item> {
-  type This<I> = HashSet<I>;
+impl<Item: Eq + Hash, S: BuildHasher + Default> CollectionTo<Item> for HashSet<Item, S> {
+  type This<I> = HashSet<I, S>;
 
   #[inline]
   fn add(mut self, element: Item) -> Self
@@ -1,7 +1,7 @@
 use std::cmp::Ordering;
 use std::collections::{BTreeMap, BTreeSet, LinkedList};
 use std::hash::Hash;
-use std::iter;
+use std::{iter, mem};
 
 use crate::Iterable;
 use crate::core::unfold::unfold;
@@ -80,6 +80,14 @@ impl<Item> Sequence<Item> for LinkedList<Item> {
   fn rposition(&self, predicate: impl FnMut(&Item) -> bool) -> Option<usize> {
     self.iter().rposition(predicate)
   }
+
+  #[inline]
+  fn rposition_sequence<'a>(&'a self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> Option<usize>
+  where
+    Item: PartialEq + 'a,
+  {
+    rposition_sequence(self.iter().rev(), self.len(), elements)
+  }
 }
 
 impl<Item> SequenceTo<Item> for LinkedList<Item> {
@@ -277,3 +285,127 @@ impl<Item> List<Item> for LinkedList<Item> {
     repeat(self.iter(), n)
   }
 }
+
+impl<Item> SequenceMut<Item> for LinkedList<Item> {
+  #[inline]
+  fn add_at_mut(&mut self, index: usize, element: Item) {
+    self.add_at_multi_mut(index, iter::once(element));
+  }
+
+  fn add_at_multi_mut(&mut self, index: usize, elements: impl IntoIterator<Item = Item>) {
+    let size = self.len();
+    assert!(index <= size, "addition index (is {index:?}) should be <= len (is {size:?})");
+    let mut tail = self.split_off(index);
+    self.extend(elements);
+    self.append(&mut tail);
+  }
+
+  fn delete_at_mut(&mut self, index: usize) {
+    let size = self.len();
+    assert!(index < size, "removal index (is {index:?}) should be < len (is {size:?})");
+    let mut tail = self.split_off(index);
+    let _unused = tail.pop_front();
+    self.append(&mut tail);
+  }
+
+  fn delete_at_multi_mut(&mut self, indices: impl IntoIterator<Item = usize>) {
+    let retained = retained_flags(indices, self.len());
+    *self = mem::take(self).into_iter().zip(retained).filter_map(|(x, keep)| keep.then_some(x)).collect();
+  }
+
+  fn delete_mut(&mut self, element: &Item)
+  where
+    Item: PartialEq,
+  {
+    if let Some(index) = self.iter().position(|x| x == element) {
+      self.delete_at_mut(index);
+    }
+  }
+
+  fn move_at_mut(&mut self, source_index: usize, target_index: usize) {
+    let size = self.len();
+    assert!(source_index < size, "source index (is {source_index:?}) should be < len (is {size:?})");
+    assert!(target_index < size, "target index (is {target_index:?}) should be < len (is {size:?})");
+    if source_index == target_index {
+      return;
+    }
+    let mut tail = self.split_off(source_index);
+    let item = tail.pop_front();
+    self.append(&mut tail);
+    let mut tail = self.split_off(target_index);
+    self.extend(item);
+    self.append(&mut tail);
+  }
+
+  #[inline]
+  fn pad_right_mut(&mut self, size: usize, element: Item)
+  where
+    Item: Clone,
+  {
+    let missing = size.saturating_sub(self.len());
+    self.extend(iter::repeat_n(element, missing));
+  }
+
+  #[inline]
+  fn sorted_mut(&mut self)
+  where
+    Item: Ord,
+  {
+    self.sorted_by_mut(Ord::cmp);
+  }
+
+  fn sorted_by_mut(&mut self, compare: impl FnMut(&Item, &Item) -> Ordering) {
+    let mut items = Vec::from_iter(mem::take(self));
+    items.sort_by(compare);
+    self.extend(items);
+  }
+
+  fn sorted_by_key_mut<K: Ord>(&mut self, to_key: impl FnMut(&Item) -> K) {
+    let mut items = Vec::from_iter(mem::take(self));
+    items.sort_by_key(to_key);
+    self.extend(items);
+  }
+
+  #[inline]
+  fn substitute_mut(&mut self, element: &Item, replacement: Item)
+  where
+    Item: PartialEq,
+  {
+    if let Some(item) = self.iter_mut().find(|x| *x == element) {
+      *item = replacement;
+    }
+  }
+
+  fn substitute_at_mut(&mut self, index: usize, replacement: Item) {
+    let size = self.len();
+    match self.iter_mut().nth(index) {
+      Some(item) => *item = replacement,
+      None => panic!("index (is {index:?}) should be < len (is {size:?})"),
+    }
+  }
+
+  fn swap_at_mut(&mut self, source_index: usize, target_index: usize) {
+    let size = self.len();
+    assert!(source_index < size, "source index (is {source_index:?}) should be < len (is {size:?})");
+    assert!(target_index < size, "target index (is {target_index:?}) should be < len (is {size:?})");
+    let (source, target) =
+      if source_index <= target_index { (source_index, target_index) } else { (target_index, source_index) };
+    if source == target {
+      return;
+    }
+    let mut iterator = self.iter_mut();
+    if let Some(source_item) = iterator.nth(source)
+      && let Some(target_item) = iterator.nth(target - source - 1)
+    {
+      mem::swap(source_item, target_item);
+    }
+  }
+
+  fn unique_mut(&mut self)
+  where
+    Item: Eq + Hash,
+  {
+    let retained = unique_flags(self.iter());
+    *self = mem::take(self).into_iter().zip(retained).filter_map(|(x, keep)| keep.then_some(x)).collect();
+  }
+}
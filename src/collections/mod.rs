@@ -1,9 +1,14 @@
+pub(crate) mod array;
 pub(crate) mod binary_heap;
+pub(crate) mod boxed_slice;
 pub(crate) mod btree_map;
 pub(crate) mod btree_set;
 pub(crate) mod hash_map;
 pub(crate) mod hash_set;
 pub(crate) mod linked_list;
+pub(crate) mod option;
+pub(crate) mod result;
 pub(crate) mod slice;
+pub(crate) mod string;
 pub(crate) mod vec;
 pub(crate) mod vec_deque;
@@ -0,0 +1,8 @@
+#[allow(clippy::wildcard_imports)]
+use crate::extensions::*;
+
+impl<Item> Collection<Item> for Option<Item> {}
+
+impl<Item> CollectionTo<Item> for Option<Item> {
+  type This<I> = Option<I>;
+}
@@ -0,0 +1,8 @@
+#[allow(clippy::wildcard_imports)]
+use crate::extensions::*;
+
+impl<Item, E> Collection<Item> for Result<Item, E> {}
+
+impl<Item, E> CollectionTo<Item> for Result<Item, E> {
+  type This<I> = Result<I, E>;
+}
@@ -33,6 +33,19 @@ impl<Item> Sequence<Item> for [Item] {
     equivalent(self.iter(), iterable)
   }
 
+  #[inline]
+  fn lower_bound(&self, element: &Item) -> usize
+  where
+    Item: Ord,
+  {
+    self.partition_point(|x| x < element)
+  }
+
+  #[inline]
+  fn lower_bound_by_key<K: Ord>(&self, key: &K, mut to_key: impl FnMut(&Item) -> K) -> usize {
+    self.partition_point(|x| to_key(x) < *key)
+  }
+
   #[inline]
   fn position_sequence<'a>(&'a self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> Option<usize>
   where
@@ -55,6 +68,27 @@ impl<Item> Sequence<Item> for [Item] {
   fn rposition(&self, predicate: impl FnMut(&Item) -> bool) -> Option<usize> {
     self.iter().rposition(predicate)
   }
+
+  #[inline]
+  fn rposition_sequence<'a>(&'a self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> Option<usize>
+  where
+    Item: PartialEq + 'a,
+  {
+    rposition_sequence(self.iter().rev(), self.len(), elements)
+  }
+
+  #[inline]
+  fn upper_bound(&self, element: &Item) -> usize
+  where
+    Item: Ord,
+  {
+    self.partition_point(|x| x <= element)
+  }
+
+  #[inline]
+  fn upper_bound_by_key<K: Ord>(&self, key: &K, mut to_key: impl FnMut(&Item) -> K) -> usize {
+    self.partition_point(|x| to_key(x) <= *key)
+  }
 }
 
 impl<Item> Slice<Item> for [Item] {
@@ -0,0 +1,248 @@
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, RandomState};
+use std::iter;
+
+#[allow(clippy::wildcard_imports)]
+use crate::extensions::*;
+
+impl Text for str {
+  #[inline]
+  fn add_at(&self, index: usize, element: char) -> String {
+    let mut result = self.chars().collect::<Vec<_>>();
+    result.insert(index, element);
+    result.into_iter().collect()
+  }
+
+  #[inline]
+  fn all(&self, mut predicate: impl FnMut(&char) -> bool) -> bool {
+    self.chars().all(|x| predicate(&x))
+  }
+
+  #[inline]
+  fn any(&self, mut predicate: impl FnMut(&char) -> bool) -> bool {
+    self.chars().any(|x| predicate(&x))
+  }
+
+  #[inline]
+  fn chunked(&self, size: usize) -> Vec<String> {
+    chunked(self.chars(), size, false).collect()
+  }
+
+  #[inline]
+  fn chunked_exact(&self, size: usize) -> Vec<String> {
+    chunked(self.chars(), size, true).collect()
+  }
+
+  #[inline]
+  fn count_by(&self, mut predicate: impl FnMut(&char) -> bool) -> usize {
+    self.chars().filter(|x| predicate(x)).count()
+  }
+
+  #[inline]
+  fn count_unique(&self) -> usize {
+    self.chars().collect::<HashSet<_>>().len()
+  }
+
+  #[inline]
+  fn delete(&self, element: char) -> String {
+    match self.find(element) {
+      Some(index) => [&self[..index], &self[index + element.len_utf8()..]].concat(),
+      None => self.to_string(),
+    }
+  }
+
+  #[inline]
+  fn delete_at(&self, index: usize) -> String {
+    let mut result = self.chars().collect::<Vec<_>>();
+    let _unused = result.remove(index);
+    result.into_iter().collect()
+  }
+
+  #[inline]
+  fn duplicates(&self) -> String {
+    duplicates(self.chars()).collect()
+  }
+
+  #[inline]
+  fn filter(&self, predicate: impl FnMut(&char) -> bool) -> String {
+    self.chars().filter(predicate).collect()
+  }
+
+  #[inline]
+  fn filter_map(&self, mut function: impl FnMut(&char) -> Option<char>) -> String {
+    self.chars().filter_map(|x| function(&x)).collect()
+  }
+
+  #[inline]
+  fn find_map<B>(&self, mut function: impl FnMut(&char) -> Option<B>) -> Option<B> {
+    self.chars().find_map(|x| function(&x))
+  }
+
+  #[inline]
+  fn fold<B>(&self, initial_value: B, mut function: impl FnMut(B, &char) -> B) -> B {
+    self.chars().fold(initial_value, |r, x| function(r, &x))
+  }
+
+  #[inline]
+  fn frequencies(&self) -> HashMap<char, usize> {
+    frequencies_by(self.chars(), |&x| x, RandomState::new())
+  }
+
+  #[inline]
+  fn group_by<K: Eq + Hash>(&self, to_key: impl FnMut(&char) -> K) -> HashMap<K, String> {
+    group_by(self.chars(), to_key, RandomState::new())
+  }
+
+  #[inline]
+  fn init(&self) -> String {
+    let mut result = self.to_string();
+    let _unused = result.pop();
+    result
+  }
+
+  #[inline]
+  fn intersperse(&self, interval: usize, element: char) -> String {
+    intersperse_with(self.chars(), interval, || element).collect()
+  }
+
+  #[inline]
+  fn map(&self, mut function: impl FnMut(&char) -> char) -> String {
+    self.chars().map(|x| function(&x)).collect()
+  }
+
+  #[inline]
+  fn max_by(&self, mut compare: impl FnMut(&char, &char) -> Ordering) -> Option<char> {
+    self.chars().max_by(|x, y| compare(x, y))
+  }
+
+  #[inline]
+  fn max_by_key<K: Ord>(&self, mut to_key: impl FnMut(&char) -> K) -> Option<char> {
+    self.chars().max_by_key(|x| to_key(x))
+  }
+
+  #[inline]
+  fn max_of(&self) -> Option<char> {
+    self.chars().max()
+  }
+
+  #[inline]
+  fn min_by(&self, mut compare: impl FnMut(&char, &char) -> Ordering) -> Option<char> {
+    self.chars().min_by(|x, y| compare(x, y))
+  }
+
+  #[inline]
+  fn min_by_key<K: Ord>(&self, mut to_key: impl FnMut(&char) -> K) -> Option<char> {
+    self.chars().min_by_key(|x| to_key(x))
+  }
+
+  #[inline]
+  fn min_of(&self) -> Option<char> {
+    self.chars().min()
+  }
+
+  #[inline]
+  fn minmax_by(&self, compare: impl FnMut(&char, &char) -> Ordering) -> Option<(char, char)> {
+    let values = self.chars().collect::<Vec<_>>();
+    minmax_by(values.iter(), compare).map(|(&min, &max)| (min, max))
+  }
+
+  #[inline]
+  fn minmax_by_key<K: Ord>(&self, mut to_key: impl FnMut(&char) -> K) -> Option<(char, char)> {
+    self.minmax_by(|x, y| to_key(x).cmp(&to_key(y)))
+  }
+
+  #[inline]
+  fn minmax_of(&self) -> Option<(char, char)> {
+    self.minmax_by(Ord::cmp)
+  }
+
+  #[inline]
+  fn pad_left(&self, size: usize, element: char) -> String {
+    let padding = size.saturating_sub(self.chars().count());
+    iter::repeat_n(element, padding).chain(self.chars()).collect()
+  }
+
+  #[inline]
+  fn pad_right(&self, size: usize, element: char) -> String {
+    pad_right_with(self.chars(), size, |_| element).collect()
+  }
+
+  #[inline]
+  fn position(&self, mut predicate: impl FnMut(&char) -> bool) -> Option<usize> {
+    self.chars().position(|x| predicate(&x))
+  }
+
+  #[inline]
+  fn position_multi(&self, mut predicate: impl FnMut(&char) -> bool) -> Vec<usize> {
+    self.chars().enumerate().filter(|(_, x)| predicate(x)).map(|(index, _)| index).collect()
+  }
+
+  #[inline]
+  fn position_sequence(&self, sequence: &str) -> Option<usize> {
+    let values = self.chars().collect::<Vec<_>>();
+    let pattern = sequence.chars().collect::<Vec<_>>();
+    sequence_positions(values.iter(), pattern.iter()).next()
+  }
+
+  #[inline]
+  fn position_sequence_multi(&self, sequence: &str) -> Vec<usize> {
+    let values = self.chars().collect::<Vec<_>>();
+    let pattern = sequence.chars().collect::<Vec<_>>();
+    sequence_positions(values.iter(), pattern.iter()).collect()
+  }
+
+  #[inline]
+  fn rev(&self) -> String {
+    self.chars().rev().collect()
+  }
+
+  #[inline]
+  fn rposition(&self, mut predicate: impl FnMut(&char) -> bool) -> Option<usize> {
+    let size = self.chars().count();
+    self.chars().rev().position(|x| predicate(&x)).map(|index| size - index - 1)
+  }
+
+  #[inline]
+  fn sorted(&self) -> String {
+    let mut result = self.chars().collect::<Vec<_>>();
+    result.sort_unstable();
+    result.into_iter().collect()
+  }
+
+  #[inline]
+  fn substitute(&self, element: char, replacement: char) -> String {
+    self.replacen(element, replacement.encode_utf8(&mut [0; 4]), 1)
+  }
+
+  #[inline]
+  fn substitute_at(&self, index: usize, replacement: char) -> String {
+    let mut result = self.chars().collect::<Vec<_>>();
+    result[index] = replacement;
+    result.into_iter().collect()
+  }
+
+  #[inline]
+  fn swap_at(&self, source_index: usize, target_index: usize) -> String {
+    let mut result = self.chars().collect::<Vec<_>>();
+    result.swap(source_index, target_index);
+    result.into_iter().collect()
+  }
+
+  #[inline]
+  fn tail(&self) -> String {
+    let mut chars = self.chars();
+    let _unused = chars.next();
+    chars.as_str().to_string()
+  }
+
+  #[inline]
+  fn unique(&self) -> String {
+    unique_by(self.chars(), |&x| x).collect()
+  }
+
+  #[inline]
+  fn windowed(&self, size: usize, step: usize) -> Vec<String> {
+    windowed(self.chars(), size, step).collect()
+  }
+}
@@ -1,3 +1,4 @@
+use std::cmp::Ordering;
 use std::collections::VecDeque;
 use std::hash::Hash;
 
@@ -46,11 +47,17 @@ impl<Item> CollectionTo<Item> for VecDeque<Item> {
     Item: Eq + Hash + 'a,
     Self: FromIterator<Item>,
   {
-    for element in elements.iterator() {
-      if let Some(index) = self.iter().position(|x| x == element) {
-        let _unused = self.remove(index);
-      }
-    }
+    self.retain(multiset_filter(elements.iterator(), false));
+    self
+  }
+
+  #[inline]
+  fn intersect<'a>(mut self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> Self
+  where
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+    Item: Eq + Hash + 'a,
+  {
+    self.retain(multiset_filter(elements.iterator(), true));
     self
   }
 
@@ -65,6 +72,22 @@ impl<Item> CollectionTo<Item> for VecDeque<Item> {
     }
     self
   }
+
+  fn substitute_multi<'a>(
+    mut self, elements: &'a impl Iterable<Item<'a> = &'a Item>, replacements: impl IntoIterator<Item = Item>,
+  ) -> Self
+  where
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+    Item: Eq + Hash + 'a,
+  {
+    let mut replaced = multiset_replacements(elements.iterator(), replacements);
+    for item in &mut self {
+      if let Some(replacement) = replaced.get_mut(&*item).and_then(VecDeque::pop_front) {
+        *item = replacement;
+      }
+    }
+    self
+  }
 }
 
 impl<Item> Sequence<Item> for VecDeque<Item> {
@@ -93,6 +116,19 @@ impl<Item> Sequence<Item> for VecDeque<Item> {
     equivalent(self.iter(), iterable)
   }
 
+  #[inline]
+  fn lower_bound(&self, element: &Item) -> usize
+  where
+    Item: Ord,
+  {
+    self.partition_point(|x| x < element)
+  }
+
+  #[inline]
+  fn lower_bound_by_key<K: Ord>(&self, key: &K, mut to_key: impl FnMut(&Item) -> K) -> usize {
+    self.partition_point(|x| to_key(x) < *key)
+  }
+
   #[inline]
   fn position_sequence<'a>(&'a self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> Option<usize>
   where
@@ -115,6 +151,27 @@ impl<Item> Sequence<Item> for VecDeque<Item> {
   fn rposition(&self, predicate: impl FnMut(&Item) -> bool) -> Option<usize> {
     self.iter().rposition(predicate)
   }
+
+  #[inline]
+  fn rposition_sequence<'a>(&'a self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> Option<usize>
+  where
+    Item: PartialEq + 'a,
+  {
+    rposition_sequence(self.iter().rev(), self.len(), elements)
+  }
+
+  #[inline]
+  fn upper_bound(&self, element: &Item) -> usize
+  where
+    Item: Ord,
+  {
+    self.partition_point(|x| x <= element)
+  }
+
+  #[inline]
+  fn upper_bound_by_key<K: Ord>(&self, key: &K, mut to_key: impl FnMut(&Item) -> K) -> usize {
+    self.partition_point(|x| to_key(x) <= *key)
+  }
 }
 
 impl<Item> SequenceTo<Item> for VecDeque<Item> {
@@ -181,6 +238,17 @@ impl<Item> SequenceTo<Item> for VecDeque<Item> {
     self
   }
 
+  #[inline]
+  fn sorted_insert(mut self, element: Item) -> Self
+  where
+    Item: Ord,
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+  {
+    let index = self.partition_point(|x| x <= &element);
+    self.insert(index, element);
+    self
+  }
+
   #[inline]
   fn substitute_at(mut self, index: usize, replacement: Item) -> Self
   where
@@ -237,3 +305,108 @@ impl<Item> List<Item> for VecDeque<Item> {
     repeat(self.iter(), n)
   }
 }
+
+impl<Item> SequenceMut<Item> for VecDeque<Item> {
+  #[inline]
+  fn add_at_mut(&mut self, index: usize, element: Item) {
+    let size = self.len();
+    assert!(index <= size, "index (is {index:?}) should be <= len (is {size:?})");
+    self.insert(index, element);
+  }
+
+  fn add_at_multi_mut(&mut self, index: usize, elements: impl IntoIterator<Item = Item>) {
+    let size = self.len();
+    assert!(index <= size, "index (is {index:?}) should be <= len (is {size:?})");
+    let mut tail = self.split_off(index);
+    self.extend(elements);
+    self.append(&mut tail);
+  }
+
+  #[inline]
+  fn delete_at_mut(&mut self, index: usize) {
+    if self.remove(index).is_none() {
+      let size = self.len();
+      panic!("index (is {index:?}) should be < len (is {size:?})")
+    }
+  }
+
+  fn delete_at_multi_mut(&mut self, indices: impl IntoIterator<Item = usize>) {
+    let mut retained = retained_flags(indices, self.len()).into_iter();
+    self.retain(|_| retained.next().unwrap_or(true));
+  }
+
+  #[inline]
+  fn delete_mut(&mut self, element: &Item)
+  where
+    Item: PartialEq,
+  {
+    if let Some(index) = self.iter().position(|x| x == element) {
+      let _unused = self.remove(index);
+    }
+  }
+
+  fn move_at_mut(&mut self, source_index: usize, target_index: usize) {
+    if source_index == target_index {
+      let size = self.len();
+      assert!(source_index < size, "source index (is {source_index:?}) should be < len (is {size:?})");
+    } else if let Some(item) = self.remove(source_index) {
+      self.insert(target_index, item);
+    }
+  }
+
+  #[inline]
+  fn pad_right_mut(&mut self, size: usize, element: Item)
+  where
+    Item: Clone,
+  {
+    if self.len() < size {
+      self.resize(size, element);
+    }
+  }
+
+  #[inline]
+  fn sorted_mut(&mut self)
+  where
+    Item: Ord,
+  {
+    self.make_contiguous().sort();
+  }
+
+  #[inline]
+  fn sorted_by_mut(&mut self, compare: impl FnMut(&Item, &Item) -> Ordering) {
+    self.make_contiguous().sort_by(compare);
+  }
+
+  #[inline]
+  fn sorted_by_key_mut<K: Ord>(&mut self, to_key: impl FnMut(&Item) -> K) {
+    self.make_contiguous().sort_by_key(to_key);
+  }
+
+  #[inline]
+  fn substitute_mut(&mut self, element: &Item, replacement: Item)
+  where
+    Item: PartialEq,
+  {
+    if let Some(item) = self.iter_mut().find(|x| *x == element) {
+      *item = replacement;
+    }
+  }
+
+  #[inline]
+  fn substitute_at_mut(&mut self, index: usize, replacement: Item) {
+    self[index] = replacement;
+  }
+
+  #[inline]
+  fn swap_at_mut(&mut self, source_index: usize, target_index: usize) {
+    self.swap(source_index, target_index);
+  }
+
+  fn unique_mut(&mut self)
+  where
+    Item: Eq + Hash,
+  {
+    let mut retained = unique_flags(self.iter()).into_iter();
+    self.retain(|_| retained.next().unwrap_or(true));
+  }
+}
@@ -0,0 +1,202 @@
+use std::cmp::Ordering;
+use std::ops::{Add, Sub};
+
+use private::Sealed;
+
+mod private {
+  /// Prevents implementing the arithmetic traits outside of this crate.
+  #[allow(unnameable_types)]
+  pub trait Sealed {}
+}
+
+/// Primitive integer types supporting overflow-aware arithmetic.
+///
+/// Used by checked and saturating aggregation operations such as [`checked_sum()`].
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+///
+/// [`checked_sum()`]: crate::CollectionTo::checked_sum
+pub trait Integer: Copy + Ord + Sealed {
+  /// The additive identity.
+  const ZERO: Self;
+
+  /// The multiplicative identity.
+  const ONE: Self;
+
+  /// The smallest value that can be represented by this integer type.
+  const MIN: Self;
+
+  /// The largest value that can be represented by this integer type.
+  const MAX: Self;
+
+  /// Checked integer addition, returning [`None`] if overflow occurred.
+  fn checked_add(self, other: Self) -> Option<Self>;
+
+  /// Checked integer multiplication, returning [`None`] if overflow occurred.
+  fn checked_mul(self, other: Self) -> Option<Self>;
+
+  /// Wrapping integer addition, also returning whether an arithmetic overflow occurred.
+  fn overflowing_add(self, other: Self) -> (Self, bool);
+}
+
+/// Primitive floating point types supporting compensated arithmetic.
+///
+/// Used by compensated aggregation operations such as [`precise_sum()`].
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+///
+/// [`precise_sum()`]: crate::CollectionTo::precise_sum
+pub trait Float: Copy + Add<Output = Self> + Sub<Output = Self> + Sealed {
+  /// The additive identity.
+  const ZERO: Self;
+
+  /// Tests if the absolute value of this value is greater than or equal to the absolute value of another value.
+  fn abs_ge(self, other: Self) -> bool;
+
+  /// Tests if this value is neither infinite nor NaN.
+  fn is_finite(self) -> bool;
+
+  /// Tests if this value is NaN.
+  fn is_nan(self) -> bool;
+
+  /// Returns the ordering between this value and another value according to the
+  /// IEEE 754 `totalOrder` predicate.
+  fn total_cmp(&self, other: &Self) -> Ordering;
+}
+
+/// Primitive numeric types convertible to floating point values.
+///
+/// Used by statistical operations such as [`mean()`].
+/// Integers with a magnitude larger than 2<sup>53</sup> are rounded to the nearest representable value.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+///
+/// [`mean()`]: crate::Collection::mean
+pub trait Number: Copy + Sealed {
+  /// Converts this value to the nearest `f64` value.
+  fn as_f64(self) -> f64;
+}
+
+macro_rules! number {
+  ($($type:ty),*) => {
+    $(
+      impl Number for $type {
+        #[inline]
+        #[allow(trivial_numeric_casts)]
+        #[allow(clippy::cast_lossless)]
+        #[allow(clippy::cast_precision_loss)]
+        fn as_f64(self) -> f64 {
+          self as f64
+        }
+      }
+    )*
+  };
+}
+
+macro_rules! integer {
+  ($($type:ty),*) => {
+    $(
+      impl Sealed for $type {}
+
+      impl Integer for $type {
+        const ZERO: Self = 0;
+        const ONE: Self = 1;
+        const MIN: Self = <$type>::MIN;
+        const MAX: Self = <$type>::MAX;
+
+        #[inline]
+        fn checked_add(self, other: Self) -> Option<Self> {
+          <$type>::checked_add(self, other)
+        }
+
+        #[inline]
+        fn checked_mul(self, other: Self) -> Option<Self> {
+          <$type>::checked_mul(self, other)
+        }
+
+        #[inline]
+        fn overflowing_add(self, other: Self) -> (Self, bool) {
+          <$type>::overflowing_add(self, other)
+        }
+      }
+    )*
+  };
+}
+
+macro_rules! float {
+  ($($type:ty),*) => {
+    $(
+      impl Sealed for $type {}
+
+      impl Float for $type {
+        const ZERO: Self = 0.0;
+
+        #[inline]
+        fn abs_ge(self, other: Self) -> bool {
+          self.abs() >= other.abs()
+        }
+
+        #[inline]
+        fn is_finite(self) -> bool {
+          <$type>::is_finite(self)
+        }
+
+        #[inline]
+        fn is_nan(self) -> bool {
+          <$type>::is_nan(self)
+        }
+
+        #[inline]
+        fn total_cmp(&self, other: &Self) -> Ordering {
+          <$type>::total_cmp(self, other)
+        }
+      }
+    )*
+  };
+}
+
+integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+float!(f32, f64);
+number!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+pub(crate) fn checked_sum<Item: Integer>(mut iterator: impl Iterator<Item = Item>) -> Option<Item> {
+  iterator.try_fold(Item::ZERO, Item::checked_add)
+}
+
+pub(crate) fn checked_product<Item: Integer>(mut iterator: impl Iterator<Item = Item>) -> Option<Item> {
+  iterator.try_fold(Item::ONE, Item::checked_mul)
+}
+
+/// Computes an exact sum clamped to the numeric bounds, independently of the order of the values.
+///
+/// The exact sum equals the wrapped sum plus the number of overflows above the upper bound
+/// minus the number of overflows below the lower bound, multiplied by the size of the type range.
+pub(crate) fn saturating_sum<Item: Integer>(iterator: impl Iterator<Item = Item>) -> Item {
+  let mut sum = Item::ZERO;
+  let mut overflows = 0_i128;
+  for value in iterator {
+    let (total, overflowed) = sum.overflowing_add(value);
+    if overflowed {
+      overflows += if value < Item::ZERO { -1 } else { 1 };
+    }
+    sum = total;
+  }
+  match overflows.cmp(&0) {
+    Ordering::Less => Item::MIN,
+    Ordering::Equal => sum,
+    Ordering::Greater => Item::MAX,
+  }
+}
+
+/// Computes a sum using the Kahan–Neumaier compensated summation algorithm.
+pub(crate) fn precise_sum<Item: Float>(iterator: impl Iterator<Item = Item>) -> Item {
+  let mut sum = Item::ZERO;
+  let mut compensation = Item::ZERO;
+  for value in iterator {
+    let total = sum + value;
+    compensation =
+      if sum.abs_ge(value) { compensation + (sum - total + value) } else { compensation + (value - total + sum) };
+    sum = total;
+  }
+  if sum.is_finite() { sum + compensation } else { sum }
+}
@@ -0,0 +1,108 @@
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+use std::hash::{BuildHasher, RandomState};
+
+use private::Sealed;
+
+mod private {
+  /// Prevents implementing the construction traits outside of this crate.
+  #[allow(unnameable_types)]
+  pub trait Sealed {}
+}
+
+/// Collection types which can be created by constructor-style operations
+/// such as [`unit()`] and [`fill_with()`] without type annotations.
+///
+/// Hash-based collections implement this trait only for the default [`RandomState`] hasher,
+/// so that their hasher is inferred by a call like `HashMap::unit(1, 2)`. Hash-based collections
+/// using a custom hasher can be created using [`unit_with_hasher()`] and [`fill_with_hasher()`] instead.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+///
+/// [`unit()`]: crate::CollectionTo::unit
+/// [`fill_with()`]: crate::CollectionTo::fill_with
+/// [`unit_with_hasher()`]: crate::CollectionTo::unit_with_hasher
+/// [`fill_with_hasher()`]: crate::CollectionTo::fill_with_hasher
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use cantrip::*;
+///
+/// let a = HashMap::unit(1, 2);
+///
+/// assert_eq!(a, HashMap::from([(1, 2)]));
+/// ```
+pub trait Constructible: Sealed {}
+
+/// Hash-based collection types which can be created by constructor-style operations
+/// such as [`unit_with_hasher()`] and [`fill_with_hasher()`] using the specified hasher.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+///
+/// [`unit_with_hasher()`]: crate::CollectionTo::unit_with_hasher
+/// [`fill_with_hasher()`]: crate::CollectionTo::fill_with_hasher
+pub trait HasherConstructible<S>: Sealed + Sized {
+  /// Creates an empty collection which will use the specified hasher.
+  #[doc(hidden)]
+  fn from_hasher(hash_builder: S) -> Self;
+}
+
+impl<Item> Sealed for BinaryHeap<Item> {}
+
+impl<Item> Constructible for BinaryHeap<Item> {}
+
+impl<Item> Sealed for Box<[Item]> {}
+
+impl<Item> Constructible for Box<[Item]> {}
+
+impl<Key, Value> Sealed for BTreeMap<Key, Value> {}
+
+impl<Key, Value> Constructible for BTreeMap<Key, Value> {}
+
+impl<Item> Sealed for BTreeSet<Item> {}
+
+impl<Item> Constructible for BTreeSet<Item> {}
+
+impl<Key, Value, S: BuildHasher> Sealed for HashMap<Key, Value, S> {}
+
+impl<Key, Value> Constructible for HashMap<Key, Value, RandomState> {}
+
+impl<Key, Value, S: BuildHasher> HasherConstructible<S> for HashMap<Key, Value, S> {
+  #[inline]
+  fn from_hasher(hash_builder: S) -> Self {
+    HashMap::with_hasher(hash_builder)
+  }
+}
+
+impl<Item, S: BuildHasher> Sealed for HashSet<Item, S> {}
+
+impl<Item> Constructible for HashSet<Item, RandomState> {}
+
+impl<Item, S: BuildHasher> HasherConstructible<S> for HashSet<Item, S> {
+  #[inline]
+  fn from_hasher(hash_builder: S) -> Self {
+    HashSet::with_hasher(hash_builder)
+  }
+}
+
+impl<Item> Sealed for LinkedList<Item> {}
+
+impl<Item> Constructible for LinkedList<Item> {}
+
+impl<Item> Sealed for Option<Item> {}
+
+impl<Item> Constructible for Option<Item> {}
+
+impl<Item, E> Sealed for Result<Item, E> {}
+
+impl<Item, E> Constructible for Result<Item, E> {}
+
+impl<Item> Sealed for Vec<Item> {}
+
+impl<Item> Constructible for Vec<Item> {}
+
+impl<Item> Sealed for VecDeque<Item> {}
+
+impl<Item> Constructible for VecDeque<Item> {}
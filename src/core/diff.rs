@@ -0,0 +1,304 @@
+use std::ops::{Index, IndexMut};
+use std::slice;
+
+use crate::core::unfold::unfold;
+
+/// Hunk of an edit script transforming a source sequence into a target sequence.
+///
+/// Indices of each hunk refer to the position of the hunk in the source and in the target sequence.
+/// Inserted and replacing elements are stored in the hunk so that an edit script can be applied
+/// to the source sequence without the target sequence.
+///
+/// # Example
+///
+/// ```
+/// use cantrip::*;
+///
+/// let a = vec![1, 2, 3, 4];
+///
+/// assert_eq!(a.diff(&vec![1, 3, 4, 5]), vec![
+///   DiffHunk::Equal { source_index: 0, target_index: 0, length: 1 },
+///   DiffHunk::Delete { source_index: 1, target_index: 1, length: 1 },
+///   DiffHunk::Equal { source_index: 2, target_index: 1, length: 2 },
+///   DiffHunk::Insert { source_index: 4, target_index: 3, elements: vec![5] },
+/// ]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DiffHunk<Item> {
+  /// Elements present in both sequences.
+  Equal {
+    /// Index of the first element in the source sequence.
+    source_index: usize,
+    /// Index of the first element in the target sequence.
+    target_index: usize,
+    /// Number of elements.
+    length: usize,
+  },
+  /// Elements present only in the source sequence.
+  Delete {
+    /// Index of the first deleted element in the source sequence.
+    source_index: usize,
+    /// Index in the target sequence where the elements were deleted.
+    target_index: usize,
+    /// Number of deleted elements.
+    length: usize,
+  },
+  /// Elements present only in the target sequence.
+  Insert {
+    /// Index in the source sequence where the elements are inserted.
+    source_index: usize,
+    /// Index of the first inserted element in the target sequence.
+    target_index: usize,
+    /// Inserted elements.
+    elements: Vec<Item>,
+  },
+  /// Elements of the source sequence replaced by different elements of the target sequence.
+  Replace {
+    /// Index of the first replaced element in the source sequence.
+    source_index: usize,
+    /// Index of the first replacing element in the target sequence.
+    target_index: usize,
+    /// Number of replaced elements.
+    length: usize,
+    /// Replacing elements.
+    elements: Vec<Item>,
+  },
+}
+
+/// Computes an edit script from the longest common subsequence of source and target elements.
+pub(crate) fn diff<'a, Item: Clone + PartialEq + 'a>(
+  source: impl Iterator<Item = &'a Item>, target: impl Iterator<Item = &'a Item>, prefix_length: usize,
+  suffix_length: usize,
+) -> Vec<DiffHunk<Item>> {
+  let source = source.collect::<Vec<_>>();
+  let target = target.collect::<Vec<_>>();
+  let matches = common_subsequence_pairs(&source, &target, prefix_length, suffix_length);
+  let mut result = Vec::new();
+  let mut source_index = 0_usize;
+  let mut target_index = 0_usize;
+  for (source_end, target_end) in matches.into_iter().chain([(source.len(), target.len())]) {
+    let length = source_end - source_index;
+    let elements = target[target_index..target_end].iter().map(|&x| x.clone()).collect::<Vec<_>>();
+    match (length, elements.is_empty()) {
+      (0, true) => {}
+      (0, false) => result.push(DiffHunk::Insert { source_index, target_index, elements }),
+      (_, true) => result.push(DiffHunk::Delete { source_index, target_index, length }),
+      (_, false) => result.push(DiffHunk::Replace {
+        source_index,
+        target_index,
+        length,
+        elements,
+      }),
+    }
+    if source_end < source.len() {
+      add_equal(&mut result, source_end, target_end, 1);
+    }
+    source_index = source_end + 1;
+    target_index = target_end + 1;
+  }
+  result
+}
+
+/// Finds the pairs of indices of elements forming a longest common subsequence of source and target elements.
+pub(crate) fn longest_common_subsequence<'a, Item: PartialEq + 'a>(
+  source: impl Iterator<Item = &'a Item>, target: impl Iterator<Item = &'a Item>, prefix_length: usize,
+  suffix_length: usize,
+) -> Vec<(usize, usize)> {
+  let source = source.collect::<Vec<_>>();
+  let target = target.collect::<Vec<_>>();
+  common_subsequence_pairs(&source, &target, prefix_length, suffix_length)
+}
+
+/// Lazily applies an edit script to source elements, copying elements not covered by the script unchanged.
+pub(crate) fn apply_diff<'a, Item: Clone + 'a>(
+  mut iterator: impl Iterator<Item = Item>, script: impl Iterator<Item = &'a DiffHunk<Item>>,
+) -> impl Iterator<Item = Item> {
+  let mut script = script.fuse();
+  let mut index = 0_usize;
+  let mut range = 0..0;
+  let mut copied = 0_usize;
+  let mut skipped = 0_usize;
+  let mut inserted: slice::Iter<'a, Item> = [].iter();
+  unfold(move || {
+    loop {
+      if copied > 0 {
+        copied -= 1;
+        let result = iterator.next();
+        assert!(result.is_some(), "hunk source range (is {range:?}) should be within the sequence");
+        return result;
+      }
+      if skipped > 0 {
+        let removed = iterator.by_ref().take(skipped).count();
+        assert!(removed == skipped, "hunk source range (is {range:?}) should be within the sequence");
+        skipped = 0;
+      }
+      if let Some(element) = inserted.next() {
+        return Some(element.clone());
+      }
+      let Some(hunk) = script.next() else {
+        return iterator.next();
+      };
+      let (source_index, copy_length, skip_length, elements) = match hunk {
+        DiffHunk::Equal { source_index, length, .. } => (*source_index, *length, 0, [].as_slice()),
+        DiffHunk::Delete { source_index, length, .. } => (*source_index, 0, *length, [].as_slice()),
+        DiffHunk::Insert { source_index, elements, .. } => (*source_index, 0, 0, elements.as_slice()),
+        DiffHunk::Replace { source_index, length, elements, .. } => (*source_index, 0, *length, elements.as_slice()),
+      };
+      assert!(source_index >= index, "hunk source index (is {source_index:?}) should be >= {index:?}");
+      range = source_index..source_index + copy_length + skip_length;
+      copied = source_index - index + copy_length;
+      skipped = skip_length;
+      inserted = elements.iter();
+      index = range.end;
+    }
+  })
+}
+
+/// Finds the pairs of indices of elements forming a longest common subsequence by matching
+/// the common prefix and suffix directly and the remaining elements using the Myers difference algorithm.
+fn common_subsequence_pairs<Item: PartialEq>(
+  source: &[&Item], target: &[&Item], prefix_length: usize, suffix_length: usize,
+) -> Vec<(usize, usize)> {
+  let suffix_length = suffix_length.min(source.len().min(target.len()) - prefix_length);
+  let source_end = source.len() - suffix_length;
+  let target_end = target.len() - suffix_length;
+  let middle = matching_pairs(&source[prefix_length..source_end], &target[prefix_length..target_end]);
+  (0..prefix_length)
+    .map(|index| (index, index))
+    .chain(middle.into_iter().map(|(x, y)| (prefix_length + x, prefix_length + y)))
+    .chain((0..suffix_length).map(|index| (source_end + index, target_end + index)))
+    .collect()
+}
+
+fn add_equal<Item>(hunks: &mut Vec<DiffHunk<Item>>, source_index: usize, target_index: usize, length: usize) {
+  if length == 0 {
+    return;
+  }
+  if let Some(DiffHunk::Equal {
+    source_index: last_source_index,
+    length: last_length,
+    ..
+  }) = hunks.last_mut()
+    && *last_source_index + *last_length == source_index
+  {
+    *last_length += length;
+    return;
+  }
+  hunks.push(DiffHunk::Equal { source_index, target_index, length });
+}
+
+/// Finds the pairs of indices of matching elements along a shortest edit path
+/// using the linear space variant of the Myers difference algorithm.
+fn matching_pairs<Item: PartialEq>(source: &[&Item], target: &[&Item]) -> Vec<(usize, usize)> {
+  let limit = (source.len() + target.len()).div_ceil(2) + 1;
+  let mut forward = Frontier::new(limit);
+  let mut backward = Frontier::new(limit);
+  let mut result = Vec::new();
+  add_matching_pairs(source, target, (0, 0), &mut forward, &mut backward, &mut result);
+  result
+}
+
+/// Adds the pairs of matching elements by trimming common prefix and suffix and splitting
+/// the remaining elements at the middle snake of a shortest edit path.
+fn add_matching_pairs<Item: PartialEq>(
+  source: &[&Item], target: &[&Item], start: (usize, usize), forward: &mut Frontier, backward: &mut Frontier,
+  pairs: &mut Vec<(usize, usize)>,
+) {
+  let prefix_length = source.iter().zip(target).take_while(|(x, y)| x == y).count();
+  pairs.extend((0..prefix_length).map(|index| (start.0 + index, start.1 + index)));
+  let (source, target) = (&source[prefix_length..], &target[prefix_length..]);
+  let suffix_length = source.iter().rev().zip(target.iter().rev()).take_while(|(x, y)| x == y).count();
+  let (source, target) = (&source[..source.len() - suffix_length], &target[..target.len() - suffix_length]);
+  let start = (start.0 + prefix_length, start.1 + prefix_length);
+  if !source.is_empty()
+    && !target.is_empty()
+    && let Some((x, y)) = middle_snake(source, target, forward, backward)
+  {
+    add_matching_pairs(&source[..x], &target[..y], start, forward, backward, pairs);
+    add_matching_pairs(&source[x..], &target[y..], (start.0 + x, start.1 + y), forward, backward, pairs);
+  }
+  pairs.extend((0..suffix_length).map(|index| (start.0 + source.len() + index, start.1 + target.len() + index)));
+}
+
+/// Finds the start of the middle snake of a shortest edit path by searching
+/// forward from the start and backward from the end simultaneously until the searches overlap.
+#[allow(clippy::cast_possible_wrap)]
+#[allow(clippy::cast_sign_loss)]
+fn middle_snake<Item: PartialEq>(
+  source: &[&Item], target: &[&Item], forward: &mut Frontier, backward: &mut Frontier,
+) -> Option<(usize, usize)> {
+  let (source_size, target_size) = (source.len(), target.len());
+  let delta = source_size as isize - target_size as isize;
+  let odd = delta % 2 != 0;
+  forward[1] = 0;
+  backward[1] = 0;
+  for distance in 0..=(source_size + target_size).div_ceil(2) as isize {
+    for diagonal in (-distance..=distance).rev().step_by(2) {
+      let mut x = if diagonal == -distance || (diagonal != distance && forward[diagonal - 1] < forward[diagonal + 1]) {
+        forward[diagonal + 1]
+      } else {
+        forward[diagonal - 1] + 1
+      };
+      let (snake_x, snake_y) = (x, (x as isize - diagonal) as usize);
+      let mut y = snake_y;
+      while x < source_size && y < target_size && source[x] == target[y] {
+        x += 1;
+        y += 1;
+      }
+      forward[diagonal] = x;
+      if odd && (diagonal - delta).abs() < distance && x + backward[delta - diagonal] >= source_size {
+        return Some((snake_x, snake_y));
+      }
+    }
+    for diagonal in (-distance..=distance).rev().step_by(2) {
+      let mut x = if diagonal == -distance || (diagonal != distance && backward[diagonal - 1] < backward[diagonal + 1])
+      {
+        backward[diagonal + 1]
+      } else {
+        backward[diagonal - 1] + 1
+      };
+      let mut y = (x as isize - diagonal) as usize;
+      while x < source_size && y < target_size && source[source_size - x - 1] == target[target_size - y - 1] {
+        x += 1;
+        y += 1;
+      }
+      backward[diagonal] = x;
+      if !odd && (diagonal - delta).abs() <= distance && x + forward[delta - diagonal] >= source_size {
+        return Some((source_size - x, target_size - y));
+      }
+    }
+  }
+  None
+}
+
+/// Furthest reaching positions of an edit path search indexed by diagonal `k = x - y`.
+struct Frontier {
+  furthest: Vec<usize>,
+  offset: isize,
+}
+
+impl Frontier {
+  #[allow(clippy::cast_possible_wrap)]
+  fn new(limit: usize) -> Self {
+    Frontier {
+      furthest: vec![0; 2 * limit + 3],
+      offset: limit as isize + 1,
+    }
+  }
+}
+
+impl Index<isize> for Frontier {
+  type Output = usize;
+
+  #[allow(clippy::cast_sign_loss)]
+  fn index(&self, diagonal: isize) -> &Self::Output {
+    &self.furthest[(diagonal + self.offset) as usize]
+  }
+}
+
+impl IndexMut<isize> for Frontier {
+  #[allow(clippy::cast_sign_loss)]
+  fn index_mut(&mut self, diagonal: isize) -> &mut Self::Output {
+    &mut self.furthest[(diagonal + self.offset) as usize]
+  }
+}
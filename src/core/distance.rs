@@ -0,0 +1,89 @@
+use std::mem;
+
+use crate::LengthMismatch;
+
+/// Computes the Damerau-Levenshtein distance allowing transpositions of adjacent elements
+/// without restricting subsequent edits of the transposed elements.
+pub(crate) fn damerau_levenshtein_distance<'a, Item: PartialEq + 'a>(
+  left: impl Iterator<Item = &'a Item>, right: impl Iterator<Item = &'a Item>,
+) -> usize {
+  let left = left.collect::<Vec<_>>();
+  let right = right.collect::<Vec<_>>();
+  let unreachable = left.len() + right.len();
+  // Distances are shifted by one row and column to represent the unreachable border
+  let mut distances = vec![vec![unreachable; right.len() + 2]; left.len() + 2];
+  for (row, distance) in distances.iter_mut().skip(1).enumerate() {
+    distance[1] = row;
+  }
+  for (column, distance) in distances[1].iter_mut().skip(1).enumerate() {
+    *distance = column;
+  }
+  // Last row in which each right element matched a left element
+  let mut last_rows = vec![0_usize; right.len()];
+  for (i, x) in left.iter().enumerate() {
+    let mut last_column = 0_usize;
+    for (j, y) in right.iter().enumerate() {
+      let (row, column) = (i + 1, j + 1);
+      let previous_row = last_rows[j];
+      let previous_column = last_column;
+      let cost = if x == y {
+        last_column = column;
+        last_rows[j] = row;
+        0
+      } else {
+        1
+      };
+      distances[row + 1][column + 1] = (distances[row][column] + cost)
+        .min(distances[row + 1][column] + 1)
+        .min(distances[row][column + 1] + 1)
+        .min(distances[previous_row][previous_column] + (row - previous_row) + (column - previous_column) - 1);
+    }
+  }
+  distances[left.len() + 1][right.len() + 1]
+}
+
+pub(crate) fn hamming_distance<'a, Item: PartialEq + 'a>(
+  mut left: impl Iterator<Item = &'a Item>, mut right: impl Iterator<Item = &'a Item>,
+) -> Result<usize, LengthMismatch> {
+  let mut result = 0_usize;
+  let mut count = 0_usize;
+  loop {
+    match (left.next(), right.next()) {
+      (Some(x), Some(y)) => {
+        result += usize::from(x != y);
+        count += 1;
+      }
+      (None, None) => return Ok(result),
+      (Some(_), None) => {
+        return Err(LengthMismatch {
+          left: count + 1 + left.count(),
+          right: count,
+        });
+      }
+      (None, Some(_)) => {
+        return Err(LengthMismatch {
+          left: count,
+          right: count + 1 + right.count(),
+        });
+      }
+    }
+  }
+}
+
+/// Computes the Levenshtein distance using two rows of the Wagner-Fischer matrix.
+pub(crate) fn levenshtein_distance<'a, Item: PartialEq + 'a>(
+  left: impl Iterator<Item = &'a Item>, right: impl Iterator<Item = &'a Item>,
+) -> usize {
+  let right = right.collect::<Vec<_>>();
+  let mut previous = (0..=right.len()).collect::<Vec<_>>();
+  let mut current = vec![0_usize; right.len() + 1];
+  for (i, x) in left.enumerate() {
+    current[0] = i + 1;
+    for (j, y) in right.iter().enumerate() {
+      let cost = usize::from(x != *y);
+      current[j + 1] = (previous[j] + cost).min(previous[j + 1] + 1).min(current[j] + 1);
+    }
+    mem::swap(&mut previous, &mut current);
+  }
+  previous[right.len()]
+}
@@ -1,4 +1,7 @@
+use std::borrow::Cow;
 use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+use std::rc::Rc;
+use std::sync::Arc;
 
 /// Conversion into an [`Iterator`] over references.
 ///
@@ -129,6 +132,23 @@ impl<Item> Iterable for [Item] {
   }
 }
 
+impl<Item, const N: usize> Iterable for [Item; N] {
+  type Item<'c>
+    = &'c Item
+  where
+    Item: 'c;
+  type Iterator<'c>
+    = core::slice::Iter<'c, Item>
+  where
+    Item: 'c;
+
+  #[allow(clippy::needless_lifetimes)]
+  #[allow(clippy::elidable_lifetime_names)]
+  fn iterator<'c>(&'c self) -> Self::Iterator<'c> {
+    self.iter()
+  }
+}
+
 impl<Item> Iterable for Vec<Item> {
   type Item<'c>
     = &'c Item
@@ -146,6 +166,74 @@ impl<Item> Iterable for Vec<Item> {
   }
 }
 
+impl<Item> Iterable for Box<[Item]> {
+  type Item<'c>
+    = &'c Item
+  where
+    Item: 'c;
+  type Iterator<'c>
+    = core::slice::Iter<'c, Item>
+  where
+    Item: 'c;
+
+  #[allow(clippy::needless_lifetimes)]
+  #[allow(clippy::elidable_lifetime_names)]
+  fn iterator<'c>(&'c self) -> Self::Iterator<'c> {
+    self.iter()
+  }
+}
+
+impl<Item> Iterable for Rc<[Item]> {
+  type Item<'c>
+    = &'c Item
+  where
+    Item: 'c;
+  type Iterator<'c>
+    = core::slice::Iter<'c, Item>
+  where
+    Item: 'c;
+
+  #[allow(clippy::needless_lifetimes)]
+  #[allow(clippy::elidable_lifetime_names)]
+  fn iterator<'c>(&'c self) -> Self::Iterator<'c> {
+    self.iter()
+  }
+}
+
+impl<Item> Iterable for Arc<[Item]> {
+  type Item<'c>
+    = &'c Item
+  where
+    Item: 'c;
+  type Iterator<'c>
+    = core::slice::Iter<'c, Item>
+  where
+    Item: 'c;
+
+  #[allow(clippy::needless_lifetimes)]
+  #[allow(clippy::elidable_lifetime_names)]
+  fn iterator<'c>(&'c self) -> Self::Iterator<'c> {
+    self.iter()
+  }
+}
+
+impl<Item: Clone> Iterable for Cow<'_, [Item]> {
+  type Item<'c>
+    = &'c Item
+  where
+    Self: 'c;
+  type Iterator<'c>
+    = core::slice::Iter<'c, Item>
+  where
+    Self: 'c;
+
+  #[allow(clippy::needless_lifetimes)]
+  #[allow(clippy::elidable_lifetime_names)]
+  fn iterator<'c>(&'c self) -> Self::Iterator<'c> {
+    self.iter()
+  }
+}
+
 impl<Item> Iterable for LinkedList<Item> {
   type Item<'c>
     = &'c Item
@@ -180,17 +268,18 @@ impl<Item> Iterable for VecDeque<Item> {
   }
 }
 
-#[allow(clippy::implicit_hasher)]
 #[allow(clippy::elidable_lifetime_names)]
-impl<Item> Iterable for HashSet<Item> {
+impl<Item, S> Iterable for HashSet<Item, S> {
   type Item<'c>
     = &'c Item
   where
-    Item: 'c;
+    Item: 'c,
+    S: 'c;
   type Iterator<'c>
     = std::collections::hash_set::Iter<'c, Item>
   where
-    Item: 'c;
+    Item: 'c,
+    S: 'c;
 
   #[allow(clippy::needless_lifetimes)]
   fn iterator<'c>(&'c self) -> Self::Iterator<'c> {
@@ -232,19 +321,76 @@ impl<Item> Iterable for BinaryHeap<Item> {
   }
 }
 
-#[allow(clippy::implicit_hasher)]
+/// Strings are iterated over their characters.
+///
+/// Unlike other implementations, the characters are produced by value
+/// because a string does not store its content as a sequence of `char` values.
+///
+/// # Example
+///
+/// ```
+/// use crate::cantrip::*;
+///
+/// let a = "ab";
+/// let mut iter = a.iterator();
+///
+/// assert_eq!(Some('a'), iter.next());
+/// assert_eq!(Some('b'), iter.next());
+/// assert_eq!(None, iter.next());
+/// ```
+impl Iterable for str {
+  type Item<'c> = char;
+  type Iterator<'c> = core::str::Chars<'c>;
+
+  #[allow(clippy::needless_lifetimes)]
+  #[allow(clippy::elidable_lifetime_names)]
+  fn iterator<'c>(&'c self) -> Self::Iterator<'c> {
+    self.chars()
+  }
+}
+
+/// Strings are iterated over their characters.
+///
+/// Unlike other implementations, the characters are produced by value
+/// because a string does not store its content as a sequence of `char` values.
+///
+/// # Example
+///
+/// ```
+/// use crate::cantrip::*;
+///
+/// let a = String::from("ab");
+/// let mut iter = a.iterator();
+///
+/// assert_eq!(Some('a'), iter.next());
+/// assert_eq!(Some('b'), iter.next());
+/// assert_eq!(None, iter.next());
+/// ```
+impl Iterable for String {
+  type Item<'c> = char;
+  type Iterator<'c> = core::str::Chars<'c>;
+
+  #[allow(clippy::needless_lifetimes)]
+  #[allow(clippy::elidable_lifetime_names)]
+  fn iterator<'c>(&'c self) -> Self::Iterator<'c> {
+    self.chars()
+  }
+}
+
 #[allow(clippy::elidable_lifetime_names)]
-impl<Key, Value> Iterable for HashMap<Key, Value> {
+impl<Key, Value, S> Iterable for HashMap<Key, Value, S> {
   type Item<'c>
     = (&'c Key, &'c Value)
   where
     Key: 'c,
-    Value: 'c;
+    Value: 'c,
+    S: 'c;
   type Iterator<'c>
     = std::collections::hash_map::Iter<'c, Key, Value>
   where
     Key: 'c,
-    Value: 'c;
+    Value: 'c,
+    S: 'c;
 
   #[allow(clippy::needless_lifetimes)]
   fn iterator<'c>(&'c self) -> Self::Iterator<'c> {
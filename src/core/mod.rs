@@ -1,2 +1,9 @@
+pub(crate) mod arithmetic;
+pub(crate) mod constructible;
+pub(crate) mod diff;
+pub(crate) mod distance;
 pub(crate) mod iterable;
+pub(crate) mod ordering;
+pub(crate) mod parallel;
+pub(crate) mod statistics;
 pub(crate) mod unfold;
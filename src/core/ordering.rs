@@ -0,0 +1,127 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+use crate::Float;
+
+/// Treatment of NaN values by floating point ordering operations.
+///
+/// # Example
+///
+/// ```
+/// use cantrip::*;
+///
+/// let a = vec![1.0, f64::NAN, 3.0];
+///
+/// assert!(a.max_of_float(NanPolicy::Total).unwrap().is_nan());
+/// assert_eq!(a.max_of_float(NanPolicy::Skip), Some(&3.0));
+/// assert!(a.max_of_float(NanPolicy::Propagate).unwrap().is_nan());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum NanPolicy {
+  /// Orders NaN values according to the IEEE 754 `totalOrder` predicate.
+  ///
+  /// Positive NaN is greater than positive infinity and negative NaN is less than negative infinity.
+  #[default]
+  Total,
+  /// Ignores NaN values.
+  Skip,
+  /// Returns the first NaN value if there is any.
+  Propagate,
+}
+
+/// Floating point number wrapper providing a total order.
+///
+/// Values are compared according to the IEEE 754 `totalOrder` predicate which allows
+/// floating point numbers to be stored in sets and used with operations requiring [`Ord`].
+///
+/// # Example
+///
+/// ```
+/// use std::collections::BTreeSet;
+///
+/// use cantrip::*;
+///
+/// let a = BTreeSet::from([TotalOrder(2.0), TotalOrder(f64::NAN), TotalOrder(-1.0)]);
+///
+/// assert_eq!(a.min_of(), Some(&TotalOrder(-1.0)));
+/// assert!(a.max_of().unwrap().0.is_nan());
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TotalOrder<T>(pub T);
+
+impl<T> From<T> for TotalOrder<T> {
+  #[inline]
+  fn from(value: T) -> Self {
+    TotalOrder(value)
+  }
+}
+
+impl<T: Float> PartialEq for TotalOrder<T> {
+  #[inline]
+  fn eq(&self, other: &Self) -> bool {
+    self.0.total_cmp(&other.0) == Ordering::Equal
+  }
+}
+
+impl<T: Float> Eq for TotalOrder<T> {}
+
+impl<T: Float> PartialOrd for TotalOrder<T> {
+  #[inline]
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl<T: Float> Ord for TotalOrder<T> {
+  #[inline]
+  fn cmp(&self, other: &Self) -> Ordering {
+    self.0.total_cmp(&other.0)
+  }
+}
+
+impl Hash for TotalOrder<f32> {
+  #[inline]
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.0.to_bits().hash(state);
+  }
+}
+
+impl Hash for TotalOrder<f64> {
+  #[inline]
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.0.to_bits().hash(state);
+  }
+}
+
+pub(crate) fn minmax_float<T: Copy, Item: Float>(
+  iterator: impl Iterator<Item = T>, to_value: impl Fn(T) -> Item, nan: NanPolicy,
+) -> Option<(T, T)> {
+  let mut result: Option<(T, T)> = None;
+  for item in iterator {
+    let value = to_value(item);
+    if value.is_nan() {
+      match nan {
+        NanPolicy::Total => {}
+        NanPolicy::Skip => continue,
+        NanPolicy::Propagate => return Some((item, item)),
+      }
+    }
+    result = Some(match result {
+      Some((min, max)) => (
+        if value.total_cmp(&to_value(min)) == Ordering::Less { item } else { min },
+        if value.total_cmp(&to_value(max)) == Ordering::Less { max } else { item },
+      ),
+      None => (item, item),
+    });
+  }
+  result
+}
+
+pub(crate) fn float_order<Item: Float>(nan: NanPolicy, descending: bool) -> impl Fn(&Item, &Item) -> Ordering {
+  move |x, y| {
+    if nan == NanPolicy::Propagate && (x.is_nan() || y.is_nan()) {
+      return y.is_nan().cmp(&x.is_nan());
+    }
+    if descending { y.total_cmp(x) } else { x.total_cmp(y) }
+  }
+}
@@ -0,0 +1,112 @@
+use std::cmp::Ordering;
+use std::num::NonZeroUsize;
+use std::{panic, thread};
+
+/// Default minimum number of elements for which parallel operations use multiple threads.
+const DEFAULT_THRESHOLD: usize = 1024;
+
+/// Parallel processing settings.
+///
+/// Determines the maximum number of threads used by parallel operations and the minimum
+/// collection size for which the work is distributed between threads. Collections smaller
+/// than the threshold are processed sequentially on the calling thread.
+///
+/// The default settings use all available CPU cores for collections of at least `1024` elements.
+///
+/// # Example
+///
+/// ```
+/// use cantrip::*;
+///
+/// let parallelism = Parallelism { threads: 4, ..Parallelism::default() };
+///
+/// assert_eq!(parallelism.threads, 4);
+/// assert_eq!(parallelism.threshold, 1024);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Parallelism {
+  /// Maximum number of threads, values lower than `1` are treated as `1`.
+  pub threads: usize,
+  /// Minimum number of elements for which the work is distributed between threads.
+  pub threshold: usize,
+}
+
+impl Default for Parallelism {
+  #[inline]
+  fn default() -> Self {
+    Parallelism {
+      threads: thread::available_parallelism().map_or(1, NonZeroUsize::get),
+      threshold: DEFAULT_THRESHOLD,
+    }
+  }
+}
+
+/// Splits the values into contiguous chunks, applies the function to each chunk
+/// on a separate scoped thread and returns the results in the original chunk order.
+pub(crate) fn parallel_chunks<T, R>(
+  mut values: Vec<T>, parallelism: Parallelism, function: impl Fn(Vec<T>) -> R + Sync,
+) -> Vec<R>
+where
+  T: Send,
+  R: Send,
+{
+  let size = values.len();
+  let threads = parallelism.threads.min(size);
+  if threads <= 1 || size < parallelism.threshold {
+    return vec![function(values)];
+  }
+  let chunk_size = size.div_ceil(threads);
+  let mut chunks = Vec::with_capacity(threads);
+  while values.len() > chunk_size {
+    let chunk_start = (values.len() - 1) / chunk_size * chunk_size;
+    chunks.push(values.split_off(chunk_start));
+  }
+  chunks.push(values);
+  chunks.reverse();
+  let function = &function;
+  thread::scope(|scope| {
+    let handles = chunks.into_iter().map(|chunk| scope.spawn(move || function(chunk))).collect::<Vec<_>>();
+    handles.into_iter().map(|handle| handle.join().unwrap_or_else(|error| panic::resume_unwind(error))).collect()
+  })
+}
+
+/// Merges individually sorted chunks into a single sorted vector
+/// preserving the order of equal elements from earlier chunks first.
+pub(crate) fn merge_sorted_chunks<T>(mut chunks: Vec<Vec<T>>, compare: impl Fn(&T, &T) -> Ordering) -> Vec<T> {
+  while chunks.len() > 1 {
+    let mut merged = Vec::with_capacity(chunks.len().div_ceil(2));
+    let mut pairs = chunks.into_iter();
+    while let Some(left) = pairs.next() {
+      match pairs.next() {
+        Some(right) => merged.push(merge_sorted(left, right, &compare)),
+        None => merged.push(left),
+      }
+    }
+    chunks = merged;
+  }
+  chunks.pop().unwrap_or_default()
+}
+
+fn merge_sorted<T>(left: Vec<T>, right: Vec<T>, compare: &impl Fn(&T, &T) -> Ordering) -> Vec<T> {
+  let mut result = Vec::with_capacity(left.len() + right.len());
+  let mut left_iterator = left.into_iter().peekable();
+  let mut right_iterator = right.into_iter().peekable();
+  loop {
+    let take_right = match (left_iterator.peek(), right_iterator.peek()) {
+      (Some(left_item), Some(right_item)) => compare(right_item, left_item) == Ordering::Less,
+      (Some(_), None) => {
+        result.extend(left_iterator);
+        return result;
+      }
+      (None, _) => {
+        result.extend(right_iterator);
+        return result;
+      }
+    };
+    if take_right {
+      result.extend(right_iterator.next());
+    } else {
+      result.extend(left_iterator.next());
+    }
+  }
+}
@@ -0,0 +1,282 @@
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Descriptive statistics summary of numeric values.
+///
+/// Quartiles are computed using linear interpolation between the closest ranks.
+///
+/// # Example
+///
+/// ```
+/// use cantrip::*;
+///
+/// let a = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+///
+/// assert_eq!(a.summary(), Some(Summary {
+///   count: 5,
+///   min: 1.0,
+///   max: 5.0,
+///   mean: 3.0,
+///   lower_quartile: 2.0,
+///   median: 3.0,
+///   upper_quartile: 4.0,
+/// }));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Summary {
+  /// Number of values.
+  pub count: usize,
+  /// Minimum value.
+  pub min: f64,
+  /// Maximum value.
+  pub max: f64,
+  /// Arithmetic mean of the values.
+  pub mean: f64,
+  /// First quartile of the values.
+  pub lower_quartile: f64,
+  /// Median of the values.
+  pub median: f64,
+  /// Third quartile of the values.
+  pub upper_quartile: f64,
+}
+
+/// Error returned by pairwise operations when the paired sequences have different lengths.
+///
+/// # Example
+///
+/// ```
+/// use cantrip::*;
+///
+/// let a = vec![1, 2, 3];
+///
+/// assert_eq!(a.dot(&vec![1, 2]), Err(LengthMismatch { left: 3, right: 2 }));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LengthMismatch {
+  /// Length of this sequence.
+  pub left: usize,
+  /// Length of the other sequence.
+  pub right: usize,
+}
+
+impl Display for LengthMismatch {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "sequence lengths differ (left is {}, right is {})", self.left, self.right)
+  }
+}
+
+impl Error for LengthMismatch {}
+
+/// Computes the arithmetic mean using a running average which avoids overflowing intermediate sums.
+pub(crate) fn mean(values: impl Iterator<Item = f64>) -> Option<f64> {
+  moments(values).map(|(_, mean, _)| mean)
+}
+
+/// Computes the sample variance using Welford's online algorithm.
+#[allow(clippy::cast_precision_loss)]
+pub(crate) fn variance(values: impl Iterator<Item = f64>) -> Option<f64> {
+  moments(values).and_then(
+    |(count, _, squared_deviations)| {
+      if count < 2 { None } else { Some(squared_deviations / (count - 1) as f64) }
+    },
+  )
+}
+
+/// Finds the most frequent value, preferring the smallest one in case of a tie.
+pub(crate) fn mode(values: impl Iterator<Item = f64>) -> Option<f64> {
+  let sorted = sorted(values);
+  let mut result = None;
+  let mut result_count = 0_usize;
+  for run in sorted.chunk_by(|x, y| x.total_cmp(y) == Ordering::Equal) {
+    if run.len() > result_count {
+      result = Some(run[0]);
+      result_count = run.len();
+    }
+  }
+  result
+}
+
+/// Computes percentiles of values using linear interpolation between the closest ranks.
+pub(crate) fn percentiles(
+  values: impl Iterator<Item = f64>, percentages: impl IntoIterator<Item = f64>,
+) -> Option<Vec<f64>> {
+  let sorted = sorted(values);
+  let result = percentages
+    .into_iter()
+    .map(|percentage| {
+      assert!((0.0..=100.0).contains(&percentage), "percentile (is {percentage:?}) should be between 0 and 100");
+      quantile(&sorted, percentage / 100.0)
+    })
+    .collect::<Option<Vec<_>>>();
+  if sorted.is_empty() { None } else { result }
+}
+
+/// Computes a quantile of sorted values using linear interpolation between the closest ranks.
+#[allow(clippy::cast_precision_loss)]
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_sign_loss)]
+pub(crate) fn quantile(sorted: &[f64], q: f64) -> Option<f64> {
+  assert!((0.0..=1.0).contains(&q), "quantile (is {q:?}) should be between 0 and 1");
+  let last = sorted.len().checked_sub(1)?;
+  let rank = q * last as f64;
+  let lower = rank.floor() as usize;
+  let upper = (lower + 1).min(last);
+  let fraction = rank - lower as f64;
+  Some(sorted[lower] + (sorted[upper] - sorted[lower]) * fraction)
+}
+
+pub(crate) fn sorted(values: impl Iterator<Item = f64>) -> Vec<f64> {
+  let mut result = values.collect::<Vec<_>>();
+  result.sort_unstable_by(f64::total_cmp);
+  result
+}
+
+pub(crate) fn summary(values: impl Iterator<Item = f64>) -> Option<Summary> {
+  let sorted = sorted(values);
+  Some(Summary {
+    count: sorted.len(),
+    min: *sorted.first()?,
+    max: *sorted.last()?,
+    mean: mean(sorted.iter().copied())?,
+    lower_quartile: quantile(&sorted, 0.25)?,
+    median: quantile(&sorted, 0.5)?,
+    upper_quartile: quantile(&sorted, 0.75)?,
+  })
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn moments(values: impl Iterator<Item = f64>) -> Option<(usize, f64, f64)> {
+  let mut count = 0_usize;
+  let mut mean = 0.0;
+  let mut squared_deviations = 0.0;
+  for value in values {
+    count += 1;
+    let delta = value - mean;
+    mean += delta / count as f64;
+    squared_deviations += delta * (value - mean);
+  }
+  if count == 0 { None } else { Some((count, mean, squared_deviations)) }
+}
+
+/// Computes the sample covariance using a pairwise variant of Welford's online algorithm.
+#[allow(clippy::cast_precision_loss)]
+pub(crate) fn covariance(
+  left: impl Iterator<Item = f64>, right: impl Iterator<Item = f64>,
+) -> Result<Option<f64>, LengthMismatch> {
+  let (count, _, _, co_deviations) = co_moments(left, right)?;
+  Ok(if count < 2 { None } else { Some(co_deviations / (count - 1) as f64) })
+}
+
+pub(crate) fn dot(left: impl Iterator<Item = f64>, right: impl Iterator<Item = f64>) -> Result<f64, LengthMismatch> {
+  fold_pairs(left, right, 0.0, |result, _, x, y| result + x * y).map(|(_, result)| result)
+}
+
+pub(crate) fn mean_absolute_error(
+  left: impl Iterator<Item = f64>, right: impl Iterator<Item = f64>,
+) -> Result<Option<f64>, LengthMismatch> {
+  mean_of_pairs(left, right, |x, y| (x - y).abs())
+}
+
+/// Computes the Pearson correlation coefficient which is undefined if either sequence is constant.
+pub(crate) fn pearson_correlation(
+  left: impl Iterator<Item = f64>, right: impl Iterator<Item = f64>,
+) -> Result<Option<f64>, LengthMismatch> {
+  let (count, left_deviations, right_deviations, co_deviations) = co_moments(left, right)?;
+  let scale = (left_deviations * right_deviations).sqrt();
+  Ok(if count < 2 || scale == 0.0 { None } else { Some((co_deviations / scale).clamp(-1.0, 1.0)) })
+}
+
+pub(crate) fn root_mean_squared_error(
+  left: impl Iterator<Item = f64>, right: impl Iterator<Item = f64>,
+) -> Result<Option<f64>, LengthMismatch> {
+  mean_of_pairs(left, right, |x, y| (x - y) * (x - y)).map(|result| result.map(f64::sqrt))
+}
+
+/// Computes the Spearman rank correlation coefficient assigning tied values their average rank.
+pub(crate) fn spearman_correlation(
+  left: impl Iterator<Item = f64>, right: impl Iterator<Item = f64>,
+) -> Result<Option<f64>, LengthMismatch> {
+  let left = left.collect::<Vec<_>>();
+  let right = right.collect::<Vec<_>>();
+  if left.len() != right.len() {
+    return Err(LengthMismatch { left: left.len(), right: right.len() });
+  }
+  pearson_correlation(ranks(&left).into_iter(), ranks(&right).into_iter())
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn co_moments(
+  left: impl Iterator<Item = f64>, right: impl Iterator<Item = f64>,
+) -> Result<(usize, f64, f64, f64), LengthMismatch> {
+  let mut left_mean = 0.0;
+  let mut right_mean = 0.0;
+  fold_pairs(left, right, (0.0, 0.0, 0.0), |(left_deviations, right_deviations, co_deviations), index, x, y| {
+    let count = (index + 1) as f64;
+    let left_delta = x - left_mean;
+    let right_delta = y - right_mean;
+    left_mean += left_delta / count;
+    right_mean += right_delta / count;
+    (
+      left_deviations + left_delta * (x - left_mean),
+      right_deviations + right_delta * (y - right_mean),
+      co_deviations + left_delta * (y - right_mean),
+    )
+  })
+  .map(|(count, (left_deviations, right_deviations, co_deviations))| {
+    (count, left_deviations, right_deviations, co_deviations)
+  })
+}
+
+fn fold_pairs<B>(
+  mut left: impl Iterator<Item = f64>, mut right: impl Iterator<Item = f64>, initial_value: B,
+  mut function: impl FnMut(B, usize, f64, f64) -> B,
+) -> Result<(usize, B), LengthMismatch> {
+  let mut result = initial_value;
+  let mut count = 0_usize;
+  loop {
+    match (left.next(), right.next()) {
+      (Some(x), Some(y)) => {
+        result = function(result, count, x, y);
+        count += 1;
+      }
+      (None, None) => return Ok((count, result)),
+      (Some(_), None) => {
+        return Err(LengthMismatch {
+          left: count + 1 + left.count(),
+          right: count,
+        });
+      }
+      (None, Some(_)) => {
+        return Err(LengthMismatch {
+          left: count,
+          right: count + 1 + right.count(),
+        });
+      }
+    }
+  }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn mean_of_pairs(
+  left: impl Iterator<Item = f64>, right: impl Iterator<Item = f64>, mut function: impl FnMut(f64, f64) -> f64,
+) -> Result<Option<f64>, LengthMismatch> {
+  fold_pairs(left, right, 0.0, |mean, index, x, y| mean + (function(x, y) - mean) / (index + 1) as f64)
+    .map(|(count, mean)| if count == 0 { None } else { Some(mean) })
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn ranks(values: &[f64]) -> Vec<f64> {
+  let mut indices = (0..values.len()).collect::<Vec<_>>();
+  indices.sort_unstable_by(|&x, &y| values[x].total_cmp(&values[y]));
+  let mut result = vec![0.0; values.len()];
+  let mut start = 0;
+  for run in indices.chunk_by(|&x, &y| values[x].total_cmp(&values[y]) == Ordering::Equal) {
+    let rank = (2 * start + run.len() + 1) as f64 / 2.0;
+    for &index in run {
+      result[index] = rank;
+    }
+    start += run.len();
+  }
+  result
+}
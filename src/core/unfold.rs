@@ -20,3 +20,45 @@ where
     (self.function)()
   }
 }
+
+/// Creates an iterator which knows the exact number of remaining elements in advance.
+///
+/// The count is `None` if the total number of generated elements does not fit into `usize`
+/// in which case the size hint provides no bounds.
+pub(crate) fn unfold_sized<A, F>(count: Option<usize>, function: F) -> UnfoldSized<F>
+where
+  F: FnMut() -> Option<A>,
+{
+  UnfoldSized { function, remaining: count }
+}
+
+pub(crate) struct UnfoldSized<F> {
+  function: F,
+  remaining: Option<usize>,
+}
+
+impl<A, F> Iterator for UnfoldSized<F>
+where
+  F: FnMut() -> Option<A>,
+{
+  type Item = A;
+
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    let result = (self.function)();
+    if result.is_some()
+      && let Some(remaining) = &mut self.remaining
+    {
+      *remaining = remaining.saturating_sub(1);
+    }
+    result
+  }
+
+  #[inline]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    match self.remaining {
+      Some(remaining) => (remaining, Some(remaining)),
+      None => (0, None),
+    }
+  }
+}
@@ -0,0 +1,244 @@
+use std::cmp::Ordering;
+
+/// Array operations.
+///
+/// Methods have the following properties:
+///
+/// - Requires the collection to represent a fixed-size array
+/// - May consume the array and its elements
+/// - Creates a new array of the same length
+///
+/// Consuming element mapping is provided by the inherent [`map`](array::map) method of arrays.
+pub trait Array<Item, const N: usize> {
+  /// Creates a new array by applying the given closure `function` to each element
+  /// of the original array by reference.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = [1, 2, 3];
+  ///
+  /// assert_eq!(a.map_ref(|&x| x + 1), [2, 3, 4]);
+  /// ```
+  #[must_use]
+  fn map_ref<B>(&self, function: impl FnMut(&Item) -> B) -> [B; N];
+
+  /// Creates a new array by reversing this array's direction.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = [1, 2, 3];
+  ///
+  /// assert_eq!(a.rev(), [3, 2, 1]);
+  /// ```
+  #[must_use]
+  fn rev(self) -> Self;
+
+  /// An array adapter which, like [`fold()`], holds an internal state, but
+  /// unlike [`fold()`], produces a new array of the same length.
+  ///
+  /// `scan()` takes two arguments: an initial value which seeds the internal
+  /// state, and a closure with two arguments, the first being a mutable
+  /// reference to the internal state and the second an array element.
+  /// The closure can assign to the internal state to share state between iterations.
+  ///
+  /// On iteration, the closure will be applied to each element of the
+  /// array and the return value from the closure becomes the element
+  /// of the new array at the same position.
+  ///
+  /// [`fold()`]: crate::CollectionTo::fold
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = [1, 2, 3];
+  ///
+  /// assert_eq!(a.scan(1, |state, x| {
+  ///   *state = *state * x;
+  ///   -*state
+  /// }), [-1, -2, -6]);
+  /// ```
+  #[must_use]
+  fn scan<S, B>(self, initial_state: S, function: impl FnMut(&mut S, Item) -> B) -> [B; N];
+
+  /// An array adapter which, like [`fold_ref()`], holds an internal state, but
+  /// unlike [`fold_ref()`], produces a new array of the same length.
+  ///
+  /// `scan_ref()` takes two arguments: an initial value which seeds the internal
+  /// state, and a closure with two arguments, the first being a mutable
+  /// reference to the internal state and the second an array element reference.
+  /// The closure can assign to the internal state to share state between iterations.
+  ///
+  /// [`fold_ref()`]: crate::Collection::fold_ref
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = [1, 2, 3];
+  ///
+  /// assert_eq!(a.scan_ref(1, |state, &x| {
+  ///   *state = *state * x;
+  ///   -*state
+  /// }), [-1, -2, -6]);
+  /// ```
+  #[must_use]
+  fn scan_ref<S, B>(&self, initial_state: S, function: impl FnMut(&mut S, &Item) -> B) -> [B; N];
+
+  /// Creates a new array by sorting this array.
+  ///
+  /// This sort is stable (i.e., does not reorder equal elements) and *O*(*n* \* log(*n*)) worst-case.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = [2, 3, 1];
+  ///
+  /// assert_eq!(a.sorted(), [1, 2, 3]);
+  /// ```
+  #[must_use]
+  fn sorted(self) -> Self
+  where
+    Item: Ord;
+
+  /// Creates a new array by sorting this array with a comparator function.
+  ///
+  /// This sort is stable (i.e., does not reorder equal elements) and *O*(*n* \* log(*n*)) worst-case.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = [2, 3, 1];
+  ///
+  /// assert_eq!(a.sorted_by(|x, y| y.cmp(x)), [3, 2, 1]);
+  /// ```
+  #[must_use]
+  fn sorted_by(self, compare: impl FnMut(&Item, &Item) -> Ordering) -> Self;
+
+  /// Creates a new array by sorting this array with a key extraction function.
+  ///
+  /// This sort is stable (i.e., does not reorder equal elements) and *O*(*m* \* *n* \* log(*n*))
+  /// worst-case, where the key function is *O*(*m*).
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = [2, 3, 1];
+  ///
+  /// assert_eq!(a.sorted_by_key(|&k| -k), [3, 2, 1]);
+  /// ```
+  #[must_use]
+  fn sorted_by_key<K: Ord>(self, to_key: impl FnMut(&Item) -> K) -> Self;
+
+  /// Creates a new array by sorting this array, but might not preserve the order of equal elements.
+  ///
+  /// This sort is unstable (i.e., may reorder equal elements), in-place
+  /// (i.e., does not allocate), and *O*(*n* \* log(*n*)) worst-case.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = [2, 3, 1];
+  ///
+  /// assert_eq!(a.sorted_unstable(), [1, 2, 3]);
+  /// ```
+  #[must_use]
+  fn sorted_unstable(self) -> Self
+  where
+    Item: Ord;
+
+  /// Creates a new array by sorting this array with a comparator function,
+  /// but might not preserve the order of equal elements.
+  ///
+  /// This sort is unstable (i.e., may reorder equal elements), in-place
+  /// (i.e., does not allocate), and *O*(*n* \* log(*n*)) worst-case.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = [2, 3, 1];
+  ///
+  /// assert_eq!(a.sorted_unstable_by(|x, y| y.cmp(x)), [3, 2, 1]);
+  /// ```
+  #[must_use]
+  fn sorted_unstable_by(self, compare: impl FnMut(&Item, &Item) -> Ordering) -> Self;
+
+  /// Creates a new array by sorting this array with a key extraction function,
+  /// but might not preserve the order of equal elements.
+  ///
+  /// This sort is unstable (i.e., may reorder equal elements), in-place
+  /// (i.e., does not allocate), and *O*(*m* \* *n* \* log(*n*)) worst-case,
+  /// where the key function is *O*(*m*).
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = [2, 3, 1];
+  ///
+  /// assert_eq!(a.sorted_unstable_by_key(|&k| -k), [3, 2, 1]);
+  /// ```
+  #[must_use]
+  fn sorted_unstable_by_key<K: Ord>(self, to_key: impl FnMut(&Item) -> K) -> Self;
+
+  /// Creates a new array by replacing an element at the specified index
+  /// in this array.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `index` is out of bounds.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = [1, 2, 3];
+  ///
+  /// assert_eq!(a.substitute_at(1, 4), [1, 4, 3]);
+  /// ```
+  #[must_use]
+  fn substitute_at(self, index: usize, replacement: Item) -> Self;
+
+  /// Creates a new array by swapping elements at specified indices
+  /// in this array.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `source_index` or `target_index` are out of bounds.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// # let a_source = [1, 2, 3];
+  /// let a = [1, 2, 3];
+  ///
+  /// assert_eq!(a.swap_at(0, 2), [3, 2, 1]);
+  ///
+  /// # let a = a_source.clone();
+  /// assert_eq!(a.swap_at(1, 1), [1, 2, 3]);
+  /// ```
+  #[must_use]
+  fn swap_at(self, source_index: usize, target_index: usize) -> Self;
+}
@@ -1,8 +1,10 @@
 use std::cmp::Ordering;
-use std::collections::{HashMap, HashSet};
-use std::hash::Hash;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::hash::{BuildHasher, Hash, RandomState};
 
-use crate::Iterable;
+use crate::core::ordering::minmax_float;
+use crate::core::statistics::{mean, mode, percentiles, quantile, sorted, summary, variance};
+use crate::{Float, Iterable, NanPolicy, Number, Summary};
 
 /// Non-consuming collection operations.
 ///
@@ -127,6 +129,66 @@ where
     disjoint(self.into_iter(), elements)
   }
 
+  /// Tests this collection and another collection have no elements in common.
+  ///
+  /// Returns `true` if any of the collections are empty.
+  ///
+  /// This is a variant of [`disjoint()`] for elements which can only be compared for equality.
+  /// It takes *O*(*n* \* *m*) time.
+  ///
+  /// [`disjoint()`]: Collection::disjoint
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1.0, 2.0, 3.0];
+  ///
+  /// assert!(a.disjoint_eq(&vec![4.0, 5.0]));
+  /// assert!(a.disjoint_eq(&vec![]));
+  ///
+  /// assert!(!a.disjoint_eq(&vec![3.0, 4.0]));
+  /// ```
+  #[inline]
+  #[must_use]
+  fn disjoint_eq<'a>(&'a self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> bool
+  where
+    Item: PartialEq + 'a,
+  {
+    !self.into_iter().any(|item| elements.iterator().any(|x| x == item))
+  }
+
+  /// Tests this collection and another collection have no elements in common.
+  ///
+  /// Returns `true` if any of the collections are empty.
+  ///
+  /// This is a variant of [`disjoint()`] for elements which are ordered but not hashable.
+  /// It takes *O*(*n* \* log(*n*) + *m* \* log(*n*)) time.
+  ///
+  /// [`disjoint()`]: Collection::disjoint
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3];
+  ///
+  /// assert!(a.disjoint_ord(&vec![4, 5]));
+  /// assert!(a.disjoint_ord(&vec![]));
+  ///
+  /// assert!(!a.disjoint_ord(&vec![3, 4]));
+  /// ```
+  #[must_use]
+  fn disjoint_ord<'a>(&'a self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> bool
+  where
+    Item: Ord + 'a,
+  {
+    let occurred = self.into_iter().collect::<BTreeSet<_>>();
+    occurred.is_empty() || !elements.iterator().any(|item| occurred.contains(item))
+  }
+
   /// Searches for an element of this collection that satisfies a predicate.
   ///
   /// `find()` takes a closure that returns `true` or `false`. It applies
@@ -347,16 +409,52 @@ where
   ///
   /// assert_eq!(a.group_fold_ref(|x| x % 2, 0, |acc, &x| acc + x), HashMap::from([(0, 2), (1, 4),]));
   /// ```
+  #[inline]
   #[must_use]
   fn group_fold_ref<K, B>(
-    &self, mut to_key: impl FnMut(&Item) -> K, initial_value: B, mut function: impl FnMut(B, &Item) -> B,
+    &self, to_key: impl FnMut(&Item) -> K, initial_value: B, function: impl FnMut(B, &Item) -> B,
   ) -> HashMap<K, B>
   where
     K: Eq + Hash,
     B: Clone,
+  {
+    self.group_fold_ref_with_hasher(to_key, initial_value, function, RandomState::new())
+  }
+
+  /// Creates `HashMap` of keys mapped and folded to values according to
+  /// specified discriminator and folding operation functions
+  /// using the specified hash builder for the resulting map.
+  ///
+  /// This is a variant of [`group_fold_ref()`] supporting custom hashing algorithms.
+  ///
+  /// [`group_fold_ref()`]: Collection::group_fold_ref
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  /// use std::hash::{BuildHasherDefault, DefaultHasher};
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3];
+  /// let hash_builder = BuildHasherDefault::<DefaultHasher>::default();
+  ///
+  /// assert_eq!(
+  ///   a.group_fold_ref_with_hasher(|x| x % 2, 0, |acc, &x| acc + x, hash_builder),
+  ///   HashMap::from_iter([(0, 2), (1, 4)])
+  /// );
+  /// ```
+  #[must_use]
+  fn group_fold_ref_with_hasher<K, B, S>(
+    &self, mut to_key: impl FnMut(&Item) -> K, initial_value: B, mut function: impl FnMut(B, &Item) -> B,
+    hash_builder: S,
+  ) -> HashMap<K, B, S>
+  where
+    K: Eq + Hash,
+    B: Clone,
+    S: BuildHasher,
   {
     let iterator = self.into_iter();
-    let mut result = HashMap::with_capacity(iterator.size_hint().0);
+    let mut result = HashMap::with_capacity_and_hasher(iterator.size_hint().0, hash_builder);
     for item in iterator {
       let key = to_key(item);
       let new_value = match result.remove(&key) {
@@ -388,16 +486,51 @@ where
   ///
   /// assert_eq!(a.group_reduce_ref(|x| x % 2, |acc, x| acc + x), HashMap::from([(0, 2), (1, 4),]));
   /// ```
+  #[inline]
   #[must_use]
   fn group_reduce_ref<K>(
-    &self, mut to_key: impl FnMut(&Item) -> K, mut function: impl FnMut(&Item, &Item) -> Item,
+    &self, to_key: impl FnMut(&Item) -> K, function: impl FnMut(&Item, &Item) -> Item,
   ) -> HashMap<K, Item>
   where
     K: Eq + Hash,
     Item: Clone,
+  {
+    self.group_reduce_ref_with_hasher(to_key, function, RandomState::new())
+  }
+
+  /// Creates `HashMap` of keys mapped and reduced to values according to
+  /// specified discriminator and reducing operation functions
+  /// using the specified hash builder for the resulting map.
+  ///
+  /// This is a variant of [`group_reduce_ref()`] supporting custom hashing algorithms.
+  ///
+  /// [`group_reduce_ref()`]: Collection::group_reduce_ref
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  /// use std::hash::{BuildHasherDefault, DefaultHasher};
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3];
+  /// let hash_builder = BuildHasherDefault::<DefaultHasher>::default();
+  ///
+  /// assert_eq!(
+  ///   a.group_reduce_ref_with_hasher(|x| x % 2, |acc, x| acc + x, hash_builder),
+  ///   HashMap::from_iter([(0, 2), (1, 4)])
+  /// );
+  /// ```
+  #[must_use]
+  fn group_reduce_ref_with_hasher<K, S>(
+    &self, mut to_key: impl FnMut(&Item) -> K, mut function: impl FnMut(&Item, &Item) -> Item, hash_builder: S,
+  ) -> HashMap<K, Item, S>
+  where
+    K: Eq + Hash,
+    Item: Clone,
+    S: BuildHasher,
   {
     let iterator = self.into_iter();
-    let mut result = HashMap::with_capacity(iterator.size_hint().0);
+    let mut result = HashMap::with_capacity_and_hasher(iterator.size_hint().0, hash_builder);
     for item in iterator {
       let key = to_key(item);
       let new_value = match result.remove(&key) {
@@ -495,6 +628,140 @@ where
     self.max_by(Ord::cmp)
   }
 
+  /// Returns the maximum element of this collection of floating point numbers.
+  ///
+  /// This is a variant of [`max_of()`] for floating point numbers which do not implement [`Ord`].
+  /// If several elements are equally maximum, the last element is returned.
+  /// If the collection is empty or contains only skipped values, [`None`] is returned.
+  ///
+  /// NaN values are treated according to the specified [`NanPolicy`]:
+  ///
+  /// - [`NanPolicy::Total`] orders them using the IEEE 754 `totalOrder` predicate
+  /// - [`NanPolicy::Skip`] ignores them
+  /// - [`NanPolicy::Propagate`] returns the first of them
+  ///
+  /// [`max_of()`]: Collection::max_of
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1.0, 3.0, 2.0];
+  /// let e = Vec::<f64>::new();
+  ///
+  /// assert_eq!(a.max_of_float(NanPolicy::Total), Some(&3.0));
+  /// assert_eq!(vec![1.0, f64::NAN].max_of_float(NanPolicy::Skip), Some(&1.0));
+  ///
+  /// assert_eq!(e.max_of_float(NanPolicy::Total), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn max_of_float(&self, nan: NanPolicy) -> Option<&Item>
+  where
+    Item: Float,
+  {
+    minmax_float(self.into_iter(), |x| *x, nan).map(|(_, max)| max)
+  }
+
+  /// Computes the arithmetic mean of elements of this collection.
+  ///
+  /// Uses a running average which does not overflow for large values.
+  /// If the collection is empty, [`None`] is returned.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3, 4];
+  /// let e = Vec::<i32>::new();
+  ///
+  /// assert_eq!(a.mean(), Some(2.5));
+  ///
+  /// assert_eq!(e.mean(), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn mean(&self) -> Option<f64>
+  where
+    Item: Number,
+  {
+    self.mean_by_key(|x| x.as_f64())
+  }
+
+  /// Computes the arithmetic mean of key function values for elements of this collection.
+  ///
+  /// Uses a running average which does not overflow for large values.
+  /// If the collection is empty, [`None`] is returned.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![("a", 1), ("b", 2), ("c", 3), ("d", 4)];
+  /// let e = Vec::<(&str, i32)>::new();
+  ///
+  /// assert_eq!(a.mean_by_key(|&(_, x)| f64::from(x)), Some(2.5));
+  ///
+  /// assert_eq!(e.mean_by_key(|&(_, x)| f64::from(x)), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn mean_by_key(&self, to_key: impl FnMut(&Item) -> f64) -> Option<f64> {
+    mean(self.into_iter().map(to_key))
+  }
+
+  /// Computes the median of elements of this collection.
+  ///
+  /// If the number of elements is even, the mean of the two middle values is returned.
+  /// If the collection is empty, [`None`] is returned.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3, 4];
+  /// let e = Vec::<i32>::new();
+  ///
+  /// assert_eq!(a.median(), Some(2.5));
+  ///
+  /// assert_eq!(e.median(), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn median(&self) -> Option<f64>
+  where
+    Item: Number,
+  {
+    self.median_by_key(|x| x.as_f64())
+  }
+
+  /// Computes the median of key function values for elements of this collection.
+  ///
+  /// If the number of elements is even, the mean of the two middle values is returned.
+  /// If the collection is empty, [`None`] is returned.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![("a", 1), ("b", 2), ("c", 3), ("d", 4)];
+  /// let e = Vec::<(&str, i32)>::new();
+  ///
+  /// assert_eq!(a.median_by_key(|&(_, x)| f64::from(x)), Some(2.5));
+  ///
+  /// assert_eq!(e.median_by_key(|&(_, x)| f64::from(x)), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn median_by_key(&self, to_key: impl FnMut(&Item) -> f64) -> Option<f64> {
+    quantile(&sorted(self.into_iter().map(to_key)), 0.5)
+  }
+
   /// Returns the element that gives the minimum value with respect to the
   /// specified comparison function.
   ///
@@ -583,6 +850,42 @@ where
     self.min_by(Ord::cmp)
   }
 
+  /// Returns the minimum element of this collection of floating point numbers.
+  ///
+  /// This is a variant of [`min_of()`] for floating point numbers which do not implement [`Ord`].
+  /// If several elements are equally minimum, the first element is returned.
+  /// If the collection is empty or contains only skipped values, [`None`] is returned.
+  ///
+  /// NaN values are treated according to the specified [`NanPolicy`]:
+  ///
+  /// - [`NanPolicy::Total`] orders them using the IEEE 754 `totalOrder` predicate
+  /// - [`NanPolicy::Skip`] ignores them
+  /// - [`NanPolicy::Propagate`] returns the first of them
+  ///
+  /// [`min_of()`]: Collection::min_of
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![2.0, 1.0, 3.0];
+  /// let e = Vec::<f64>::new();
+  ///
+  /// assert_eq!(a.min_of_float(NanPolicy::Total), Some(&1.0));
+  /// assert!(vec![1.0, f64::NAN].min_of_float(NanPolicy::Propagate).unwrap().is_nan());
+  ///
+  /// assert_eq!(e.min_of_float(NanPolicy::Total), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn min_of_float(&self, nan: NanPolicy) -> Option<&Item>
+  where
+    Item: Float,
+  {
+    minmax_float(self.into_iter(), |x| *x, nan).map(|(min, _)| min)
+  }
+
   /// Returns the minimum and maximum element of this collection with respect to the
   /// specified comparison function.
   ///
@@ -660,6 +963,296 @@ where
     self.minmax_by(Ord::cmp)
   }
 
+  /// Returns the minimum and maximum element of this collection of floating point numbers.
+  ///
+  /// This is a variant of [`minmax_of()`] for floating point numbers which do not implement [`Ord`].
+  /// For the minimum, the first minimal element is returned. For the maximum,
+  /// the last maximal element is returned.
+  /// If the collection is empty or contains only skipped values, [`None`] is returned.
+  ///
+  /// NaN values are treated according to the specified [`NanPolicy`]:
+  ///
+  /// - [`NanPolicy::Total`] orders them using the IEEE 754 `totalOrder` predicate
+  /// - [`NanPolicy::Skip`] ignores them
+  /// - [`NanPolicy::Propagate`] returns the first of them
+  ///
+  /// [`minmax_of()`]: Collection::minmax_of
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![2.0, 1.0, f64::NAN, 3.0];
+  /// let e = Vec::<f64>::new();
+  ///
+  /// assert_eq!(a.minmax_of_float(NanPolicy::Skip), Some((&1.0, &3.0)));
+  ///
+  /// assert_eq!(e.minmax_of_float(NanPolicy::Skip), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn minmax_of_float(&self, nan: NanPolicy) -> Option<(&Item, &Item)>
+  where
+    Item: Float,
+  {
+    minmax_float(self.into_iter(), |x| *x, nan)
+  }
+
+  /// Computes the most frequent value of elements of this collection.
+  ///
+  /// If several values are equally frequent, the smallest one is returned.
+  /// If the collection is empty, [`None`] is returned.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3, 4];
+  /// let e = Vec::<i32>::new();
+  ///
+  /// assert_eq!(a.mode(), Some(1.0));
+  ///
+  /// assert_eq!(e.mode(), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn mode(&self) -> Option<f64>
+  where
+    Item: Number,
+  {
+    self.mode_by_key(|x| x.as_f64())
+  }
+
+  /// Computes the most frequent value of key function values for elements of this collection.
+  ///
+  /// If several values are equally frequent, the smallest one is returned.
+  /// If the collection is empty, [`None`] is returned.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![("a", 1), ("b", 2), ("c", 3), ("d", 4)];
+  /// let e = Vec::<(&str, i32)>::new();
+  ///
+  /// assert_eq!(a.mode_by_key(|&(_, x)| f64::from(x)), Some(1.0));
+  ///
+  /// assert_eq!(e.mode_by_key(|&(_, x)| f64::from(x)), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn mode_by_key(&self, to_key: impl FnMut(&Item) -> f64) -> Option<f64> {
+    mode(self.into_iter().map(to_key))
+  }
+
+  /// Finds the `n`-th smallest element of this collection according to a key function.
+  ///
+  /// The position `n` is zero-based, so `nth_by_key(0, to_key)` finds an element with the
+  /// smallest key. If several elements have an equal key, it is unspecified which one is returned.
+  /// If `n` is not smaller than the collection size, [`None`] is returned.
+  ///
+  /// This operation uses a selection algorithm with expected *O*(*n*) time
+  /// instead of sorting the whole collection.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![-3, 1, -2, 4];
+  ///
+  /// assert_eq!(a.nth_by_key(0, |x: &i32| x.abs()), Some(&1));
+  /// assert_eq!(a.nth_by_key(3, |x: &i32| x.abs()), Some(&4));
+  ///
+  /// assert_eq!(a.nth_by_key(4, |x: &i32| x.abs()), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn nth_by_key<K: Ord>(&self, n: usize, mut to_key: impl FnMut(&Item) -> K) -> Option<&Item> {
+    nth_by(self.into_iter(), n, |x, y| to_key(x).cmp(&to_key(y)))
+  }
+
+  /// Finds the `n`-th largest element of this collection.
+  ///
+  /// The position `n` is zero-based, so `nth_largest(0)` finds a maximum element.
+  /// If `n` is not smaller than the collection size, [`None`] is returned.
+  ///
+  /// This operation uses a selection algorithm with expected *O*(*n*) time
+  /// instead of sorting the whole collection.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![2, 4, 1, 3];
+  /// let e = Vec::<i32>::new();
+  ///
+  /// assert_eq!(a.nth_largest(0), Some(&4));
+  /// assert_eq!(a.nth_largest(2), Some(&2));
+  ///
+  /// assert_eq!(a.nth_largest(4), None);
+  /// assert_eq!(e.nth_largest(0), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn nth_largest(&self, n: usize) -> Option<&Item>
+  where
+    Item: Ord,
+  {
+    nth_by(self.into_iter(), n, |x, y| y.cmp(x))
+  }
+
+  /// Finds the `n`-th smallest element of this collection.
+  ///
+  /// The position `n` is zero-based, so `nth_smallest(0)` finds a minimum element.
+  /// If `n` is not smaller than the collection size, [`None`] is returned.
+  ///
+  /// This operation uses a selection algorithm with expected *O*(*n*) time
+  /// instead of sorting the whole collection.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![2, 4, 1, 3];
+  /// let e = Vec::<i32>::new();
+  ///
+  /// assert_eq!(a.nth_smallest(0), Some(&1));
+  /// assert_eq!(a.nth_smallest(2), Some(&3));
+  ///
+  /// assert_eq!(a.nth_smallest(4), None);
+  /// assert_eq!(e.nth_smallest(0), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn nth_smallest(&self, n: usize) -> Option<&Item>
+  where
+    Item: Ord,
+  {
+    nth_by(self.into_iter(), n, Ord::cmp)
+  }
+
+  /// Computes the specified percentiles of elements of this collection.
+  ///
+  /// Each percentile is computed using linear interpolation between the closest ranks.
+  /// If the collection is empty, [`None`] is returned.
+  ///
+  /// # Panics
+  ///
+  /// Panics if any of the `percentages` is not between 0 and 100.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3, 4];
+  /// let e = Vec::<i32>::new();
+  ///
+  /// assert_eq!(a.percentiles(vec![0.0, 50.0, 100.0]), Some(vec![1.0, 2.5, 4.0]));
+  ///
+  /// assert_eq!(e.percentiles(vec![0.0, 50.0, 100.0]), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn percentiles(&self, percentages: impl IntoIterator<Item = f64>) -> Option<Vec<f64>>
+  where
+    Item: Number,
+  {
+    self.percentiles_by_key(percentages, |x| x.as_f64())
+  }
+
+  /// Computes the specified percentiles of key function values for elements of this collection.
+  ///
+  /// Each percentile is computed using linear interpolation between the closest ranks.
+  /// If the collection is empty, [`None`] is returned.
+  ///
+  /// # Panics
+  ///
+  /// Panics if any of the `percentages` is not between 0 and 100.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![("a", 1), ("b", 2), ("c", 3), ("d", 4)];
+  /// let e = Vec::<(&str, i32)>::new();
+  ///
+  /// assert_eq!(a.percentiles_by_key(vec![0.0, 50.0, 100.0], |&(_, x)| f64::from(x)), Some(vec![1.0, 2.5, 4.0]));
+  ///
+  /// assert_eq!(e.percentiles_by_key(vec![0.0, 50.0, 100.0], |&(_, x)| f64::from(x)), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn percentiles_by_key(
+    &self, percentages: impl IntoIterator<Item = f64>, to_key: impl FnMut(&Item) -> f64,
+  ) -> Option<Vec<f64>> {
+    percentiles(self.into_iter().map(to_key), percentages)
+  }
+
+  /// Computes the `q`-th quantile of elements of this collection.
+  ///
+  /// The quantile is computed using linear interpolation between the closest ranks.
+  /// If the collection is empty, [`None`] is returned.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `q` is not between 0 and 1.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3, 4];
+  /// let e = Vec::<i32>::new();
+  ///
+  /// assert_eq!(a.quantile(0.25), Some(1.75));
+  ///
+  /// assert_eq!(e.quantile(0.25), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn quantile(&self, q: f64) -> Option<f64>
+  where
+    Item: Number,
+  {
+    self.quantile_by_key(q, |x| x.as_f64())
+  }
+
+  /// Computes the `q`-th quantile of key function values for elements of this collection.
+  ///
+  /// The quantile is computed using linear interpolation between the closest ranks.
+  /// If the collection is empty, [`None`] is returned.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `q` is not between 0 and 1.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![("a", 1), ("b", 2), ("c", 3), ("d", 4)];
+  /// let e = Vec::<(&str, i32)>::new();
+  ///
+  /// assert_eq!(a.quantile_by_key(0.25, |&(_, x)| f64::from(x)), Some(1.75));
+  ///
+  /// assert_eq!(e.quantile_by_key(0.25, |&(_, x)| f64::from(x)), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn quantile_by_key(&self, q: f64, to_key: impl FnMut(&Item) -> f64) -> Option<f64> {
+    quantile(&sorted(self.into_iter().map(to_key)), q)
+  }
+
   /// Reduces the elements to a single one by repeatedly applying a reducing
   /// operation.
   ///
@@ -700,6 +1293,53 @@ where
       .and_then(|value1| iterator.next().map(|value2| iterator.fold(function(value1, value2), |r, x| function(&r, x))))
   }
 
+  /// Computes the sample standard deviation of elements of this collection.
+  ///
+  /// If the collection contains fewer than two elements, [`None`] is returned.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3, 4];
+  /// let e = Vec::<i32>::new();
+  ///
+  /// assert_eq!(a.std_dev(), Some(1.2909944487358056));
+  ///
+  /// assert_eq!(e.std_dev(), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn std_dev(&self) -> Option<f64>
+  where
+    Item: Number,
+  {
+    self.std_dev_by_key(|x| x.as_f64())
+  }
+
+  /// Computes the sample standard deviation of key function values for elements of this collection.
+  ///
+  /// If the collection contains fewer than two elements, [`None`] is returned.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![("a", 1), ("b", 2), ("c", 3), ("d", 4)];
+  /// let e = Vec::<(&str, i32)>::new();
+  ///
+  /// assert_eq!(a.std_dev_by_key(|&(_, x)| f64::from(x)), Some(1.2909944487358056));
+  ///
+  /// assert_eq!(e.std_dev_by_key(|&(_, x)| f64::from(x)), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn std_dev_by_key(&self, to_key: impl FnMut(&Item) -> f64) -> Option<f64> {
+    variance(self.into_iter().map(to_key)).map(f64::sqrt)
+  }
+
   /// Tests if another collection contains all elements of this collection
   /// at least as many times as they appear in this collection.
   ///
@@ -734,6 +1374,59 @@ where
     subset(self.into_iter(), elements)
   }
 
+  /// Computes the descriptive statistics summary of elements of this collection.
+  ///
+  /// The [`Summary`] consists of the count, minimum, maximum, mean and quartiles.
+  /// If the collection is empty, [`None`] is returned.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3, 4];
+  /// let e = Vec::<i32>::new();
+  ///
+  /// let s = a.summary().unwrap();
+  ///
+  /// assert_eq!((s.count, s.min, s.max, s.median), (4, 1.0, 4.0, 2.5));
+  ///
+  /// assert_eq!(e.summary(), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn summary(&self) -> Option<Summary>
+  where
+    Item: Number,
+  {
+    self.summary_by_key(|x| x.as_f64())
+  }
+
+  /// Computes the descriptive statistics summary of key function values for elements of this collection.
+  ///
+  /// The [`Summary`] consists of the count, minimum, maximum, mean and quartiles.
+  /// If the collection is empty, [`None`] is returned.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![("a", 1), ("b", 2), ("c", 3), ("d", 4)];
+  /// let e = Vec::<(&str, i32)>::new();
+  ///
+  /// let s = a.summary_by_key(|&(_, x)| f64::from(x)).unwrap();
+  ///
+  /// assert_eq!((s.count, s.min, s.max, s.median), (4, 1.0, 4.0, 2.5));
+  ///
+  /// assert_eq!(e.summary_by_key(|&(_, x)| f64::from(x)), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn summary_by_key(&self, to_key: impl FnMut(&Item) -> f64) -> Option<Summary> {
+    summary(self.into_iter().map(to_key))
+  }
+
   /// Tests if this collection contains all elements of another collection
   /// at least as many times as they appear in the other collection.
   ///
@@ -767,6 +1460,87 @@ where
   {
     superset(self.into_iter(), elements)
   }
+
+  /// Folds every element into an accumulator by applying a fallible operation,
+  /// returning the final result.
+  ///
+  /// `try_fold_ref()` takes two arguments: an initial value, and a closure with two
+  /// arguments: an 'accumulator', and a reference to an element. The closure either
+  /// returns `Ok` with the value that the accumulator should have for the next iteration,
+  /// or `Err` which is propagated back to the caller immediately.
+  ///
+  /// This is a non-consuming variant of [`try_fold()`].
+  ///
+  /// [`try_fold()`]: crate::CollectionTo::try_fold
+  ///
+  /// # Errors
+  ///
+  /// Returns the first error produced by the closure,
+  /// without evaluating the remaining elements.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3];
+  ///
+  /// assert_eq!(a.try_fold_ref(0_i8, |acc, &x| acc.checked_add(x).ok_or(x)), Ok(6));
+  /// assert_eq!(a.try_fold_ref(127_i8, |acc, &x| acc.checked_add(x).ok_or(x)), Err(1));
+  /// ```
+  #[inline]
+  fn try_fold_ref<B, E>(&self, initial_value: B, function: impl FnMut(B, &Item) -> Result<B, E>) -> Result<B, E> {
+    self.into_iter().try_fold(initial_value, function)
+  }
+
+  /// Computes the sample variance of elements of this collection.
+  ///
+  /// Uses Welford's online algorithm which is numerically stable.
+  /// If the collection contains fewer than two elements, [`None`] is returned.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3, 4];
+  /// let e = Vec::<i32>::new();
+  ///
+  /// assert_eq!(a.variance(), Some(1.6666666666666667));
+  ///
+  /// assert_eq!(e.variance(), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn variance(&self) -> Option<f64>
+  where
+    Item: Number,
+  {
+    self.variance_by_key(|x| x.as_f64())
+  }
+
+  /// Computes the sample variance of key function values for elements of this collection.
+  ///
+  /// Uses Welford's online algorithm which is numerically stable.
+  /// If the collection contains fewer than two elements, [`None`] is returned.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![("a", 1), ("b", 2), ("c", 3), ("d", 4)];
+  /// let e = Vec::<(&str, i32)>::new();
+  ///
+  /// assert_eq!(a.variance_by_key(|&(_, x)| f64::from(x)), Some(1.6666666666666667));
+  ///
+  /// assert_eq!(e.variance_by_key(|&(_, x)| f64::from(x)), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn variance_by_key(&self, to_key: impl FnMut(&Item) -> f64) -> Option<f64> {
+    variance(self.into_iter().map(to_key))
+  }
 }
 
 pub(crate) fn frequencies<'a, Item: Eq + Hash + 'a>(
@@ -818,6 +1592,17 @@ pub(crate) fn minmax_by<'a, Item: 'a>(
   }
 }
 
+pub(crate) fn nth_by<'a, Item: 'a>(
+  iterator: impl Iterator<Item = &'a Item>, n: usize, mut compare: impl FnMut(&Item, &Item) -> Ordering,
+) -> Option<&'a Item> {
+  let mut values = iterator.collect::<Vec<_>>();
+  if n >= values.len() {
+    return None;
+  }
+  let (_, result, _) = values.select_nth_unstable_by(n, |x, y| compare(x, y));
+  Some(*result)
+}
+
 pub(crate) fn subset<'a, Item: Eq + Hash + 'a>(
   iterator: impl Iterator<Item = &'a Item>, elements: &'a impl Iterable<Item<'a> = &'a Item>,
 ) -> bool {
@@ -1,12 +1,18 @@
-use std::cmp::Reverse;
-use std::collections::{BinaryHeap, HashMap, LinkedList, VecDeque};
-use std::hash::Hash;
+use std::cell::RefCell;
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BTreeMap, BinaryHeap, HashMap, VecDeque};
+use std::hash::{BuildHasher, Hash, RandomState};
 use std::iter;
 use std::iter::{Product, Sum};
 
 use crate::Iterable;
-use crate::core::unfold::unfold;
+use crate::Parallelism;
+use crate::core::arithmetic::{checked_product, checked_sum, precise_sum, saturating_sum};
+use crate::core::ordering::float_order;
+use crate::core::parallel::parallel_chunks;
+use crate::core::unfold::{unfold, unfold_sized};
 use crate::extensions::{MAX_SIZE, collect_by_index, frequencies};
+use crate::{Constructible, Float, HasherConstructible, Integer, NanPolicy};
 
 /// Consuming collection operations.
 ///
@@ -62,6 +68,66 @@ where
     self.into_iter().chain(elements).collect()
   }
 
+  /// Multiplies all elements of this collection, returning [`None`] if the computation overflows.
+  ///
+  /// An empty collection returns the one value of the type.
+  /// This is an overflow-aware variant of [`product()`].
+  ///
+  /// [`product()`]: CollectionTo::product
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3];
+  /// let e = Vec::<i32>::new();
+  ///
+  /// assert_eq!(a.checked_product(), Some(6));
+  /// assert_eq!(vec![i32::MAX, 2].checked_product(), None);
+  ///
+  /// assert_eq!(e.checked_product(), Some(1));
+  /// ```
+  #[inline]
+  #[must_use]
+  fn checked_product(self) -> Option<Item>
+  where
+    Self: IntoIterator<Item = Item> + Sized,
+    Item: Integer,
+  {
+    checked_product(self.into_iter())
+  }
+
+  /// Sums all elements of this collection, returning [`None`] if the computation overflows.
+  ///
+  /// An empty collection returns the zero value of the type.
+  /// This is an overflow-aware variant of [`sum()`].
+  ///
+  /// [`sum()`]: CollectionTo::sum
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3];
+  /// let e = Vec::<i32>::new();
+  ///
+  /// assert_eq!(a.checked_sum(), Some(6));
+  /// assert_eq!(vec![i32::MAX, 1].checked_sum(), None);
+  ///
+  /// assert_eq!(e.checked_sum(), Some(0));
+  /// ```
+  #[inline]
+  #[must_use]
+  fn checked_sum(self) -> Option<Item>
+  where
+    Self: IntoIterator<Item = Item> + Sized,
+    Item: Integer,
+  {
+    checked_sum(self.into_iter())
+  }
+
   /// Transforms this collection into the specified collection type.
   ///
   /// `collect()` can take any collection and turn it into a relevant
@@ -213,11 +279,45 @@ where
   where
     Self: FromIterator<Item> + Sized,
     Item: Clone,
+  {
+    self.combinations_iter(k).collect()
+  }
+
+  /// Creates an iterator lazily generating combinations of specified size from the elements
+  /// of this collection.
+  ///
+  /// This is a lazy variant of [`combinations()`] which yields each combination on demand.
+  /// The size hint reports the exact number of remaining combinations unless it exceeds `usize::MAX`.
+  ///
+  /// [`combinations()`]: CollectionTo::combinations
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3];
+  /// let e = Vec::<i32>::new();
+  ///
+  /// let mut combinations = a.combinations_iter(2);
+  /// assert_eq!(combinations.size_hint(), (3, Some(3)));
+  /// assert_eq!(combinations.next(), Some(vec![1, 2]));
+  /// assert_eq!(combinations.size_hint(), (2, Some(2)));
+  /// assert_eq!(combinations.collect::<Vec<_>>(), vec![vec![1, 3], vec![2, 3]]);
+  ///
+  /// assert_eq!(a.combinations_iter(4).size_hint(), (0, Some(0)));
+  /// assert_eq!(e.combinations_iter(1).next(), None);
+  /// ```
+  fn combinations_iter<'a>(&'a self, k: usize) -> impl Iterator<Item = Self>
+  where
+    Self: FromIterator<Item> + Sized,
+    Item: Clone,
+    Item: 'a,
   {
     assert!(k <= MAX_SIZE, "k (is {k:?}) should be <= {MAX_SIZE:?})");
     let iterator = self.into_iter();
     let values = iterator.collect::<Vec<_>>();
-    compute_combinations(&values, k)
+    compute_combinations(values, k)
   }
 
   /// Creates a new collection from this collection without
@@ -286,24 +386,72 @@ where
     Self: IntoIterator<Item = Item> + FromIterator<Item>,
     Item: Eq + Hash + 'a,
   {
-    let mut deleted: HashMap<&Item, usize> = frequencies(elements.iterator());
-    self
-      .into_iter()
-      .filter(|x| {
-        if let Some(count) = deleted.get_mut(x)
-          && *count > 0
-        {
-          *count -= 1;
-          return false;
-        }
-        true
-      })
-      .collect()
+    self.into_iter().filter(multiset_filter(elements.iterator(), false)).collect()
+  }
+
+  /// Creates a new collection from this collection without
+  /// the first occurrences of elements found in another collection.
+  ///
+  /// The order of retained values is preserved for sequences.
+  ///
+  /// This is a variant of [`delete_multi()`] for elements which can only be compared for equality.
+  /// It takes *O*(*n* \* *m*) time.
+  ///
+  /// [`delete_multi()`]: CollectionTo::delete_multi
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1.0, 2.0, 2.0, 3.0];
+  ///
+  /// assert_eq!(a.delete_multi_eq(&vec![1.0, 2.0]), vec![2.0, 3.0]);
+  /// ```
+  #[must_use]
+  fn delete_multi_eq<'a>(self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> Self
+  where
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+    Item: PartialEq + 'a,
+  {
+    self.into_iter().filter(multiset_filter_eq(elements.iterator(), false)).collect()
+  }
+
+  /// Creates a new collection from this collection without
+  /// the first occurrences of elements found in another collection.
+  ///
+  /// The order of retained values is preserved for sequences.
+  ///
+  /// This is a variant of [`delete_multi()`] for elements which are ordered but not hashable.
+  ///
+  /// [`delete_multi()`]: CollectionTo::delete_multi
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 2, 3];
+  ///
+  /// assert_eq!(a.delete_multi_ord(&vec![1, 2]), vec![2, 3]);
+  /// ```
+  #[must_use]
+  fn delete_multi_ord<'a>(self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> Self
+  where
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+    Item: Ord + 'a,
+  {
+    self.into_iter().filter(multiset_filter_ord(elements.iterator(), false)).collect()
   }
 
   /// Creates a new collection containing a result of a function
   /// specified number of times.
   ///
+  /// Hash-based sets can only be created this way with the default hasher,
+  /// use [`fill_with_hasher()`] to specify a custom hasher.
+  ///
+  /// [`fill_with_hasher()`]: CollectionTo::fill_with_hasher
+  ///
   /// # Example
   ///
   /// ```
@@ -316,12 +464,42 @@ where
   #[must_use]
   fn fill_with(mut element: impl FnMut() -> Item, size: usize) -> Self
   where
-    Self: FromIterator<Item>,
+    Self: FromIterator<Item> + Constructible,
     Item: Clone,
   {
     iter::repeat_n(element(), size).collect()
   }
 
+  /// Creates a new hash-based set containing a result of a function
+  /// specified number of times using the specified hasher.
+  ///
+  /// This is a variant of [`fill_with()`] for hash-based sets with a custom hasher.
+  ///
+  /// [`fill_with()`]: CollectionTo::fill_with
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashSet;
+  /// use std::hash::RandomState;
+  ///
+  /// use cantrip::*;
+  ///
+  /// assert_eq!(HashSet::fill_with_hasher(|| 1, 2, RandomState::new()), HashSet::from([1]));
+  /// assert_eq!(HashSet::fill_with_hasher(|| 1, 0, RandomState::new()), HashSet::new());
+  /// ```
+  #[inline]
+  #[must_use]
+  fn fill_with_hasher<S>(mut element: impl FnMut() -> Item, size: usize, hash_builder: S) -> Self
+  where
+    Self: Extend<Item> + HasherConstructible<S>,
+    Item: Clone,
+  {
+    let mut result = Self::from_hasher(hash_builder);
+    result.extend(iter::repeat_n(element(), size));
+    result
+  }
+
   /// Creates a new collection by filtering this collection using a
   /// closure to determine if an element should be retained.
   ///
@@ -898,18 +1076,46 @@ where
   ///
   /// assert_eq!(a.group_by(|x| x % 2), HashMap::from([(0, vec![2]), (1, vec![1, 3])]));
   /// ```
+  #[inline]
   #[must_use]
-  fn group_by<K>(self, mut to_key: impl FnMut(&Item) -> K) -> HashMap<K, Self>
+  fn group_by<K>(self, to_key: impl FnMut(&Item) -> K) -> HashMap<K, Self>
   where
     Self: IntoIterator<Item = Item> + Default + Extend<Item>,
     K: Eq + Hash,
   {
-    let iterator = self.into_iter();
-    let mut result = HashMap::<K, Self>::with_capacity(iterator.size_hint().0);
-    for item in iterator {
-      result.entry(to_key(&item)).or_default().extend(iter::once(item));
-    }
-    result
+    self.group_by_with_hasher(to_key, RandomState::new())
+  }
+
+  /// Creates a map of keys mapped to collections of elements according to
+  /// the specified discriminator function using the specified hash builder
+  /// for the resulting map.
+  ///
+  /// This is a variant of [`group_by()`] supporting custom hashing algorithms.
+  ///
+  /// [`group_by()`]: CollectionTo::group_by
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  /// use std::hash::{BuildHasherDefault, DefaultHasher};
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3];
+  /// let hash_builder = BuildHasherDefault::<DefaultHasher>::default();
+  ///
+  /// assert_eq!(
+  ///   a.group_by_with_hasher(|x| x % 2, hash_builder),
+  ///   HashMap::from_iter([(0, vec![2]), (1, vec![1, 3])])
+  /// );
+  /// ```
+  #[must_use]
+  fn group_by_with_hasher<K, S>(self, to_key: impl FnMut(&Item) -> K, hash_builder: S) -> HashMap<K, Self, S>
+  where
+    Self: IntoIterator<Item = Item> + Default + Extend<Item>,
+    K: Eq + Hash,
+    S: BuildHasher,
+  {
+    group_by(self.into_iter(), to_key, hash_builder)
   }
 
   /// Creates a map of keys mapped and folded to values according to
@@ -932,17 +1138,53 @@ where
   ///
   /// assert_eq!(a.group_fold(|x| x % 2, 0, |acc, x| acc + x), HashMap::from([(0, 2), (1, 4),]));
   /// ```
+  #[inline]
   #[must_use]
   fn group_fold<K, B>(
-    self, mut to_key: impl FnMut(&Item) -> K, initial_value: B, mut function: impl FnMut(B, Item) -> B,
+    self, to_key: impl FnMut(&Item) -> K, initial_value: B, function: impl FnMut(B, Item) -> B,
   ) -> HashMap<K, B>
   where
     Self: IntoIterator<Item = Item> + Sized,
     K: Eq + Hash,
     B: Clone,
+  {
+    self.group_fold_with_hasher(to_key, initial_value, function, RandomState::new())
+  }
+
+  /// Creates a map of keys mapped and folded to values according to
+  /// specified discriminator and folding operation functions
+  /// using the specified hash builder for the resulting map.
+  ///
+  /// This is a variant of [`group_fold()`] supporting custom hashing algorithms.
+  ///
+  /// [`group_fold()`]: CollectionTo::group_fold
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  /// use std::hash::{BuildHasherDefault, DefaultHasher};
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3];
+  /// let hash_builder = BuildHasherDefault::<DefaultHasher>::default();
+  ///
+  /// assert_eq!(
+  ///   a.group_fold_with_hasher(|x| x % 2, 0, |acc, x| acc + x, hash_builder),
+  ///   HashMap::from_iter([(0, 2), (1, 4)])
+  /// );
+  /// ```
+  #[must_use]
+  fn group_fold_with_hasher<K, B, S>(
+    self, mut to_key: impl FnMut(&Item) -> K, initial_value: B, mut function: impl FnMut(B, Item) -> B, hash_builder: S,
+  ) -> HashMap<K, B, S>
+  where
+    Self: IntoIterator<Item = Item> + Sized,
+    K: Eq + Hash,
+    B: Clone,
+    S: BuildHasher,
   {
     let iterator = self.into_iter();
-    let mut result = HashMap::with_capacity(iterator.size_hint().0);
+    let mut result = HashMap::with_capacity_and_hasher(iterator.size_hint().0, hash_builder);
     for item in iterator {
       let key = to_key(&item);
       let new_value = if let Some(value) = result.remove(&key) {
@@ -975,16 +1217,49 @@ where
   ///
   /// assert_eq!(a.group_reduce(|x| x % 2, |acc, x| acc + x), HashMap::from([(0, 2), (1, 4),]));
   /// ```
+  #[inline]
+  #[must_use]
+  fn group_reduce<K>(self, to_key: impl FnMut(&Item) -> K, function: impl FnMut(Item, Item) -> Item) -> HashMap<K, Item>
+  where
+    Self: IntoIterator<Item = Item> + Sized,
+    K: Eq + Hash,
+  {
+    self.group_reduce_with_hasher(to_key, function, RandomState::new())
+  }
+
+  /// Creates `HashMap` of keys mapped and reduced to values according to
+  /// specified discriminator and reducing operation functions
+  /// using the specified hash builder for the resulting map.
+  ///
+  /// This is a variant of [`group_reduce()`] supporting custom hashing algorithms.
+  ///
+  /// [`group_reduce()`]: CollectionTo::group_reduce
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  /// use std::hash::{BuildHasherDefault, DefaultHasher};
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3];
+  /// let hash_builder = BuildHasherDefault::<DefaultHasher>::default();
+  ///
+  /// assert_eq!(
+  ///   a.group_reduce_with_hasher(|x| x % 2, |acc, x| acc + x, hash_builder),
+  ///   HashMap::from_iter([(0, 2), (1, 4)])
+  /// );
+  /// ```
   #[must_use]
-  fn group_reduce<K>(
-    self, mut to_key: impl FnMut(&Item) -> K, mut function: impl FnMut(Item, Item) -> Item,
-  ) -> HashMap<K, Item>
+  fn group_reduce_with_hasher<K, S>(
+    self, mut to_key: impl FnMut(&Item) -> K, mut function: impl FnMut(Item, Item) -> Item, hash_builder: S,
+  ) -> HashMap<K, Item, S>
   where
     Self: IntoIterator<Item = Item> + Sized,
     K: Eq + Hash,
+    S: BuildHasher,
   {
     let iterator = self.into_iter();
-    let mut result = HashMap::with_capacity(iterator.size_hint().0);
+    let mut result = HashMap::with_capacity_and_hasher(iterator.size_hint().0, hash_builder);
     for item in iterator {
       let key = to_key(&item);
       let new_value = if let Some(value) = result.remove(&key) { function(value, item) } else { item };
@@ -1032,97 +1307,226 @@ where
     Self: IntoIterator<Item = Item> + FromIterator<Item>,
     Item: Eq + Hash + 'a,
   {
-    let mut retained: HashMap<&Item, usize> = frequencies(elements.iterator());
-    self
-      .into_iter()
-      .filter_map(|item| {
-        if let Some(count) = retained.get_mut(&item)
-          && *count > 0
-        {
-          *count -= 1;
-          return Some(item);
-        }
-        None
-      })
-      .collect()
+    self.into_iter().filter(multiset_filter(elements.iterator(), true)).collect()
   }
 
-  /// Creates a new collection containing the n largest elements of
-  /// this collection in descending order.
+  /// Creates a new collection containing the first occurrences of elements
+  /// of this collection that are also found in another collection.
+  ///
+  /// The order of retained values is preserved for sequences.
+  ///
+  /// This is a variant of [`intersect()`] for elements which can only be compared for equality.
+  /// It takes *O*(*n* \* *m*) time.
+  ///
+  /// [`intersect()`]: CollectionTo::intersect
   ///
   /// # Example
   ///
   /// ```
   /// use cantrip::*;
   ///
-  /// # let a_source = vec![2, 1, 3];
-  /// let a = vec![2, 1, 3];
-  /// let e = Vec::<i32>::new();
+  /// let a = vec![1.0, 2.0, 2.0, 3.0];
   ///
-  /// assert_eq!(a.largest(2), vec![3, 2]);
-  /// # let a = a_source.clone();
-  /// assert_eq!(a.largest(4), vec![3, 2, 1]);
-  /// assert_eq!(e.largest(3), vec![]);
+  /// assert_eq!(a.intersect_eq(&vec![4.0, 3.0, 2.0, 2.0, 5.0]), vec![2.0, 2.0, 3.0]);
   /// ```
   #[must_use]
-  fn largest(self, n: usize) -> Self
+  fn intersect_eq<'a>(self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> Self
   where
     Self: IntoIterator<Item = Item> + FromIterator<Item>,
-    Item: Ord,
+    Item: PartialEq + 'a,
   {
-    let mut iterator = self.into_iter();
-    let mut heap = iterator.by_ref().map(|x| Reverse(x)).take(n).collect::<BinaryHeap<_>>();
-    for item in iterator {
-      if let Some(mut top) = heap.peek_mut()
-        && item > top.0
-      {
-        *top = Reverse(item);
-      }
-    }
-    let result = unfold(|| heap.pop()).map(|x| x.0).collect::<Vec<_>>();
-    result.into_iter().rev().collect()
+    self.into_iter().filter(multiset_filter_eq(elements.iterator(), true)).collect()
   }
 
-  /// Creates a new collection by applying the given closure `function` to each element in
-  /// this collection.
-  ///
-  /// The closure `function` takes a reference to an element of type
-  /// `Item` and returns a value of type `R`.
-  /// The resulting elements are collected into a new collection of the same type.
-  ///
-  /// This is a consuming variant of [`map_ref()`].
-  ///
-  /// [`map_ref()`]: CollectionTo::map_ref
-  ///
-  /// # Arguments
-  ///
-  /// * `self` - the collection to apply the mapping to.
-  /// * `function` - the closure to apply to each element.
-  ///
-  /// # Returns
+  /// Creates a new collection containing the first occurrences of elements
+  /// of this collection that are also found in another collection.
   ///
-  /// A new collection of the same type, containing the mapped elements.
+  /// The order of retained values is preserved for sequences.
   ///
-  /// # Safety
+  /// This is a variant of [`intersect()`] for elements which are ordered but not hashable.
   ///
-  /// The caller must ensure that the closure does not mutate any shared state while being executed.
-  /// The closure must not panic while being executed, as this will lead to undefined behavior.
+  /// [`intersect()`]: CollectionTo::intersect
   ///
   /// # Example
   ///
   /// ```
   /// use cantrip::*;
   ///
-  /// let a = vec![1, 2, 3];
+  /// let a = vec![1, 2, 2, 3];
   ///
-  /// assert_eq!(a.map(|x| x + 1), vec![2, 3, 4]);
+  /// assert_eq!(a.intersect_ord(&vec![4, 3, 2, 2, 5]), vec![2, 2, 3]);
   /// ```
-  #[inline]
   #[must_use]
-  fn map<B>(self, function: impl FnMut(Item) -> B) -> Self::This<B>
+  fn intersect_ord<'a>(self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> Self
   where
-    Self: IntoIterator<Item = Item> + Sized,
-    Self::This<B>: FromIterator<B>,
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+    Item: Ord + 'a,
+  {
+    self.into_iter().filter(multiset_filter_ord(elements.iterator(), true)).collect()
+  }
+
+  /// Creates a new collection containing the n largest elements of
+  /// this collection in descending order.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// # let a_source = vec![2, 1, 3];
+  /// let a = vec![2, 1, 3];
+  /// let e = Vec::<i32>::new();
+  ///
+  /// assert_eq!(a.largest(2), vec![3, 2]);
+  /// # let a = a_source.clone();
+  /// assert_eq!(a.largest(4), vec![3, 2, 1]);
+  /// assert_eq!(e.largest(3), vec![]);
+  /// ```
+  #[must_use]
+  fn largest(self, n: usize) -> Self
+  where
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+    Item: Ord,
+  {
+    largest(self.into_iter(), n).into_iter().collect()
+  }
+
+  /// Creates a new collection containing the n largest elements of
+  /// this collection in descending order with respect to the specified comparison function.
+  ///
+  /// It takes *O*(*n* \* log(*k*)) time and *O*(*k*) memory, where *k* is the number of selected elements.
+  /// Equal elements are returned in their original order.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// # let a_source = vec![2, 1, 3];
+  /// let a = vec![2, 1, 3];
+  /// let e = Vec::<i32>::new();
+  ///
+  /// assert_eq!(a.largest_by(2, |x, y| x.cmp(y)), vec![3, 2]);
+  /// # let a = a_source.clone();
+  /// assert_eq!(a.largest_by(4, |x, y| x.cmp(y)), vec![3, 2, 1]);
+  /// assert_eq!(e.largest_by(3, |x, y| x.cmp(y)), vec![]);
+  /// ```
+  #[must_use]
+  fn largest_by(self, n: usize, mut compare: impl FnMut(&Item, &Item) -> Ordering) -> Self
+  where
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+  {
+    smallest_by(self.into_iter(), n, |x, y| compare(y, x)).into_iter().collect()
+  }
+
+  /// Creates a new collection containing the n largest elements of
+  /// this collection in descending order with respect to the specified key function.
+  ///
+  /// It takes *O*(*n* \* log(*k*)) time and *O*(*k*) memory, where *k* is the number of selected elements.
+  /// Equal elements are returned in their original order.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// # let a_source = vec![("a", 2), ("b", 1), ("c", 3)];
+  /// let a = vec![("a", 2), ("b", 1), ("c", 3)];
+  /// let e = Vec::<(&str, i32)>::new();
+  ///
+  /// assert_eq!(a.largest_by_key(2, |&(_, x)| x), vec![("c", 3), ("a", 2)]);
+  /// # let a = a_source.clone();
+  /// assert_eq!(a.largest_by_key(4, |&(_, x)| x), vec![("c", 3), ("a", 2), ("b", 1)]);
+  /// assert_eq!(e.largest_by_key(3, |&(_, x)| x), vec![]);
+  /// ```
+  #[must_use]
+  fn largest_by_key<K>(self, n: usize, mut to_key: impl FnMut(&Item) -> K) -> Self
+  where
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+    K: Ord,
+  {
+    smallest_by(self.into_iter(), n, |x, y| to_key(y).cmp(&to_key(x))).into_iter().collect()
+  }
+
+  /// Creates a new collection containing the n largest elements of
+  /// this collection of floating point numbers in descending order.
+  ///
+  /// This is a variant of [`largest()`] for floating point numbers which do not implement [`Ord`].
+  ///
+  /// NaN values are treated according to the specified [`NanPolicy`]:
+  ///
+  /// - [`NanPolicy::Total`] orders them using the IEEE 754 `totalOrder` predicate
+  /// - [`NanPolicy::Skip`] omits them
+  /// - [`NanPolicy::Propagate`] places them first in their original order
+  ///
+  /// [`largest()`]: CollectionTo::largest
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// # let a_source = vec![2.0, 1.0, 3.0];
+  /// let a = vec![2.0, 1.0, 3.0];
+  /// let e = Vec::<f64>::new();
+  ///
+  /// assert_eq!(a.largest_floats(2, NanPolicy::Total), vec![3.0, 2.0]);
+  /// # let a = a_source.clone();
+  /// assert_eq!(a.largest_floats(4, NanPolicy::Total), vec![3.0, 2.0, 1.0]);
+  /// assert_eq!(vec![1.0, f64::NAN, 2.0].largest_floats(2, NanPolicy::Skip), vec![2.0, 1.0]);
+  /// assert!(vec![1.0, f64::NAN, 2.0].largest_floats(1, NanPolicy::Propagate)[0].is_nan());
+  /// assert_eq!(e.largest_floats(3, NanPolicy::Total), vec![]);
+  /// ```
+  #[must_use]
+  fn largest_floats(self, n: usize, nan: NanPolicy) -> Self
+  where
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+    Item: Float,
+  {
+    let iterator = self.into_iter().filter(|x| nan != NanPolicy::Skip || !x.is_nan());
+    smallest_by(iterator, n, float_order(nan, true)).into_iter().collect()
+  }
+
+  /// Creates a new collection by applying the given closure `function` to each element in
+  /// this collection.
+  ///
+  /// The closure `function` takes a reference to an element of type
+  /// `Item` and returns a value of type `R`.
+  /// The resulting elements are collected into a new collection of the same type.
+  ///
+  /// This is a consuming variant of [`map_ref()`].
+  ///
+  /// [`map_ref()`]: CollectionTo::map_ref
+  ///
+  /// # Arguments
+  ///
+  /// * `self` - the collection to apply the mapping to.
+  /// * `function` - the closure to apply to each element.
+  ///
+  /// # Returns
+  ///
+  /// A new collection of the same type, containing the mapped elements.
+  ///
+  /// # Safety
+  ///
+  /// The caller must ensure that the closure does not mutate any shared state while being executed.
+  /// The closure must not panic while being executed, as this will lead to undefined behavior.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3];
+  ///
+  /// assert_eq!(a.map(|x| x + 1), vec![2, 3, 4]);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn map<B>(self, function: impl FnMut(Item) -> B) -> Self::This<B>
+  where
+    Self: IntoIterator<Item = Item> + Sized,
+    Self::This<B>: FromIterator<B>,
   {
     self.into_iter().map(function).collect()
   }
@@ -1170,6 +1574,175 @@ where
     self.into_iter().map(function).collect()
   }
 
+  /// Creates a new collection by applying the given fallible closure `function`
+  /// to each element in this collection, collecting all errors.
+  ///
+  /// Unlike [`try_map()`], the closure is applied to every element even after
+  /// an error occurs, so that all errors can be reported at once.
+  ///
+  /// The order of errors is preserved for sequences.
+  /// To obtain the errors together with their element positions for sequences,
+  /// use [`map_validated_indexed()`].
+  ///
+  /// [`try_map()`]: CollectionTo::try_map
+  /// [`map_validated_indexed()`]: crate::SequenceTo::map_validated_indexed
+  ///
+  /// # Errors
+  ///
+  /// Returns a collection of all errors produced by the closure if there are any.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec!["1", "2", "3"];
+  /// let b = vec!["1", "x", "3", "y"];
+  ///
+  /// assert_eq!(a.map_validated(|x| x.parse::<i32>().map_err(|_| x)), Ok(vec![1, 2, 3]));
+  /// assert_eq!(b.map_validated(|x| x.parse::<i32>().map_err(|_| x)), Err(vec!["x", "y"]));
+  /// ```
+  fn map_validated<B, E>(self, mut function: impl FnMut(Item) -> Result<B, E>) -> Result<Self::This<B>, Self::This<E>>
+  where
+    Self: IntoIterator<Item = Item> + Sized,
+    Self::This<B>: Default + Extend<B>,
+    Self::This<E>: Default + Extend<E>,
+  {
+    let mut result: Self::This<B> = Self::This::default();
+    let mut errors: Option<Self::This<E>> = None;
+    for item in self {
+      match function(item) {
+        Ok(value) => {
+          if errors.is_none() {
+            result.extend(iter::once(value));
+          }
+        }
+        Err(error) => errors.get_or_insert_with(Self::This::default).extend(iter::once(error)),
+      }
+    }
+    errors.map_or(Ok(result), Err)
+  }
+
+  /// Creates a new collection by filtering this collection using a closure
+  /// evaluated on multiple threads to determine if an element should be retained.
+  ///
+  /// Produces the same result as [`filter()`]. The elements are divided into
+  /// contiguous chunks processed in parallel according to the specified
+  /// [`Parallelism`] settings.
+  ///
+  /// The order of retained values is preserved for sequences.
+  ///
+  /// [`filter()`]: CollectionTo::filter
+  /// [`Parallelism`]: crate::Parallelism
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3];
+  /// let parallelism = Parallelism { threads: 2, threshold: 0 };
+  ///
+  /// assert_eq!(a.par_filter(parallelism, |&x| x != 2), vec![1, 3]);
+  /// ```
+  #[must_use]
+  fn par_filter(self, parallelism: Parallelism, predicate: impl Fn(&Item) -> bool + Sync) -> Self
+  where
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+    Item: Send,
+  {
+    let values = self.into_iter().collect::<Vec<_>>();
+    parallel_chunks(values, parallelism, |chunk| chunk.into_iter().filter(&predicate).collect::<Vec<_>>())
+      .into_iter()
+      .flatten()
+      .collect()
+  }
+
+  /// Creates a map of keys mapped to collections of elements according to
+  /// the specified discriminator function evaluated on multiple threads.
+  ///
+  /// Produces the same result as [`group_by()`]. The elements are divided into
+  /// contiguous chunks processed in parallel according to the specified
+  /// [`Parallelism`] settings.
+  ///
+  /// The order of grouped values is preserved for sequences.
+  ///
+  /// [`group_by()`]: CollectionTo::group_by
+  /// [`Parallelism`]: crate::Parallelism
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3];
+  /// let parallelism = Parallelism { threads: 2, threshold: 0 };
+  ///
+  /// assert_eq!(a.par_group_by(parallelism, |x| x % 2), HashMap::from([(0, vec![2]), (1, vec![1, 3])]));
+  /// ```
+  #[must_use]
+  fn par_group_by<K>(self, parallelism: Parallelism, to_key: impl Fn(&Item) -> K + Sync) -> HashMap<K, Self>
+  where
+    Self: IntoIterator<Item = Item> + Default + Extend<Item>,
+    K: Eq + Hash + Send,
+    Item: Send,
+  {
+    let values = self.into_iter().collect::<Vec<_>>();
+    let partial_groups = parallel_chunks(values, parallelism, |chunk| {
+      let mut groups = HashMap::<K, Vec<Item>>::new();
+      for item in chunk {
+        groups.entry(to_key(&item)).or_default().push(item);
+      }
+      groups
+    });
+    let mut result = HashMap::<K, Self>::new();
+    for groups in partial_groups {
+      for (key, items) in groups {
+        result.entry(key).or_default().extend(items);
+      }
+    }
+    result
+  }
+
+  /// Creates a new collection by applying the given closure `function` to each element
+  /// of this collection on multiple threads.
+  ///
+  /// Produces the same result as [`map()`]. The elements are divided into
+  /// contiguous chunks processed in parallel according to the specified
+  /// [`Parallelism`] settings.
+  ///
+  /// The order of mapped values is preserved for sequences.
+  ///
+  /// [`map()`]: CollectionTo::map
+  /// [`Parallelism`]: crate::Parallelism
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3];
+  /// let parallelism = Parallelism { threads: 2, threshold: 0 };
+  ///
+  /// assert_eq!(a.par_map(parallelism, |x| x + 1), vec![2, 3, 4]);
+  /// ```
+  #[must_use]
+  fn par_map<B>(self, parallelism: Parallelism, function: impl Fn(Item) -> B + Sync) -> Self::This<B>
+  where
+    Self: IntoIterator<Item = Item> + Sized,
+    Self::This<B>: FromIterator<B>,
+    Item: Send,
+    B: Send,
+  {
+    let values = self.into_iter().collect::<Vec<_>>();
+    parallel_chunks(values, parallelism, |chunk| chunk.into_iter().map(&function).collect::<Vec<_>>())
+      .into_iter()
+      .flatten()
+      .collect()
+  }
+
   /// Creates two new collections from this collection by applying
   /// specified predicate.
   ///
@@ -1226,39 +1799,68 @@ where
   ///
   /// assert_eq!(e.partitions(), Vec::<Vec<Vec<i32>>>::new());
   /// ```
-  #[allow(clippy::cast_possible_wrap)]
   #[must_use]
   fn partitions(&self) -> Vec<Vec<Self>>
   where
     Self: FromIterator<Item> + Sized,
     Item: Clone,
+  {
+    self.partitions_iter().collect()
+  }
+
+  /// Creates an iterator lazily generating all partitions of this collection.
+  ///
+  /// This is a lazy variant of [`partitions()`] which yields each partition on demand.
+  /// The size hint reports the exact number of remaining partitions unless it exceeds `usize::MAX`.
+  ///
+  /// [`partitions()`]: CollectionTo::partitions
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3];
+  /// let e = Vec::<i32>::new();
+  ///
+  /// let mut partitions = a.partitions_iter();
+  /// assert_eq!(partitions.size_hint(), (5, Some(5)));
+  /// assert_eq!(partitions.next(), Some(vec![vec![1, 2, 3]]));
+  /// assert_eq!(partitions.next(), Some(vec![vec![1, 2], vec![3]]));
+  /// assert_eq!(partitions.size_hint(), (3, Some(3)));
+  ///
+  /// assert_eq!(e.partitions_iter().size_hint(), (0, Some(0)));
+  /// ```
+  #[allow(clippy::cast_possible_wrap)]
+  fn partitions_iter<'a>(&'a self) -> impl Iterator<Item = Vec<Self>>
+  where
+    Self: FromIterator<Item> + Sized,
+    Item: Clone,
+    Item: 'a,
   {
     let iterator = self.into_iter();
     let values = iterator.collect::<Vec<_>>();
     let length = values.len();
     assert!(length <= MAX_SIZE, "len (is {length:?}) should be <= {MAX_SIZE:?})");
-    if values.is_empty() {
-      return vec![];
-    }
     let size = values.len() as i64;
-    let mut result = Vec::new();
-    let mut stack = VecDeque::<(i64, Vec<Vec<i64>>)>::with_capacity(values.len());
-    stack.push_back((0, vec![]));
-
-    while let Some((current_index, mut partition)) = stack.pop_back() {
-      if current_index == size {
-        result.push(partition.iter().map(|tuple| collect_by_index(&values, tuple)).collect());
-        continue;
-      }
-      for index in 0..partition.len() {
+    let count = if values.is_empty() { Some(0) } else { bell_number(length) };
+    let mut stack = if values.is_empty() { Vec::new() } else { vec![(0_i64, Vec::<Vec<i64>>::new())] };
+    unfold_sized(count, move || {
+      while let Some((current_index, partition)) = stack.pop() {
+        if current_index == size {
+          return Some(partition.iter().map(|tuple| collect_by_index(&values, tuple)).collect());
+        }
         let mut new_partition = partition.clone();
-        new_partition[index].push(current_index);
-        stack.push_front((current_index + 1, new_partition));
+        new_partition.push([current_index].to_vec());
+        stack.push((current_index + 1, new_partition));
+        for index in (0..partition.len()).rev() {
+          let mut new_partition = partition.clone();
+          new_partition[index].push(current_index);
+          stack.push((current_index + 1, new_partition));
+        }
       }
-      partition.push([current_index].to_vec());
-      stack.push_front((current_index + 1, partition));
-    }
-    result
+      None
+    })
   }
 
   /// Creates two new collections with arbitrary element types from this collection
@@ -1333,22 +1935,54 @@ where
     partition_map_ref(self.into_iter(), function)
   }
 
-  /// Creates a new collection containing all sub-collections of this collection.
+  /// Creates two new collections from this collection of results by separating
+  /// the `Ok` values from the `Err` values.
   ///
-  /// Sub-collections for sequences are generated based on element positions, not values.
-  /// Therefore, if a sequence contains duplicate elements, the resulting sub-collections will too.
-  /// To obtain combinations of unique elements for sequences, use [`unique()`]`.powerset()`.
-  ///
-  /// The order of sub-collection values is preserved for sequences.
-  ///
-  /// [`unique()`]: crate::SequenceTo::unique
+  /// The order of values is preserved for sequences.
   ///
   /// # Example
   ///
   /// ```
   /// use cantrip::*;
   ///
-  /// let a = vec![1, 2, 3];
+  /// let a: Vec<Result<i32, &str>> = vec![Ok(1), Err("x"), Ok(3), Err("y")];
+  ///
+  /// assert_eq!(a.partition_results(), (vec![1, 3], vec!["x", "y"]));
+  /// ```
+  #[must_use]
+  fn partition_results<A, E>(self) -> (Self::This<A>, Self::This<E>)
+  where
+    Self: IntoIterator<Item = Result<A, E>> + Sized,
+    Self::This<A>: Default + Extend<A>,
+    Self::This<E>: Default + Extend<E>,
+  {
+    let mut result_left: Self::This<A> = Self::This::default();
+    let mut result_right: Self::This<E> = Self::This::default();
+    for item in self {
+      match item {
+        Ok(value) => result_left.extend(iter::once(value)),
+        Err(error) => result_right.extend(iter::once(error)),
+      }
+    }
+    (result_left, result_right)
+  }
+
+  /// Creates a new collection containing all sub-collections of this collection.
+  ///
+  /// Sub-collections for sequences are generated based on element positions, not values.
+  /// Therefore, if a sequence contains duplicate elements, the resulting sub-collections will too.
+  /// To obtain combinations of unique elements for sequences, use [`unique()`]`.powerset()`.
+  ///
+  /// The order of sub-collection values is preserved for sequences.
+  ///
+  /// [`unique()`]: crate::SequenceTo::unique
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3];
   /// let e = Vec::<i32>::new();
   ///
   /// assert_eq!(a.powerset(), vec![
@@ -1368,15 +2002,78 @@ where
   where
     Self: FromIterator<Item> + Sized,
     Item: Clone,
+  {
+    self.powerset_iter().collect()
+  }
+
+  /// Creates an iterator lazily generating all sub-collections of this collection.
+  ///
+  /// This is a lazy variant of [`powerset()`] which yields each sub-collection on demand.
+  /// The size hint reports the exact number of remaining sub-collections unless it exceeds `usize::MAX`.
+  ///
+  /// [`powerset()`]: CollectionTo::powerset
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3];
+  /// let e = Vec::<i32>::new();
+  ///
+  /// let mut powerset = a.powerset_iter();
+  /// assert_eq!(powerset.size_hint(), (8, Some(8)));
+  /// assert_eq!(powerset.next(), Some(vec![]));
+  /// assert_eq!(powerset.next(), Some(vec![1]));
+  /// assert_eq!(powerset.size_hint(), (6, Some(6)));
+  ///
+  /// assert_eq!(e.powerset_iter().collect::<Vec<_>>(), vec![vec![]]);
+  /// ```
+  fn powerset_iter<'a>(&'a self) -> impl Iterator<Item = Self>
+  where
+    Self: FromIterator<Item> + Sized,
+    Item: Clone,
+    Item: 'a,
   {
     let iterator = self.into_iter();
     let values = iterator.collect::<Vec<_>>();
     let length = values.len();
     assert!(length <= MAX_SIZE, "len (is {length:?}) should be <= {MAX_SIZE:?})");
-    let sizes = 1..=values.len();
-    iter::once(iter::empty().collect())
-      .chain(sizes.flat_map(|size| compute_combinations::<Item, Self>(&values, size)))
-      .collect()
+    let count = u32::try_from(length).ok().and_then(|exponent| 2_usize.checked_pow(exponent));
+    let sizes = 1..=length;
+    let mut subsets = iter::once(iter::empty().collect())
+      .chain(sizes.flat_map(move |size| compute_combinations::<Item, Self>(values.clone(), size)));
+    unfold_sized(count, move || subsets.next())
+  }
+
+  /// Sums all elements of this collection using compensated summation.
+  ///
+  /// Uses the Kahan–Neumaier algorithm which keeps the rounding error independent
+  /// of the number of elements. An empty collection returns zero.
+  /// This is an accurate variant of [`sum()`] for floating point numbers.
+  ///
+  /// [`sum()`]: CollectionTo::sum
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1.0, 1e100, 1.0, -1e100];
+  /// let e = Vec::<f64>::new();
+  ///
+  /// assert_eq!(a.precise_sum(), 2.0);
+  ///
+  /// assert_eq!(e.precise_sum(), 0.0);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn precise_sum(self) -> Item
+  where
+    Self: IntoIterator<Item = Item> + Sized,
+    Item: Float,
+  {
+    precise_sum(self.into_iter())
   }
 
   /// Iterates over the entire collection, multiplying all the elements
@@ -1458,6 +2155,40 @@ where
     iterator.next().map(|result| iterator.fold(result, function))
   }
 
+  /// Sums all elements of this collection, saturating at the numeric bounds instead of overflowing.
+  ///
+  /// The exact sum is clamped to the numeric bounds only once, so the result does not depend
+  /// on the order of elements.
+  /// An empty collection returns the zero value of the type.
+  /// This is an overflow-aware variant of [`sum()`].
+  ///
+  /// [`sum()`]: CollectionTo::sum
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3];
+  /// let e = Vec::<i32>::new();
+  ///
+  /// assert_eq!(a.saturating_sum(), 6);
+  /// assert_eq!(vec![i32::MAX, 1].saturating_sum(), i32::MAX);
+  /// assert_eq!(vec![100_i8, 100, -100].saturating_sum(), 100);
+  /// assert_eq!(vec![-100_i8, 100, 100].saturating_sum(), 100);
+  ///
+  /// assert_eq!(e.saturating_sum(), 0);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn saturating_sum(self) -> Item
+  where
+    Self: IntoIterator<Item = Item> + Sized,
+    Item: Integer,
+  {
+    saturating_sum(self.into_iter())
+  }
+
   /// Creates a new collection containing the n smallest elements of
   /// this collection in descending order.
   ///
@@ -1481,17 +2212,103 @@ where
     Self: IntoIterator<Item = Item> + FromIterator<Item>,
     Item: Ord,
   {
-    let mut iterator = self.into_iter();
-    let mut heap = iterator.by_ref().take(n).collect::<BinaryHeap<_>>();
-    for item in iterator {
-      if let Some(mut top) = heap.peek_mut()
-        && item < *top
-      {
-        *top = item;
-      }
-    }
-    let result = unfold(|| heap.pop()).collect::<Vec<_>>();
-    result.into_iter().rev().collect()
+    smallest(self.into_iter(), n).into_iter().collect()
+  }
+
+  /// Creates a new collection containing the n smallest elements of
+  /// this collection in ascending order with respect to the specified comparison function.
+  ///
+  /// It takes *O*(*n* \* log(*k*)) time and *O*(*k*) memory, where *k* is the number of selected elements.
+  /// Equal elements are returned in their original order.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// # let a_source = vec![2, 3, 1];
+  /// let a = vec![2, 3, 1];
+  /// let e = Vec::<i32>::new();
+  ///
+  /// assert_eq!(a.smallest_by(2, |x, y| x.cmp(y)), vec![1, 2]);
+  /// # let a = a_source.clone();
+  /// assert_eq!(a.smallest_by(4, |x, y| x.cmp(y)), vec![1, 2, 3]);
+  /// assert_eq!(e.smallest_by(3, |x, y| x.cmp(y)), vec![]);
+  /// ```
+  #[must_use]
+  fn smallest_by(self, n: usize, compare: impl FnMut(&Item, &Item) -> Ordering) -> Self
+  where
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+  {
+    smallest_by(self.into_iter(), n, compare).into_iter().collect()
+  }
+
+  /// Creates a new collection containing the n smallest elements of
+  /// this collection in ascending order with respect to the specified key function.
+  ///
+  /// It takes *O*(*n* \* log(*k*)) time and *O*(*k*) memory, where *k* is the number of selected elements.
+  /// Equal elements are returned in their original order.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// # let a_source = vec![("a", 2), ("b", 3), ("c", 1)];
+  /// let a = vec![("a", 2), ("b", 3), ("c", 1)];
+  /// let e = Vec::<(&str, i32)>::new();
+  ///
+  /// assert_eq!(a.smallest_by_key(2, |&(_, x)| x), vec![("c", 1), ("a", 2)]);
+  /// # let a = a_source.clone();
+  /// assert_eq!(a.smallest_by_key(4, |&(_, x)| x), vec![("c", 1), ("a", 2), ("b", 3)]);
+  /// assert_eq!(e.smallest_by_key(3, |&(_, x)| x), vec![]);
+  /// ```
+  #[must_use]
+  fn smallest_by_key<K>(self, n: usize, mut to_key: impl FnMut(&Item) -> K) -> Self
+  where
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+    K: Ord,
+  {
+    smallest_by(self.into_iter(), n, |x, y| to_key(x).cmp(&to_key(y))).into_iter().collect()
+  }
+
+  /// Creates a new collection containing the n smallest elements of
+  /// this collection of floating point numbers in ascending order.
+  ///
+  /// This is a variant of [`smallest()`] for floating point numbers which do not implement [`Ord`].
+  ///
+  /// NaN values are treated according to the specified [`NanPolicy`]:
+  ///
+  /// - [`NanPolicy::Total`] orders them using the IEEE 754 `totalOrder` predicate
+  /// - [`NanPolicy::Skip`] omits them
+  /// - [`NanPolicy::Propagate`] places them first in their original order
+  ///
+  /// [`smallest()`]: CollectionTo::smallest
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// # let a_source = vec![2.0, 3.0, 1.0];
+  /// let a = vec![2.0, 3.0, 1.0];
+  /// let e = Vec::<f64>::new();
+  ///
+  /// assert_eq!(a.smallest_floats(2, NanPolicy::Total), vec![1.0, 2.0]);
+  /// # let a = a_source.clone();
+  /// assert_eq!(a.smallest_floats(4, NanPolicy::Total), vec![1.0, 2.0, 3.0]);
+  /// assert_eq!(vec![2.0, f64::NAN, 1.0].smallest_floats(2, NanPolicy::Skip), vec![1.0, 2.0]);
+  /// assert!(vec![2.0, f64::NAN, 1.0].smallest_floats(1, NanPolicy::Propagate)[0].is_nan());
+  /// assert_eq!(e.smallest_floats(3, NanPolicy::Total), vec![]);
+  /// ```
+  #[must_use]
+  fn smallest_floats(self, n: usize, nan: NanPolicy) -> Self
+  where
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+    Item: Float,
+  {
+    let iterator = self.into_iter().filter(|x| nan != NanPolicy::Skip || !x.is_nan());
+    smallest_by(iterator, n, float_order(nan, false)).into_iter().collect()
   }
 
   /// Creates a new collection from this collection by replacing the
@@ -1558,11 +2375,7 @@ where
     Self: IntoIterator<Item = Item> + FromIterator<Item>,
     Item: Eq + Hash + 'a,
   {
-    let elements_iterator = elements.iterator();
-    let mut replaced = HashMap::<&Item, LinkedList<Item>>::with_capacity(elements_iterator.size_hint().0);
-    for (item, replacement) in elements_iterator.zip(replacements.into_iter()) {
-      replaced.entry(item).or_default().push_back(replacement);
-    }
+    let mut replaced = multiset_replacements(elements.iterator(), replacements);
     self
       .into_iter()
       .map(|x| if let Some(items) = replaced.get_mut(&x) { items.pop_front().unwrap_or(x) } else { x })
@@ -1607,8 +2420,247 @@ where
     self.into_iter().sum()
   }
 
+  /// Creates a new collection by filtering this collection using a fallible
+  /// closure to determine if an element should be retained.
+  ///
+  /// Given an element the closure must return `Ok(true)` or `Ok(false)`. The returned
+  /// collection will contain only the elements for which the closure returns `Ok(true)`.
+  ///
+  /// This is a consuming variant of [`try_filter_ref()`].
+  ///
+  /// [`try_filter_ref()`]: CollectionTo::try_filter_ref
+  ///
+  /// # Errors
+  ///
+  /// Returns the first error produced by the closure,
+  /// without evaluating the remaining elements.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// # let a_source = vec![1, 2, 3];
+  /// let a = vec![1, 2, 3];
+  ///
+  /// assert_eq!(a.try_filter(|&x| if x < 4 { Ok(x != 2) } else { Err(x) }), Ok(vec![1, 3]));
+  ///
+  /// # let a = a_source.clone();
+  /// assert_eq!(a.try_filter(|&x| if x < 2 { Ok(true) } else { Err(x) }), Err(2));
+  /// ```
+  #[inline]
+  fn try_filter<E>(self, mut predicate: impl FnMut(&Item) -> Result<bool, E>) -> Result<Self, E>
+  where
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+  {
+    self
+      .into_iter()
+      .filter_map(|item| match predicate(&item) {
+        Ok(true) => Some(Ok(item)),
+        Ok(false) => None,
+        Err(error) => Some(Err(error)),
+      })
+      .collect()
+  }
+
+  /// Creates a new collection by filtering this collection using a fallible
+  /// closure to determine if an element should be retained.
+  ///
+  /// Given an element the closure must return `Ok(true)` or `Ok(false)`. The returned
+  /// collection will contain only the elements for which the closure returns `Ok(true)`.
+  ///
+  /// This is a non-consuming variant of [`try_filter()`].
+  ///
+  /// [`try_filter()`]: CollectionTo::try_filter
+  ///
+  /// # Errors
+  ///
+  /// Returns the first error produced by the closure,
+  /// without evaluating the remaining elements.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3];
+  ///
+  /// assert_eq!(a.try_filter_ref(|&x| if x < 4 { Ok(x != 2) } else { Err(x) }), Ok(vec![1, 3]));
+  /// assert_eq!(a.try_filter_ref(|&x| if x < 2 { Ok(true) } else { Err(x) }), Err(2));
+  /// ```
+  #[inline]
+  fn try_filter_ref<E>(&self, mut predicate: impl FnMut(&Item) -> Result<bool, E>) -> Result<Self, E>
+  where
+    Self: FromIterator<Item>,
+    Item: Clone,
+  {
+    self
+      .into_iter()
+      .filter_map(|item| match predicate(item) {
+        Ok(true) => Some(Ok(item.clone())),
+        Ok(false) => None,
+        Err(error) => Some(Err(error)),
+      })
+      .collect()
+  }
+
+  /// Folds every element into an accumulator by applying a fallible operation,
+  /// returning the final result.
+  ///
+  /// `try_fold()` takes two arguments: an initial value, and a closure with two
+  /// arguments: an 'accumulator', and an element. The closure either returns
+  /// `Ok` with the value that the accumulator should have for the next iteration,
+  /// or `Err` which is propagated back to the caller immediately.
+  ///
+  /// This is a consuming variant of [`try_fold_ref()`].
+  ///
+  /// [`try_fold_ref()`]: crate::Collection::try_fold_ref
+  ///
+  /// # Errors
+  ///
+  /// Returns the first error produced by the closure,
+  /// without evaluating the remaining elements.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// # let a_source = vec![1, 2, 3];
+  /// let a = vec![1, 2, 3];
+  ///
+  /// assert_eq!(a.try_fold(0_i8, |acc, x| acc.checked_add(x).ok_or(x)), Ok(6));
+  ///
+  /// # let a = a_source.clone();
+  /// assert_eq!(a.try_fold(127_i8, |acc, x| acc.checked_add(x).ok_or(x)), Err(1));
+  /// ```
+  #[inline]
+  fn try_fold<B, E>(self, initial_value: B, function: impl FnMut(B, Item) -> Result<B, E>) -> Result<B, E>
+  where
+    Self: IntoIterator<Item = Item> + Sized,
+  {
+    self.into_iter().try_fold(initial_value, function)
+  }
+
+  /// Creates a map of keys mapped to collections of elements according to
+  /// the specified fallible discriminator function.
+  ///
+  /// The discriminator function takes a reference to an element and returns
+  /// either a group key or an error.
+  ///
+  /// # Errors
+  ///
+  /// Returns the first error produced by the closure,
+  /// without evaluating the remaining elements.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// # let a_source = vec![1, 2, 3];
+  /// let a = vec![1, 2, 3];
+  ///
+  /// assert_eq!(
+  ///   a.try_group_by(|x| if *x < 4 { Ok(x % 2) } else { Err(*x) }),
+  ///   Ok(HashMap::from([(0, vec![2]), (1, vec![1, 3])]))
+  /// );
+  ///
+  /// # let a = a_source.clone();
+  /// assert_eq!(a.try_group_by(|x| if *x < 2 { Ok(x % 2) } else { Err(*x) }), Err(2));
+  /// ```
+  fn try_group_by<K, E>(self, mut to_key: impl FnMut(&Item) -> Result<K, E>) -> Result<HashMap<K, Self>, E>
+  where
+    Self: IntoIterator<Item = Item> + Default + Extend<Item>,
+    K: Eq + Hash,
+  {
+    let iterator = self.into_iter();
+    let mut result = HashMap::<K, Self>::with_capacity(iterator.size_hint().0);
+    for item in iterator {
+      result.entry(to_key(&item)?).or_default().extend(iter::once(item));
+    }
+    Ok(result)
+  }
+
+  /// Creates a new collection by applying the given fallible closure `function`
+  /// to each element in this collection.
+  ///
+  /// The closure `function` takes an element of type `Item` and returns
+  /// either a value of type `B` or an error of type `E`.
+  ///
+  /// This is a consuming variant of [`try_map_ref()`].
+  ///
+  /// [`try_map_ref()`]: CollectionTo::try_map_ref
+  ///
+  /// # Errors
+  ///
+  /// Returns the first error produced by the closure,
+  /// without evaluating the remaining elements.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// # let a_source = vec!["1", "2", "3"];
+  /// let a = vec!["1", "2", "3"];
+  ///
+  /// assert_eq!(a.try_map(|x| x.parse::<i32>()), Ok(vec![1, 2, 3]));
+  ///
+  /// # let a = a_source.clone();
+  /// assert_eq!(a.try_map(|x| if x == "2" { Err(x) } else { Ok(x) }), Err("2"));
+  /// ```
+  #[inline]
+  fn try_map<B, E>(self, function: impl FnMut(Item) -> Result<B, E>) -> Result<Self::This<B>, E>
+  where
+    Self: IntoIterator<Item = Item> + Sized,
+    Self::This<B>: FromIterator<B>,
+  {
+    self.into_iter().map(function).collect()
+  }
+
+  /// Creates a new collection by applying the given fallible closure `function`
+  /// to each element in this collection.
+  ///
+  /// The closure `function` takes a reference to an element of type `Item` and returns
+  /// either a value of type `B` or an error of type `E`.
+  ///
+  /// This is a non-consuming variant of [`try_map()`].
+  ///
+  /// [`try_map()`]: CollectionTo::try_map
+  ///
+  /// # Errors
+  ///
+  /// Returns the first error produced by the closure,
+  /// without evaluating the remaining elements.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec!["1", "2", "3"];
+  ///
+  /// assert_eq!(a.try_map_ref(|x| x.parse::<i32>()), Ok(vec![1, 2, 3]));
+  /// assert_eq!(a.try_map_ref(|&x| if x == "2" { Err(x) } else { Ok(x) }), Err("2"));
+  /// ```
+  #[inline]
+  fn try_map_ref<B, E>(&self, function: impl FnMut(&Item) -> Result<B, E>) -> Result<Self::This<B>, E>
+  where
+    Self::This<B>: FromIterator<B>,
+  {
+    self.into_iter().map(function).collect()
+  }
+
   /// Creates a new collection containing a single element.
   ///
+  /// Hash-based sets can only be created this way with the default hasher,
+  /// use [`unit_with_hasher()`] to specify a custom hasher.
+  ///
+  /// [`unit_with_hasher()`]: CollectionTo::unit_with_hasher
+  ///
   /// # Example
   ///
   /// ```
@@ -1619,27 +2671,54 @@ where
   #[must_use]
   fn unit(element: Item) -> Self
   where
-    Self: FromIterator<Item>,
+    Self: FromIterator<Item> + Constructible,
   {
     iter::once(element).collect()
   }
+
+  /// Creates a new hash-based set containing a single element using the specified hasher.
+  ///
+  /// This is a variant of [`unit()`] for hash-based sets with a custom hasher.
+  ///
+  /// [`unit()`]: CollectionTo::unit
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashSet;
+  /// use std::hash::RandomState;
+  ///
+  /// use cantrip::*;
+  ///
+  /// assert_eq!(HashSet::unit_with_hasher(1, RandomState::new()), HashSet::from([1]));
+  /// ```
+  #[inline]
+  #[must_use]
+  fn unit_with_hasher<S>(element: Item, hash_builder: S) -> Self
+  where
+    Self: Extend<Item> + HasherConstructible<S>,
+  {
+    let mut result = Self::from_hasher(hash_builder);
+    result.extend(iter::once(element));
+    result
+  }
 }
 
 #[allow(clippy::cast_possible_wrap)]
-pub(crate) fn compute_combinations<'a, Item, Collection>(values: &[&Item], k: usize) -> Vec<Collection>
+pub(crate) fn compute_combinations<Item, Collection>(values: Vec<&Item>, k: usize) -> impl Iterator<Item = Collection>
 where
-  Item: Clone + 'a,
+  Item: Clone,
   Collection: FromIterator<Item>,
 {
   let size = values.len();
   let mut combination = iter::once(i64::MIN).chain(0..(k as i64)).collect::<Vec<_>>();
   let mut current_slot = (size + 1).saturating_sub(k);
-  unfold(|| {
+  unfold_sized(binomial(size, k), move || {
     if current_slot == 0 {
       return None;
     }
     current_slot = k;
-    let tuple = Some(collect_by_index(values, &combination[1..]));
+    let tuple = Some(collect_by_index(&values, &combination[1..]));
     while combination[current_slot] >= (size + current_slot - k) as i64 - 1 {
       current_slot -= 1;
     }
@@ -1650,7 +2729,41 @@ where
     }
     tuple
   })
-  .collect()
+}
+
+pub(crate) fn binomial(n: usize, k: usize) -> Option<usize> {
+  if k > n {
+    return Some(0);
+  }
+  let k = k.min(n - k);
+  let mut result = 1_usize;
+  for index in 0..k {
+    let divisor = index + 1;
+    let factor = n - index;
+    let common = gcd(result, divisor);
+    result = (result / common).checked_mul(factor / (divisor / common))?;
+  }
+  Some(result)
+}
+
+pub(crate) fn bell_number(n: usize) -> Option<usize> {
+  let mut row = vec![1_usize];
+  for _ in 1..n {
+    let mut next_row = Vec::with_capacity(row.len() + 1);
+    next_row.push(row[row.len() - 1]);
+    for value in &row {
+      next_row.push(next_row[next_row.len() - 1].checked_add(*value)?);
+    }
+    row = next_row;
+  }
+  Some(row[row.len() - 1])
+}
+
+fn gcd(mut a: usize, mut b: usize) -> usize {
+  while b != 0 {
+    (a, b) = (b, a % b);
+  }
+  a
 }
 
 pub(crate) fn partition_map_ref<'a, Item: 'a, A, B, Left: Default + Extend<A>, Right: Default + Extend<B>>(
@@ -1666,3 +2779,150 @@ pub(crate) fn partition_map_ref<'a, Item: 'a, A, B, Left: Default + Extend<A>, R
   }
   (result_left, result_right)
 }
+
+pub(crate) fn group_by<Item, K, Collection, S>(
+  iterator: impl Iterator<Item = Item>, mut to_key: impl FnMut(&Item) -> K, hash_builder: S,
+) -> HashMap<K, Collection, S>
+where
+  K: Eq + Hash,
+  Collection: Default + Extend<Item>,
+  S: BuildHasher,
+{
+  let mut result = HashMap::<K, Collection, S>::with_capacity_and_hasher(iterator.size_hint().0, hash_builder);
+  for item in iterator {
+    result.entry(to_key(&item)).or_default().extend(iter::once(item));
+  }
+  result
+}
+
+/// Creates a predicate matching the first occurrences of the specified elements
+/// respecting their multiplicity, returning `matched` for them and `!matched` otherwise.
+pub(crate) fn multiset_filter<'a, Item: Eq + Hash + 'a>(
+  elements: impl Iterator<Item = &'a Item>, matched: bool,
+) -> impl FnMut(&Item) -> bool {
+  let mut remaining: HashMap<&Item, usize> = frequencies(elements);
+  move |item| {
+    if let Some(count) = remaining.get_mut(item)
+      && *count > 0
+    {
+      *count -= 1;
+      return matched;
+    }
+    !matched
+  }
+}
+
+pub(crate) fn multiset_filter_eq<'a, Item: PartialEq + 'a>(
+  elements: impl Iterator<Item = &'a Item>, matched: bool,
+) -> impl FnMut(&Item) -> bool {
+  let mut remaining = elements.collect::<Vec<_>>();
+  move |item| {
+    if let Some(index) = remaining.iter().position(|&x| x == item) {
+      let _unused = remaining.swap_remove(index);
+      return matched;
+    }
+    !matched
+  }
+}
+
+pub(crate) fn multiset_filter_ord<'a, Item: Ord + 'a>(
+  elements: impl Iterator<Item = &'a Item>, matched: bool,
+) -> impl FnMut(&Item) -> bool {
+  let mut remaining = BTreeMap::<&Item, usize>::new();
+  for item in elements {
+    *remaining.entry(item).or_default() += 1;
+  }
+  move |item| {
+    if let Some(count) = remaining.get_mut(item)
+      && *count > 0
+    {
+      *count -= 1;
+      return matched;
+    }
+    !matched
+  }
+}
+
+pub(crate) fn multiset_replacements<'a, Item: Eq + Hash + 'a>(
+  elements: impl Iterator<Item = &'a Item>, replacements: impl IntoIterator<Item = Item>,
+) -> HashMap<&'a Item, VecDeque<Item>> {
+  let mut result = HashMap::<&Item, VecDeque<Item>>::with_capacity(elements.size_hint().0);
+  for (item, replacement) in elements.zip(replacements) {
+    result.entry(item).or_default().push_back(replacement);
+  }
+  result
+}
+
+pub(crate) fn largest<Item: Ord>(mut iterator: impl Iterator<Item = Item>, n: usize) -> Vec<Item> {
+  let mut heap = iterator.by_ref().map(|x| Reverse(x)).take(n).collect::<BinaryHeap<_>>();
+  for item in iterator {
+    if let Some(mut top) = heap.peek_mut()
+      && item > top.0
+    {
+      *top = Reverse(item);
+    }
+  }
+  let mut result = unfold(|| heap.pop()).map(|x| x.0).collect::<Vec<_>>();
+  result.reverse();
+  result
+}
+
+pub(crate) fn smallest<Item: Ord>(mut iterator: impl Iterator<Item = Item>, n: usize) -> Vec<Item> {
+  let mut heap = iterator.by_ref().take(n).collect::<BinaryHeap<_>>();
+  for item in iterator {
+    if let Some(mut top) = heap.peek_mut()
+      && item < *top
+    {
+      *top = item;
+    }
+  }
+  let mut result = unfold(|| heap.pop()).collect::<Vec<_>>();
+  result.reverse();
+  result
+}
+
+pub(crate) fn smallest_by<Item, F: FnMut(&Item, &Item) -> Ordering>(
+  iterator: impl Iterator<Item = Item>, n: usize, compare: F,
+) -> Vec<Item> {
+  let compare = RefCell::new(compare);
+  let mut iterator = iterator.enumerate().map(|(index, item)| OrderedBy { index, item, compare: &compare });
+  let mut heap = iterator.by_ref().take(n).collect::<BinaryHeap<_>>();
+  for item in iterator {
+    if let Some(mut top) = heap.peek_mut()
+      && item < *top
+    {
+      *top = item;
+    }
+  }
+  heap.into_sorted_vec().into_iter().map(|x| x.item).collect()
+}
+
+// Orders elements by a comparison function, breaking ties by their original position
+struct OrderedBy<'a, Item, F> {
+  index: usize,
+  item: Item,
+  compare: &'a RefCell<F>,
+}
+
+impl<Item, F: FnMut(&Item, &Item) -> Ordering> PartialEq for OrderedBy<'_, Item, F> {
+  #[inline]
+  fn eq(&self, other: &Self) -> bool {
+    self.cmp(other) == Ordering::Equal
+  }
+}
+
+impl<Item, F: FnMut(&Item, &Item) -> Ordering> Eq for OrderedBy<'_, Item, F> {}
+
+impl<Item, F: FnMut(&Item, &Item) -> Ordering> PartialOrd for OrderedBy<'_, Item, F> {
+  #[inline]
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl<Item, F: FnMut(&Item, &Item) -> Ordering> Ord for OrderedBy<'_, Item, F> {
+  #[inline]
+  fn cmp(&self, other: &Self) -> Ordering {
+    (self.compare.borrow_mut())(&self.item, &other.item).then(self.index.cmp(&other.index))
+  }
+}
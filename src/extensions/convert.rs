@@ -1,5 +1,5 @@
 use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
-use std::hash::Hash;
+use std::hash::{BuildHasher, Hash};
 
 /// Conversion operations.
 ///
@@ -155,6 +155,38 @@ pub trait Convert<Item> {
     self.into_iter().collect()
   }
 
+  /// Creates a new hash map from the elements of this collection
+  /// using the specified hash builder.
+  ///
+  /// This is a variant of [`to_map()`] supporting custom hashing algorithms.
+  ///
+  /// [`to_map()`]: Convert::to_map
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  /// use std::hash::{BuildHasherDefault, DefaultHasher};
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = vec![(1, 1), (2, 2), (3, 3)];
+  /// let hash_builder = BuildHasherDefault::<DefaultHasher>::default();
+  ///
+  /// assert_eq!(a.to_map_with_hasher(hash_builder), HashMap::from_iter([(1, 1), (2, 2), (3, 3)]));
+  /// ```
+  #[inline]
+  fn to_map_with_hasher<K, V, S>(self, hash_builder: S) -> HashMap<K, V, S>
+  where
+    K: Eq + Hash,
+    S: BuildHasher,
+    Self: IntoIterator<Item = (K, V)> + Sized,
+  {
+    let mut result = HashMap::with_hasher(hash_builder);
+    result.extend(self);
+    result
+  }
+
   /// Creates a new hash set from the elements of this collection.
   ///
   /// This is an equivalent of [`Iterator::collect`].
@@ -181,6 +213,38 @@ pub trait Convert<Item> {
     self.into_iter().collect()
   }
 
+  /// Creates a new hash set from the elements of this collection
+  /// using the specified hash builder.
+  ///
+  /// This is a variant of [`to_set()`] supporting custom hashing algorithms.
+  ///
+  /// [`to_set()`]: Convert::to_set
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashSet;
+  /// use std::hash::{BuildHasherDefault, DefaultHasher};
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3];
+  /// let hash_builder = BuildHasherDefault::<DefaultHasher>::default();
+  ///
+  /// assert_eq!(a.to_set_with_hasher(hash_builder), HashSet::from_iter([1, 2, 3]));
+  /// ```
+  #[inline]
+  fn to_set_with_hasher<S>(self, hash_builder: S) -> HashSet<Item, S>
+  where
+    Item: Eq + Hash,
+    S: BuildHasher,
+    Self: IntoIterator<Item = Item> + Sized,
+  {
+    let mut result = HashSet::with_hasher(hash_builder);
+    result.extend(self);
+    result
+  }
+
   /// Creates a new vector from the elements of this collection.
   ///
   /// This is an equivalent of [`Iterator::collect`].
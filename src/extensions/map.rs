@@ -1,5 +1,8 @@
-use crate::Iterable;
-use crate::extensions::{count_unique, subset, superset};
+use crate::core::arithmetic::{checked_product, checked_sum, precise_sum, saturating_sum};
+use crate::core::ordering::minmax_float;
+use crate::core::statistics::{mean, mode, percentiles, quantile, sorted, summary, variance};
+use crate::extensions::{count_unique, smallest_by, subset, superset};
+use crate::{Constructible, Float, HasherConstructible, Integer, Iterable, NanPolicy, Number, Summary};
 use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet, LinkedList};
 use std::hash::Hash;
@@ -150,6 +153,134 @@ where
     self.into_iter().any(predicate)
   }
 
+  /// Multiplies keys of this map, returning [`None`] if the computation overflows.
+  ///
+  /// An empty map returns the one value of the type.
+  /// This is an overflow-aware variant of [`product_keys()`].
+  ///
+  /// [`product_keys()`]: Map::product_keys
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = HashMap::from([(1, 1), (2, 2), (3, 3)]);
+  /// let e = HashMap::<i32, i32>::new();
+  ///
+  /// assert_eq!(a.checked_product_keys(), Some(6));
+  /// assert_eq!(HashMap::from([(i32::MAX, 1), (2, 2)]).checked_product_keys(), None);
+  ///
+  /// assert_eq!(e.checked_product_keys(), Some(1));
+  /// ```
+  #[inline]
+  #[must_use]
+  fn checked_product_keys(self) -> Option<Key>
+  where
+    Self: IntoIterator<Item = (Key, Value)> + Sized,
+    Key: Integer,
+  {
+    checked_product(self.into_iter().map(|(k, _)| k))
+  }
+
+  /// Multiplies values of this map, returning [`None`] if the computation overflows.
+  ///
+  /// An empty map returns the one value of the type.
+  /// This is an overflow-aware variant of [`product_values()`].
+  ///
+  /// [`product_values()`]: Map::product_values
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = HashMap::from([(1, 1), (2, 2), (3, 3)]);
+  /// let e = HashMap::<i32, i32>::new();
+  ///
+  /// assert_eq!(a.checked_product_values(), Some(6));
+  /// assert_eq!(HashMap::from([(1, i32::MAX), (2, 2)]).checked_product_values(), None);
+  ///
+  /// assert_eq!(e.checked_product_values(), Some(1));
+  /// ```
+  #[inline]
+  #[must_use]
+  fn checked_product_values(self) -> Option<Value>
+  where
+    Self: IntoIterator<Item = (Key, Value)> + Sized,
+    Value: Integer,
+  {
+    checked_product(self.into_iter().map(|(_, v)| v))
+  }
+
+  /// Sums keys of this map, returning [`None`] if the computation overflows.
+  ///
+  /// An empty map returns the zero value of the type.
+  /// This is an overflow-aware variant of [`sum_keys()`].
+  ///
+  /// [`sum_keys()`]: Map::sum_keys
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = HashMap::from([(1, 1), (2, 2), (3, 3)]);
+  /// let e = HashMap::<i32, i32>::new();
+  ///
+  /// assert_eq!(a.checked_sum_keys(), Some(6));
+  /// assert_eq!(HashMap::from([(i32::MAX, 1), (1, 2)]).checked_sum_keys(), None);
+  ///
+  /// assert_eq!(e.checked_sum_keys(), Some(0));
+  /// ```
+  #[inline]
+  #[must_use]
+  fn checked_sum_keys(self) -> Option<Key>
+  where
+    Self: IntoIterator<Item = (Key, Value)> + Sized,
+    Key: Integer,
+  {
+    checked_sum(self.into_iter().map(|(k, _)| k))
+  }
+
+  /// Sums values of this map, returning [`None`] if the computation overflows.
+  ///
+  /// An empty map returns the zero value of the type.
+  /// This is an overflow-aware variant of [`sum_values()`].
+  ///
+  /// [`sum_values()`]: Map::sum_values
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = HashMap::from([(1, 1), (2, 2), (3, 3)]);
+  /// let e = HashMap::<i32, i32>::new();
+  ///
+  /// assert_eq!(a.checked_sum_values(), Some(6));
+  /// assert_eq!(HashMap::from([(1, i32::MAX), (2, 1)]).checked_sum_values(), None);
+  ///
+  /// assert_eq!(e.checked_sum_values(), Some(0));
+  /// ```
+  #[inline]
+  #[must_use]
+  fn checked_sum_values(self) -> Option<Value>
+  where
+    Self: IntoIterator<Item = (Key, Value)> + Sized,
+    Value: Integer,
+  {
+    checked_sum(self.into_iter().map(|(_, v)| v))
+  }
+
   /// Transforms this map into the specified collection type.
   ///
   /// `collect()` can take any map and turn it into a relevant
@@ -378,6 +509,11 @@ where
   /// Creates a new map containing a result of a function
   /// specified number of times.
   ///
+  /// Hash-based maps can only be created this way with the default hasher,
+  /// use [`fill_with_hasher()`] to specify a custom hasher.
+  ///
+  /// [`fill_with_hasher()`]: Map::fill_with_hasher
+  ///
   /// # Example
   ///
   /// ```
@@ -393,13 +529,45 @@ where
   #[must_use]
   fn fill_with(mut value: impl FnMut() -> (Key, Value), size: usize) -> Self
   where
-    Self: FromIterator<(Key, Value)>,
+    Self: FromIterator<(Key, Value)> + Constructible,
     Key: Clone,
     Value: Clone,
   {
     iter::repeat_n(value(), size).collect()
   }
 
+  /// Creates a new hash-based map containing a result of a function
+  /// specified number of times using the specified hasher.
+  ///
+  /// This is a variant of [`fill_with()`] for hash-based maps with a custom hasher.
+  ///
+  /// [`fill_with()`]: Map::fill_with
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  /// use std::hash::RandomState;
+  ///
+  /// use cantrip::*;
+  ///
+  /// assert_eq!(HashMap::fill_with_hasher(|| (1, 1), 1, RandomState::new()), HashMap::from([(1, 1),]));
+  ///
+  /// assert_eq!(HashMap::fill_with_hasher(|| (1, 1), 0, RandomState::new()), HashMap::new());
+  /// ```
+  #[inline]
+  #[must_use]
+  fn fill_with_hasher<S>(mut value: impl FnMut() -> (Key, Value), size: usize, hash_builder: S) -> Self
+  where
+    Self: Extend<(Key, Value)> + HasherConstructible<S>,
+    Key: Clone,
+    Value: Clone,
+  {
+    let mut result = Self::from_hasher(hash_builder);
+    result.extend(iter::repeat_n(value(), size));
+    result
+  }
+
   /// Creates a new map by filtering the original map using a
   /// closure to determine if an entry should be retained.
   ///
@@ -1014,6 +1182,37 @@ where
     self.into_iter().filter(|(k, v)| retained.contains(&(k, v))).collect()
   }
 
+  /// Creates a new vector containing the n entries of this map with the
+  /// largest values in descending order of their values.
+  ///
+  /// It takes *O*(*n* \* log(*k*)) time and *O*(*k*) memory, where *k* is the number of selected elements.
+  /// Entries with equal values are returned in their iteration order.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// # let a_source = HashMap::from([(1, 2), (2, 1), (3, 3)]);
+  /// let a = HashMap::from([(1, 2), (2, 1), (3, 3)]);
+  /// let e = HashMap::<i32, i32>::new();
+  ///
+  /// assert_eq!(a.largest_by_value(2), vec![(3, 3), (1, 2)]);
+  /// # let a = a_source.clone();
+  /// assert_eq!(a.largest_by_value(4), vec![(3, 3), (1, 2), (2, 1)]);
+  /// assert_eq!(e.largest_by_value(3), vec![]);
+  /// ```
+  #[must_use]
+  fn largest_by_value(self, n: usize) -> Vec<(Key, Value)>
+  where
+    Self: IntoIterator<Item = (Key, Value)> + Sized,
+    Value: Ord,
+  {
+    smallest_by(self.into_iter(), n, |(_, x), (_, y)| y.cmp(x))
+  }
+
   /// Creates a new map by applying the given closure `function` to each entry in
   /// the original map.
   ///
@@ -1148,6 +1347,62 @@ where
     self.into_iter().map(|(k, v)| (function(&k), v)).collect()
   }
 
+  /// Creates a new map by applying the given fallible closure `function` to each entry
+  /// in the original map, collecting all errors.
+  ///
+  /// Unlike [`try_map()`], the closure is applied to every entry even after
+  /// an error occurs, so that all errors can be reported at once.
+  ///
+  /// [`try_map()`]: Map::try_map
+  ///
+  /// # Errors
+  ///
+  /// Returns a map of keys of the failed entries to the errors produced
+  /// by the closure if there are any.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = HashMap::from([(1, "1"), (2, "2"), (3, "3")]);
+  /// let b = HashMap::from([(1, "1"), (2, "x"), (3, "y")]);
+  ///
+  /// assert_eq!(
+  ///   a.map_validated(|(k, v)| v.parse::<i32>().map(|v| (k, v)).map_err(|_| v)),
+  ///   Ok(HashMap::from([(1, 1), (2, 2), (3, 3),]))
+  /// );
+  /// assert_eq!(
+  ///   b.map_validated(|(k, v)| v.parse::<i32>().map(|v| (k, v)).map_err(|_| v)),
+  ///   Err(HashMap::from([(2, "x"), (3, "y"),]))
+  /// );
+  /// ```
+  fn map_validated<L, W, E>(
+    self, mut function: impl FnMut((Key, Value)) -> Result<(L, W), E>,
+  ) -> Result<Self::This<L, W>, Self::This<Key, E>>
+  where
+    Self: IntoIterator<Item = (Key, Value)> + Sized,
+    Self::This<L, W>: Default + Extend<(L, W)>,
+    Self::This<Key, E>: Default + Extend<(Key, E)>,
+    Key: Clone,
+  {
+    let mut result: Self::This<L, W> = Self::This::default();
+    let mut errors: Option<Self::This<Key, E>> = None;
+    for (key, value) in self {
+      match function((key.clone(), value)) {
+        Ok(entry) => {
+          if errors.is_none() {
+            result.extend(iter::once(entry));
+          }
+        }
+        Err(error) => errors.get_or_insert_with(Self::This::default).extend(iter::once((key, error))),
+      }
+    }
+    errors.map_or(Ok(result), Err)
+  }
+
   /// Creates a new map by applying the given closure `function` to each value in
   /// the original map.
   ///
@@ -1274,11 +1529,19 @@ where
     self.max_by(|x, y| x.cmp(&y))
   }
 
-  /// Returns the entry that gives the minimum value with respect to the
-  /// specified comparison function.
+  /// Returns the entry with the maximum value of this map of floating point numbers.
   ///
-  /// If several entries are equally minimum, the last entry is
-  /// returned. If the map is empty, [`None`] is returned.
+  /// This is a variant of [`max_of()`] for floating point values which do not implement [`Ord`].
+  /// If several entries have equally maximum values, the last entry is returned.
+  /// If the map is empty or contains only skipped values, [`None`] is returned.
+  ///
+  /// NaN values are treated according to the specified [`NanPolicy`]:
+  ///
+  /// - [`NanPolicy::Total`] orders them using the IEEE 754 `totalOrder` predicate
+  /// - [`NanPolicy::Skip`] ignores them
+  /// - [`NanPolicy::Propagate`] returns the first of them
+  ///
+  /// [`max_of()`]: Map::max_of
   ///
   /// # Example
   ///
@@ -1287,24 +1550,26 @@ where
   ///
   /// use cantrip::*;
   ///
-  /// let a = HashMap::from([(1, 1), (2, 2), (3, 3)]);
-  /// let e = HashMap::<i32, i32>::new();
+  /// let a = HashMap::from([(1, 1.0), (2, 3.0), (3, f64::NAN)]);
+  /// let e = HashMap::<i32, f64>::new();
   ///
-  /// assert_eq!(a.min_by(|x, y| x.0.cmp(y.0)), Some((&1, &1)));
+  /// assert_eq!(a.max_of_float_values(NanPolicy::Skip), Some((&2, &3.0)));
   ///
-  /// assert_eq!(e.min_by(|x, y| x.0.cmp(y.0)), None);
+  /// assert_eq!(e.max_of_float_values(NanPolicy::Skip), None);
   /// ```
   #[inline]
   #[must_use]
-  fn min_by(&self, mut compare: impl FnMut((&Key, &Value), (&Key, &Value)) -> Ordering) -> Option<(&Key, &Value)> {
-    self.into_iter().min_by(|&x, &y| compare(x, y))
+  fn max_of_float_values(&self, nan: NanPolicy) -> Option<(&Key, &Value)>
+  where
+    Value: Float,
+  {
+    minmax_float(self.into_iter(), |(_, v)| *v, nan).map(|(_, max)| max)
   }
 
-  /// Returns the entry that gives the minimum value from the
-  /// specified function.
+  /// Computes the arithmetic mean of key function values for entries of this map.
   ///
-  /// If several entries are equally the minimum, the last entry is
-  /// returned. If the map is empty, [`None`] is returned.
+  /// Uses a running average which does not overflow for large values.
+  /// If the map is empty, [`None`] is returned.
   ///
   /// # Example
   ///
@@ -1313,26 +1578,23 @@ where
   ///
   /// use cantrip::*;
   ///
-  /// let a = HashMap::from([(1, 1), (2, 2), (3, 3)]);
+  /// let a = HashMap::from([(1, 1), (2, 2), (3, 3), (4, 4)]);
   /// let e = HashMap::<i32, i32>::new();
   ///
-  /// assert_eq!(a.min_by_key(|(k, _)| -k), Some((&3, &3)));
+  /// assert_eq!(a.mean_by_key(|(_, &v)| f64::from(v)), Some(2.5));
   ///
-  /// assert_eq!(e.min_by_key(|(k, _)| -k), None);
+  /// assert_eq!(e.mean_by_key(|(_, &v)| f64::from(v)), None);
   /// ```
   #[inline]
   #[must_use]
-  fn min_by_key<K>(&self, mut to_key: impl FnMut((&Key, &Value)) -> K) -> Option<(&Key, &Value)>
-  where
-    K: Ord,
-  {
-    self.into_iter().min_by_key(|&x| to_key(x))
+  fn mean_by_key(&self, to_key: impl FnMut((&Key, &Value)) -> f64) -> Option<f64> {
+    mean(self.into_iter().map(to_key))
   }
 
-  /// Returns the minimum entry of this map.
+  /// Computes the arithmetic mean of values of this map.
   ///
-  /// If several entries are equally minimum, the last entry is
-  /// returned. If the map is empty, [`None`] is returned.
+  /// Uses a running average which does not overflow for large values.
+  /// If the map is empty, [`None`] is returned.
   ///
   /// # Example
   ///
@@ -1341,29 +1603,26 @@ where
   ///
   /// use cantrip::*;
   ///
-  /// let a = HashMap::from([(1, 1), (2, 2), (3, 3)]);
+  /// let a = HashMap::from([(1, 1), (2, 2), (3, 3), (4, 4)]);
   /// let e = HashMap::<i32, i32>::new();
   ///
-  /// assert_eq!(a.min_of(), Some((&1, &1)));
+  /// assert_eq!(a.mean_values(), Some(2.5));
   ///
-  /// assert_eq!(e.min_of(), None);
+  /// assert_eq!(e.mean_values(), None);
   /// ```
   #[inline]
   #[must_use]
-  fn min_of(&self) -> Option<(&Key, &Value)>
+  fn mean_values(&self) -> Option<f64>
   where
-    Key: Ord,
-    Value: Ord,
+    Value: Number,
   {
-    self.min_by(|(k1, v1), (k2, v2)| (k1, v1).cmp(&(k2, v2)))
+    self.mean_by_key(|(_, v)| v.as_f64())
   }
 
-  /// Returns the minimum and maximum entry of this map with respect to the
-  /// specified comparison function.
+  /// Computes the median of key function values for entries of this map.
   ///
-  /// For the minimum, the first minimal entry is returned. For the maximum,
-  /// the last maximal entry is returned. If the map is empty, [`None`] is returned.
-  /// This matches the behavior of the standard [`Iterator::min`] and [`Iterator::max`] methods.
+  /// If the number of values is even, the mean of the two middle values is returned.
+  /// If the map is empty, [`None`] is returned.
   ///
   /// # Example
   ///
@@ -1372,27 +1631,23 @@ where
   ///
   /// use cantrip::*;
   ///
-  /// let a = HashMap::from([(1, 1), (2, 2), (3, 3)]);
+  /// let a = HashMap::from([(1, 1), (2, 2), (3, 3), (4, 4)]);
   /// let e = HashMap::<i32, i32>::new();
   ///
-  /// assert_eq!(a.minmax_by(|x, y| x.0.cmp(y.0)), Some(((&1, &1), (&3, &3))));
+  /// assert_eq!(a.median_by_key(|(_, &v)| f64::from(v)), Some(2.5));
   ///
-  /// assert_eq!(e.minmax_by(|x, y| x.0.cmp(y.0)), None);
+  /// assert_eq!(e.median_by_key(|(_, &v)| f64::from(v)), None);
   /// ```
   #[inline]
   #[must_use]
-  fn minmax_by(
-    &self, compare: impl FnMut((&Key, &Value), (&Key, &Value)) -> Ordering,
-  ) -> Option<((&Key, &Value), (&Key, &Value))> {
-    minmax_by_pairs(self.into_iter(), compare)
+  fn median_by_key(&self, to_key: impl FnMut((&Key, &Value)) -> f64) -> Option<f64> {
+    quantile(&sorted(self.into_iter().map(to_key)), 0.5)
   }
 
-  /// Returns the minimum and maximum entry of this map from the
-  /// specified function.
+  /// Computes the median of values of this map.
   ///
-  /// For the minimum, the first minimal entry is returned. For the maximum,
-  /// the last maximal entry is returned. If the map is empty, [`None`] is returned.
-  /// This matches the behavior of the standard [`Iterator::min`] and [`Iterator::max`] methods.
+  /// If the number of values is even, the mean of the two middle values is returned.
+  /// If the map is empty, [`None`] is returned.
   ///
   /// # Example
   ///
@@ -1401,27 +1656,27 @@ where
   ///
   /// use cantrip::*;
   ///
-  /// let a = HashMap::from([(1, 1), (2, 2), (3, 3)]);
+  /// let a = HashMap::from([(1, 1), (2, 2), (3, 3), (4, 4)]);
   /// let e = HashMap::<i32, i32>::new();
   ///
-  /// assert_eq!(a.minmax_by_key(|(k, _)| -k), Some(((&3, &3), (&1, &1))));
+  /// assert_eq!(a.median_values(), Some(2.5));
   ///
-  /// assert_eq!(e.minmax_by_key(|(k, _)| -k), None);
+  /// assert_eq!(e.median_values(), None);
   /// ```
   #[inline]
   #[must_use]
-  fn minmax_by_key<K>(&self, mut to_key: impl FnMut((&Key, &Value)) -> K) -> Option<((&Key, &Value), (&Key, &Value))>
+  fn median_values(&self) -> Option<f64>
   where
-    K: Ord,
+    Value: Number,
   {
-    minmax_by_pairs(self.into_iter(), |x, y| to_key(x).cmp(&to_key(y)))
+    self.median_by_key(|(_, v)| v.as_f64())
   }
 
-  /// Return the minimum and maximum entry of this map.
+  /// Returns the entry that gives the minimum value with respect to the
+  /// specified comparison function.
   ///
-  /// For the minimum, the first minimal entry is returned. For the maximum,
-  /// the last maximal entry is returned. If the map is empty, [`None`] is returned.
-  /// This matches the behavior of the standard [`Iterator::min`] and [`Iterator::max`] methods.
+  /// If several entries are equally minimum, the last entry is
+  /// returned. If the map is empty, [`None`] is returned.
   ///
   /// # Example
   ///
@@ -1433,26 +1688,21 @@ where
   /// let a = HashMap::from([(1, 1), (2, 2), (3, 3)]);
   /// let e = HashMap::<i32, i32>::new();
   ///
-  /// assert_eq!(a.minmax_of(), Some(((&1, &1), (&3, &3))));
+  /// assert_eq!(a.min_by(|x, y| x.0.cmp(y.0)), Some((&1, &1)));
   ///
-  /// assert_eq!(e.minmax_of(), None);
+  /// assert_eq!(e.min_by(|x, y| x.0.cmp(y.0)), None);
   /// ```
   #[inline]
   #[must_use]
-  fn minmax_of(&self) -> Option<((&Key, &Value), (&Key, &Value))>
-  where
-    Key: Ord,
-    Value: Ord,
-  {
-    self.minmax_by(|(x1, x2), (y1, y2)| (x1, x2).cmp(&(y1, y2)))
+  fn min_by(&self, mut compare: impl FnMut((&Key, &Value), (&Key, &Value)) -> Ordering) -> Option<(&Key, &Value)> {
+    self.into_iter().min_by(|&x, &y| compare(x, y))
   }
 
-  /// Creates two new maps from the original map using by applying
-  /// specified predicate.
+  /// Returns the entry that gives the minimum value from the
+  /// specified function.
   ///
-  /// The predicate passed to `partition()` can return `true`, or `false`.
-  /// `partition()` returns a pair, all the entries for which it returned
-  /// `true`, and all the entries for which it returned `false`.
+  /// If several entries are equally the minimum, the last entry is
+  /// returned. If the map is empty, [`None`] is returned.
   ///
   /// # Example
   ///
@@ -1462,11 +1712,287 @@ where
   /// use cantrip::*;
   ///
   /// let a = HashMap::from([(1, 1), (2, 2), (3, 3)]);
+  /// let e = HashMap::<i32, i32>::new();
   ///
-  /// let (even, odd) = a.partition(|(&k, _)| k % 2 == 0);
+  /// assert_eq!(a.min_by_key(|(k, _)| -k), Some((&3, &3)));
   ///
-  /// assert_eq!(even, HashMap::from([(2, 2),]));
-  /// assert_eq!(odd, HashMap::from([(1, 1), (3, 3),]));
+  /// assert_eq!(e.min_by_key(|(k, _)| -k), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn min_by_key<K>(&self, mut to_key: impl FnMut((&Key, &Value)) -> K) -> Option<(&Key, &Value)>
+  where
+    K: Ord,
+  {
+    self.into_iter().min_by_key(|&x| to_key(x))
+  }
+
+  /// Returns the minimum entry of this map.
+  ///
+  /// If several entries are equally minimum, the last entry is
+  /// returned. If the map is empty, [`None`] is returned.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = HashMap::from([(1, 1), (2, 2), (3, 3)]);
+  /// let e = HashMap::<i32, i32>::new();
+  ///
+  /// assert_eq!(a.min_of(), Some((&1, &1)));
+  ///
+  /// assert_eq!(e.min_of(), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn min_of(&self) -> Option<(&Key, &Value)>
+  where
+    Key: Ord,
+    Value: Ord,
+  {
+    self.min_by(|(k1, v1), (k2, v2)| (k1, v1).cmp(&(k2, v2)))
+  }
+
+  /// Returns the entry with the minimum value of this map of floating point numbers.
+  ///
+  /// This is a variant of [`min_of()`] for floating point values which do not implement [`Ord`].
+  /// If several entries have equally minimum values, the first entry is returned.
+  /// If the map is empty or contains only skipped values, [`None`] is returned.
+  ///
+  /// NaN values are treated according to the specified [`NanPolicy`]:
+  ///
+  /// - [`NanPolicy::Total`] orders them using the IEEE 754 `totalOrder` predicate
+  /// - [`NanPolicy::Skip`] ignores them
+  /// - [`NanPolicy::Propagate`] returns the first of them
+  ///
+  /// [`min_of()`]: Map::min_of
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = HashMap::from([(1, 1.0), (2, 3.0), (3, f64::NAN)]);
+  /// let e = HashMap::<i32, f64>::new();
+  ///
+  /// assert_eq!(a.min_of_float_values(NanPolicy::Skip), Some((&1, &1.0)));
+  ///
+  /// assert_eq!(e.min_of_float_values(NanPolicy::Skip), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn min_of_float_values(&self, nan: NanPolicy) -> Option<(&Key, &Value)>
+  where
+    Value: Float,
+  {
+    minmax_float(self.into_iter(), |(_, v)| *v, nan).map(|(min, _)| min)
+  }
+
+  /// Returns the minimum and maximum entry of this map with respect to the
+  /// specified comparison function.
+  ///
+  /// For the minimum, the first minimal entry is returned. For the maximum,
+  /// the last maximal entry is returned. If the map is empty, [`None`] is returned.
+  /// This matches the behavior of the standard [`Iterator::min`] and [`Iterator::max`] methods.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = HashMap::from([(1, 1), (2, 2), (3, 3)]);
+  /// let e = HashMap::<i32, i32>::new();
+  ///
+  /// assert_eq!(a.minmax_by(|x, y| x.0.cmp(y.0)), Some(((&1, &1), (&3, &3))));
+  ///
+  /// assert_eq!(e.minmax_by(|x, y| x.0.cmp(y.0)), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn minmax_by(
+    &self, compare: impl FnMut((&Key, &Value), (&Key, &Value)) -> Ordering,
+  ) -> Option<((&Key, &Value), (&Key, &Value))> {
+    minmax_by_pairs(self.into_iter(), compare)
+  }
+
+  /// Returns the minimum and maximum entry of this map from the
+  /// specified function.
+  ///
+  /// For the minimum, the first minimal entry is returned. For the maximum,
+  /// the last maximal entry is returned. If the map is empty, [`None`] is returned.
+  /// This matches the behavior of the standard [`Iterator::min`] and [`Iterator::max`] methods.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = HashMap::from([(1, 1), (2, 2), (3, 3)]);
+  /// let e = HashMap::<i32, i32>::new();
+  ///
+  /// assert_eq!(a.minmax_by_key(|(k, _)| -k), Some(((&3, &3), (&1, &1))));
+  ///
+  /// assert_eq!(e.minmax_by_key(|(k, _)| -k), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn minmax_by_key<K>(&self, mut to_key: impl FnMut((&Key, &Value)) -> K) -> Option<((&Key, &Value), (&Key, &Value))>
+  where
+    K: Ord,
+  {
+    minmax_by_pairs(self.into_iter(), |x, y| to_key(x).cmp(&to_key(y)))
+  }
+
+  /// Return the minimum and maximum entry of this map.
+  ///
+  /// For the minimum, the first minimal entry is returned. For the maximum,
+  /// the last maximal entry is returned. If the map is empty, [`None`] is returned.
+  /// This matches the behavior of the standard [`Iterator::min`] and [`Iterator::max`] methods.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = HashMap::from([(1, 1), (2, 2), (3, 3)]);
+  /// let e = HashMap::<i32, i32>::new();
+  ///
+  /// assert_eq!(a.minmax_of(), Some(((&1, &1), (&3, &3))));
+  ///
+  /// assert_eq!(e.minmax_of(), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn minmax_of(&self) -> Option<((&Key, &Value), (&Key, &Value))>
+  where
+    Key: Ord,
+    Value: Ord,
+  {
+    self.minmax_by(|(x1, x2), (y1, y2)| (x1, x2).cmp(&(y1, y2)))
+  }
+
+  /// Returns the entries with the minimum and maximum value of this map of floating point numbers.
+  ///
+  /// This is a variant of [`minmax_of()`] for floating point values which do not implement [`Ord`].
+  /// For the minimum, the first minimal entry is returned. For the maximum,
+  /// the last maximal entry is returned.
+  /// If the map is empty or contains only skipped values, [`None`] is returned.
+  ///
+  /// NaN values are treated according to the specified [`NanPolicy`]:
+  ///
+  /// - [`NanPolicy::Total`] orders them using the IEEE 754 `totalOrder` predicate
+  /// - [`NanPolicy::Skip`] ignores them
+  /// - [`NanPolicy::Propagate`] returns the first of them
+  ///
+  /// [`minmax_of()`]: Map::minmax_of
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = HashMap::from([(1, 1.0), (2, 3.0), (3, f64::NAN)]);
+  /// let e = HashMap::<i32, f64>::new();
+  ///
+  /// assert_eq!(a.minmax_of_float_values(NanPolicy::Skip), Some(((&1, &1.0), (&2, &3.0))));
+  ///
+  /// assert_eq!(e.minmax_of_float_values(NanPolicy::Skip), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn minmax_of_float_values(&self, nan: NanPolicy) -> Option<((&Key, &Value), (&Key, &Value))>
+  where
+    Value: Float,
+  {
+    minmax_float(self.into_iter(), |(_, v)| *v, nan)
+  }
+
+  /// Computes the most frequent value of key function values for entries of this map.
+  ///
+  /// If several values are equally frequent, the smallest one is returned.
+  /// If the map is empty, [`None`] is returned.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = HashMap::from([(1, 1), (2, 2), (3, 3), (4, 4)]);
+  /// let e = HashMap::<i32, i32>::new();
+  ///
+  /// assert_eq!(a.mode_by_key(|(_, &v)| f64::from(v)), Some(1.0));
+  ///
+  /// assert_eq!(e.mode_by_key(|(_, &v)| f64::from(v)), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn mode_by_key(&self, to_key: impl FnMut((&Key, &Value)) -> f64) -> Option<f64> {
+    mode(self.into_iter().map(to_key))
+  }
+
+  /// Computes the most frequent value of values of this map.
+  ///
+  /// If several values are equally frequent, the smallest one is returned.
+  /// If the map is empty, [`None`] is returned.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = HashMap::from([(1, 1), (2, 2), (3, 3), (4, 4)]);
+  /// let e = HashMap::<i32, i32>::new();
+  ///
+  /// assert_eq!(a.mode_values(), Some(1.0));
+  ///
+  /// assert_eq!(e.mode_values(), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn mode_values(&self) -> Option<f64>
+  where
+    Value: Number,
+  {
+    self.mode_by_key(|(_, v)| v.as_f64())
+  }
+
+  /// Creates two new maps from the original map using by applying
+  /// specified predicate.
+  ///
+  /// The predicate passed to `partition()` can return `true`, or `false`.
+  /// `partition()` returns a pair, all the entries for which it returned
+  /// `true`, and all the entries for which it returned `false`.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = HashMap::from([(1, 1), (2, 2), (3, 3)]);
+  ///
+  /// let (even, odd) = a.partition(|(&k, _)| k % 2 == 0);
+  ///
+  /// assert_eq!(even, HashMap::from([(2, 2),]));
+  /// assert_eq!(odd, HashMap::from([(1, 1), (3, 3),]));
   /// ```
   #[inline]
   #[must_use]
@@ -1565,6 +2091,136 @@ where
     (result_left, result_right)
   }
 
+  /// Creates two new maps from the original map of results by separating
+  /// the entries with `Ok` values from the entries with `Err` values.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a: HashMap<i32, Result<i32, &str>> = HashMap::from([(1, Ok(1)), (2, Err("x")), (3, Ok(3))]);
+  ///
+  /// let (ok, err) = a.partition_results();
+  ///
+  /// assert_eq!(ok, HashMap::from([(1, 1), (3, 3),]));
+  /// assert_eq!(err, HashMap::from([(2, "x"),]));
+  /// ```
+  #[must_use]
+  fn partition_results<W, E>(self) -> (Self::This<Key, W>, Self::This<Key, E>)
+  where
+    Self: IntoIterator<Item = (Key, Result<W, E>)> + Sized,
+    Self::This<Key, W>: Default + Extend<(Key, W)>,
+    Self::This<Key, E>: Default + Extend<(Key, E)>,
+  {
+    let mut result_left: Self::This<Key, W> = Self::This::default();
+    let mut result_right: Self::This<Key, E> = Self::This::default();
+    for (key, value) in self {
+      match value {
+        Ok(value) => result_left.extend(iter::once((key, value))),
+        Err(error) => result_right.extend(iter::once((key, error))),
+      }
+    }
+    (result_left, result_right)
+  }
+
+  /// Computes the specified percentiles of key function values for entries of this map.
+  ///
+  /// Each percentile is computed using linear interpolation between the closest ranks.
+  /// If the map is empty, [`None`] is returned.
+  ///
+  /// # Panics
+  ///
+  /// Panics if any of the `percentages` is not between 0 and 100.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = HashMap::from([(1, 1), (2, 2), (3, 3), (4, 4)]);
+  /// let e = HashMap::<i32, i32>::new();
+  ///
+  /// assert_eq!(a.percentiles_by_key(vec![0.0, 50.0, 100.0], |(_, &v)| f64::from(v)), Some(vec![1.0, 2.5, 4.0]));
+  ///
+  /// assert_eq!(e.percentiles_by_key(vec![0.0, 50.0, 100.0], |(_, &v)| f64::from(v)), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn percentiles_by_key(
+    &self, percentages: impl IntoIterator<Item = f64>, to_key: impl FnMut((&Key, &Value)) -> f64,
+  ) -> Option<Vec<f64>> {
+    percentiles(self.into_iter().map(to_key), percentages)
+  }
+
+  /// Computes the specified percentiles of values of this map.
+  ///
+  /// Each percentile is computed using linear interpolation between the closest ranks.
+  /// If the map is empty, [`None`] is returned.
+  ///
+  /// # Panics
+  ///
+  /// Panics if any of the `percentages` is not between 0 and 100.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = HashMap::from([(1, 1), (2, 2), (3, 3), (4, 4)]);
+  /// let e = HashMap::<i32, i32>::new();
+  ///
+  /// assert_eq!(a.percentiles_values(vec![0.0, 50.0, 100.0]), Some(vec![1.0, 2.5, 4.0]));
+  ///
+  /// assert_eq!(e.percentiles_values(vec![0.0, 50.0, 100.0]), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn percentiles_values(&self, percentages: impl IntoIterator<Item = f64>) -> Option<Vec<f64>>
+  where
+    Value: Number,
+  {
+    self.percentiles_by_key(percentages, |(_, v)| v.as_f64())
+  }
+
+  /// Sums values of this map using compensated summation.
+  ///
+  /// Uses the Kahan–Neumaier algorithm which keeps the rounding error independent
+  /// of the number of values. An empty map returns zero.
+  /// This is an accurate variant of [`sum_values()`] for floating point numbers.
+  ///
+  /// [`sum_values()`]: Map::sum_values
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = HashMap::from([(1, 1.0), (2, 1e100), (3, 1.0), (4, -1e100)]);
+  /// let e = HashMap::<i32, f64>::new();
+  ///
+  /// assert_eq!(a.precise_sum_values(), 2.0);
+  ///
+  /// assert_eq!(e.precise_sum_values(), 0.0);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn precise_sum_values(self) -> Value
+  where
+    Self: IntoIterator<Item = (Key, Value)> + Sized,
+    Value: Float,
+  {
+    precise_sum(self.into_iter().map(|(_, v)| v))
+  }
+
   /// Iterates over the entire map, multiplying all the keys
   ///
   /// An empty map returns the one value of the type.
@@ -1639,6 +2295,67 @@ where
     self.into_iter().map(|(_, v)| v).product()
   }
 
+  /// Computes the `q`-th quantile of key function values for entries of this map.
+  ///
+  /// The quantile is computed using linear interpolation between the closest ranks.
+  /// If the map is empty, [`None`] is returned.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `q` is not between 0 and 1.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = HashMap::from([(1, 1), (2, 2), (3, 3), (4, 4)]);
+  /// let e = HashMap::<i32, i32>::new();
+  ///
+  /// assert_eq!(a.quantile_by_key(0.25, |(_, &v)| f64::from(v)), Some(1.75));
+  ///
+  /// assert_eq!(e.quantile_by_key(0.25, |(_, &v)| f64::from(v)), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn quantile_by_key(&self, q: f64, to_key: impl FnMut((&Key, &Value)) -> f64) -> Option<f64> {
+    quantile(&sorted(self.into_iter().map(to_key)), q)
+  }
+
+  /// Computes the `q`-th quantile of values of this map.
+  ///
+  /// The quantile is computed using linear interpolation between the closest ranks.
+  /// If the map is empty, [`None`] is returned.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `q` is not between 0 and 1.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = HashMap::from([(1, 1), (2, 2), (3, 3), (4, 4)]);
+  /// let e = HashMap::<i32, i32>::new();
+  ///
+  /// assert_eq!(a.quantile_values(0.25), Some(1.75));
+  ///
+  /// assert_eq!(e.quantile_values(0.25), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn quantile_values(&self, q: f64) -> Option<f64>
+  where
+    Value: Number,
+  {
+    self.quantile_by_key(q, |(_, v)| v.as_f64())
+  }
+
   /// Reduces the elements to a single one by repeatedly applying a reducing
   /// operation.
   ///
@@ -1739,6 +2456,156 @@ where
     })
   }
 
+  /// Sums keys of this map, saturating at the numeric bounds instead of overflowing.
+  ///
+  /// The exact sum is clamped to the numeric bounds only once, so the result does not depend
+  /// on the order of keys.
+  /// An empty map returns the zero value of the type.
+  /// This is an overflow-aware variant of [`sum_keys()`].
+  ///
+  /// [`sum_keys()`]: Map::sum_keys
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = HashMap::from([(1, 1), (2, 2), (3, 3)]);
+  /// let e = HashMap::<i32, i32>::new();
+  ///
+  /// assert_eq!(a.saturating_sum_keys(), 6);
+  /// assert_eq!(HashMap::from([(i32::MAX, 1), (1, 2)]).saturating_sum_keys(), i32::MAX);
+  ///
+  /// assert_eq!(e.saturating_sum_keys(), 0);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn saturating_sum_keys(self) -> Key
+  where
+    Self: IntoIterator<Item = (Key, Value)> + Sized,
+    Key: Integer,
+  {
+    saturating_sum(self.into_iter().map(|(k, _)| k))
+  }
+
+  /// Sums values of this map, saturating at the numeric bounds instead of overflowing.
+  ///
+  /// The exact sum is clamped to the numeric bounds only once, so the result does not depend
+  /// on the order of values.
+  /// An empty map returns the zero value of the type.
+  /// This is an overflow-aware variant of [`sum_values()`].
+  ///
+  /// [`sum_values()`]: Map::sum_values
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = HashMap::from([(1, 1), (2, 2), (3, 3)]);
+  /// let e = HashMap::<i32, i32>::new();
+  ///
+  /// assert_eq!(a.saturating_sum_values(), 6);
+  /// assert_eq!(HashMap::from([(1, i32::MAX), (2, 1)]).saturating_sum_values(), i32::MAX);
+  ///
+  /// assert_eq!(e.saturating_sum_values(), 0);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn saturating_sum_values(self) -> Value
+  where
+    Self: IntoIterator<Item = (Key, Value)> + Sized,
+    Value: Integer,
+  {
+    saturating_sum(self.into_iter().map(|(_, v)| v))
+  }
+
+  /// Creates a new vector containing the n entries of this map with the
+  /// smallest values in ascending order of their values.
+  ///
+  /// It takes *O*(*n* \* log(*k*)) time and *O*(*k*) memory, where *k* is the number of selected elements.
+  /// Entries with equal values are returned in their iteration order.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// # let a_source = HashMap::from([(1, 2), (2, 3), (3, 1)]);
+  /// let a = HashMap::from([(1, 2), (2, 3), (3, 1)]);
+  /// let e = HashMap::<i32, i32>::new();
+  ///
+  /// assert_eq!(a.smallest_by_value(2), vec![(3, 1), (1, 2)]);
+  /// # let a = a_source.clone();
+  /// assert_eq!(a.smallest_by_value(4), vec![(3, 1), (1, 2), (2, 3)]);
+  /// assert_eq!(e.smallest_by_value(3), vec![]);
+  /// ```
+  #[must_use]
+  fn smallest_by_value(self, n: usize) -> Vec<(Key, Value)>
+  where
+    Self: IntoIterator<Item = (Key, Value)> + Sized,
+    Value: Ord,
+  {
+    smallest_by(self.into_iter(), n, |(_, x), (_, y)| x.cmp(y))
+  }
+
+  /// Computes the sample standard deviation of key function values for entries of this map.
+  ///
+  /// If the map contains fewer than two entries, [`None`] is returned.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = HashMap::from([(1, 1), (2, 3)]);
+  /// let e = HashMap::<i32, i32>::new();
+  ///
+  /// assert_eq!(a.std_dev_by_key(|(_, &v)| f64::from(v)), Some(2.0_f64.sqrt()));
+  ///
+  /// assert_eq!(e.std_dev_by_key(|(_, &v)| f64::from(v)), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn std_dev_by_key(&self, to_key: impl FnMut((&Key, &Value)) -> f64) -> Option<f64> {
+    variance(self.into_iter().map(to_key)).map(f64::sqrt)
+  }
+
+  /// Computes the sample standard deviation of values of this map.
+  ///
+  /// If the map contains fewer than two entries, [`None`] is returned.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = HashMap::from([(1, 1), (2, 3)]);
+  /// let e = HashMap::<i32, i32>::new();
+  ///
+  /// assert_eq!(a.std_dev_values(), Some(2.0_f64.sqrt()));
+  ///
+  /// assert_eq!(e.std_dev_values(), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn std_dev_values(&self) -> Option<f64>
+  where
+    Value: Number,
+  {
+    self.std_dev_by_key(|(_, v)| v.as_f64())
+  }
+
   /// Tests if all keys of this map can be found in another collection.
   ///
   /// Returns `true` if this map is empty.
@@ -1824,7 +2691,7 @@ where
   {
     let keys_iterator = keys.iterator();
     let mut replaced = HashMap::<&Key, LinkedList<(Key, Value)>>::with_capacity(keys_iterator.size_hint().0);
-    for (item, replacement) in keys_iterator.zip(replacements.into_iter()) {
+    for (item, replacement) in keys_iterator.zip(replacements) {
       replaced.entry(item).or_default().push_back(replacement);
     }
     self
@@ -1944,6 +2811,63 @@ where
     self.into_iter().map(|(_, v)| v).sum()
   }
 
+  /// Computes the descriptive statistics summary of key function values for entries of this map.
+  ///
+  /// The [`Summary`] consists of the count, minimum, maximum, mean and quartiles.
+  /// If the map is empty, [`None`] is returned.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = HashMap::from([(1, 1), (2, 2), (3, 3), (4, 4)]);
+  /// let e = HashMap::<i32, i32>::new();
+  ///
+  /// let s = a.summary_by_key(|(_, &v)| f64::from(v)).unwrap();
+  ///
+  /// assert_eq!((s.count, s.min, s.max, s.median), (4, 1.0, 4.0, 2.5));
+  ///
+  /// assert_eq!(e.summary_by_key(|(_, &v)| f64::from(v)), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn summary_by_key(&self, to_key: impl FnMut((&Key, &Value)) -> f64) -> Option<Summary> {
+    summary(self.into_iter().map(to_key))
+  }
+
+  /// Computes the descriptive statistics summary of values of this map.
+  ///
+  /// The [`Summary`] consists of the count, minimum, maximum, mean and quartiles.
+  /// If the map is empty, [`None`] is returned.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = HashMap::from([(1, 1), (2, 2), (3, 3), (4, 4)]);
+  /// let e = HashMap::<i32, i32>::new();
+  ///
+  /// let s = a.summary_values().unwrap();
+  ///
+  /// assert_eq!((s.count, s.min, s.max, s.median), (4, 1.0, 4.0, 2.5));
+  ///
+  /// assert_eq!(e.summary_values(), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn summary_values(&self) -> Option<Summary>
+  where
+    Value: Number,
+  {
+    self.summary_by_key(|(_, v)| v.as_f64())
+  }
+
   /// Creates a new vector from the keys of this map in arbitrary order.
   ///
   /// # Example
@@ -1990,8 +2914,266 @@ where
     self.into_iter().map(|(_, v)| v).cloned().collect()
   }
 
+  /// Creates a new map by filtering the original map using a fallible
+  /// closure to determine if an entry should be retained.
+  ///
+  /// Given an entry the closure must return `Ok(true)` or `Ok(false)`. The returned
+  /// map will contain only the entries for which the closure returns `Ok(true)`.
+  ///
+  /// This is a consuming variant of [`try_filter_ref()`].
+  ///
+  /// [`try_filter_ref()`]: Map::try_filter_ref
+  ///
+  /// # Errors
+  ///
+  /// Returns the first error produced by the closure,
+  /// without evaluating the remaining entries.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// # let a_source = HashMap::from([(1, 1), (2, 2), (3, 3)]);
+  /// let a = HashMap::from([(1, 1), (2, 2), (3, 3)]);
+  ///
+  /// assert_eq!(
+  ///   a.try_filter(|(&k, &v)| if v < 4 { Ok(k != 2) } else { Err(k) }),
+  ///   Ok(HashMap::from([(1, 1), (3, 3),]))
+  /// );
+  ///
+  /// # let a = a_source.clone();
+  /// assert_eq!(a.try_filter(|(&k, &v)| if v < 3 { Ok(true) } else { Err(k) }), Err(3));
+  /// ```
+  #[inline]
+  fn try_filter<E>(self, mut predicate: impl FnMut((&Key, &Value)) -> Result<bool, E>) -> Result<Self, E>
+  where
+    Self: IntoIterator<Item = (Key, Value)> + FromIterator<(Key, Value)>,
+  {
+    self
+      .into_iter()
+      .filter_map(|(k, v)| match predicate((&k, &v)) {
+        Ok(true) => Some(Ok((k, v))),
+        Ok(false) => None,
+        Err(error) => Some(Err(error)),
+      })
+      .collect()
+  }
+
+  /// Creates a new map by filtering the original map using a fallible
+  /// closure to determine if an entry should be retained.
+  ///
+  /// Given an entry the closure must return `Ok(true)` or `Ok(false)`. The returned
+  /// map will contain only the entries for which the closure returns `Ok(true)`.
+  ///
+  /// This is a non-consuming variant of [`try_filter()`].
+  ///
+  /// [`try_filter()`]: Map::try_filter
+  ///
+  /// # Errors
+  ///
+  /// Returns the first error produced by the closure,
+  /// without evaluating the remaining entries.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = HashMap::from([(1, 1), (2, 2), (3, 3)]);
+  ///
+  /// assert_eq!(
+  ///   a.try_filter_ref(|(&k, &v)| if v < 4 { Ok(k != 2) } else { Err(k) }),
+  ///   Ok(HashMap::from([(1, 1), (3, 3),]))
+  /// );
+  /// assert_eq!(a.try_filter_ref(|(&k, &v)| if v < 3 { Ok(true) } else { Err(k) }), Err(3));
+  /// ```
+  #[inline]
+  fn try_filter_ref<E>(&self, mut predicate: impl FnMut((&Key, &Value)) -> Result<bool, E>) -> Result<Self, E>
+  where
+    Self: FromIterator<(Key, Value)>,
+    Key: Clone,
+    Value: Clone,
+  {
+    self
+      .into_iter()
+      .filter_map(|(k, v)| match predicate((k, v)) {
+        Ok(true) => Some(Ok((k.clone(), v.clone()))),
+        Ok(false) => None,
+        Err(error) => Some(Err(error)),
+      })
+      .collect()
+  }
+
+  /// Folds every entry into an accumulator by applying a fallible operation,
+  /// returning the final result.
+  ///
+  /// `try_fold()` takes two arguments: an initial value, and a closure with two
+  /// arguments: an 'accumulator', and an entry. The closure either returns
+  /// `Ok` with the value that the accumulator should have for the next iteration,
+  /// or `Err` which is propagated back to the caller immediately.
+  ///
+  /// This is a consuming variant of [`try_fold_ref()`].
+  ///
+  /// [`try_fold_ref()`]: Map::try_fold_ref
+  ///
+  /// # Errors
+  ///
+  /// Returns the first error produced by the closure,
+  /// without evaluating the remaining entries.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// # let a_source = HashMap::from([(1, 1), (2, 2), (3, 3)]);
+  /// let a = HashMap::from([(1, 1), (2, 2), (3, 3)]);
+  ///
+  /// assert_eq!(a.try_fold(0_i8, |acc, (k, v)| acc.checked_add(k + v).ok_or(k)), Ok(12));
+  ///
+  /// # let a = a_source.clone();
+  /// assert!(a.try_fold(127_i8, |acc, (k, v)| acc.checked_add(k + v).ok_or(k)).is_err());
+  /// ```
+  #[inline]
+  fn try_fold<B, E>(self, initial_value: B, function: impl FnMut(B, (Key, Value)) -> Result<B, E>) -> Result<B, E>
+  where
+    Self: IntoIterator<Item = (Key, Value)> + Sized,
+  {
+    self.into_iter().try_fold(initial_value, function)
+  }
+
+  /// Folds every entry into an accumulator by applying a fallible operation,
+  /// returning the final result.
+  ///
+  /// `try_fold_ref()` takes two arguments: an initial value, and a closure with two
+  /// arguments: an 'accumulator', and a reference to an entry. The closure either
+  /// returns `Ok` with the value that the accumulator should have for the next iteration,
+  /// or `Err` which is propagated back to the caller immediately.
+  ///
+  /// This is a non-consuming variant of [`try_fold()`].
+  ///
+  /// [`try_fold()`]: Map::try_fold
+  ///
+  /// # Errors
+  ///
+  /// Returns the first error produced by the closure,
+  /// without evaluating the remaining entries.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = HashMap::from([(1, 1), (2, 2), (3, 3)]);
+  ///
+  /// assert_eq!(a.try_fold_ref(0_i8, |acc, (&k, &v)| acc.checked_add(k + v).ok_or(k)), Ok(12));
+  /// assert!(a.try_fold_ref(127_i8, |acc, (&k, &v)| acc.checked_add(k + v).ok_or(k)).is_err());
+  /// ```
+  #[inline]
+  fn try_fold_ref<B, E>(
+    &self, initial_value: B, function: impl FnMut(B, (&Key, &Value)) -> Result<B, E>,
+  ) -> Result<B, E> {
+    self.into_iter().try_fold(initial_value, function)
+  }
+
+  /// Creates a new map by applying the given fallible closure `function` to each entry
+  /// in the original map.
+  ///
+  /// The closure `function` takes an entry of the type `(Key, Value)` and returns
+  /// either a value of type `(L, W)` or an error of type `E`.
+  ///
+  /// This is a consuming variant of [`try_map_ref()`].
+  ///
+  /// [`try_map_ref()`]: Map::try_map_ref
+  ///
+  /// # Errors
+  ///
+  /// Returns the first error produced by the closure,
+  /// without evaluating the remaining entries.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// # let a_source = HashMap::from([(1, "1"), (2, "2"), (3, "3")]);
+  /// let a = HashMap::from([(1, "1"), (2, "2"), (3, "3")]);
+  ///
+  /// assert_eq!(
+  ///   a.try_map(|(k, v)| v.parse::<i32>().map(|v| (k, v))),
+  ///   Ok(HashMap::from([(1, 1), (2, 2), (3, 3),]))
+  /// );
+  ///
+  /// # let a = a_source.clone();
+  /// assert_eq!(a.try_map(|(k, v)| if k == 2 { Err(v) } else { Ok((k, v)) }), Err("2"));
+  /// ```
+  #[inline]
+  fn try_map<L, W, E>(self, function: impl FnMut((Key, Value)) -> Result<(L, W), E>) -> Result<Self::This<L, W>, E>
+  where
+    Self: IntoIterator<Item = (Key, Value)> + Sized,
+    Self::This<L, W>: FromIterator<(L, W)>,
+  {
+    self.into_iter().map(function).collect()
+  }
+
+  /// Creates a new map by applying the given fallible closure `function` to each entry
+  /// in the original map.
+  ///
+  /// The closure `function` takes a reference to an entry of the type `(Key, Value)`
+  /// and returns either a value of type `(L, W)` or an error of type `E`.
+  ///
+  /// This is a non-consuming variant of [`try_map()`].
+  ///
+  /// [`try_map()`]: Map::try_map
+  ///
+  /// # Errors
+  ///
+  /// Returns the first error produced by the closure,
+  /// without evaluating the remaining entries.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = HashMap::from([(1, "1"), (2, "2"), (3, "3")]);
+  ///
+  /// assert_eq!(
+  ///   a.try_map_ref(|(&k, v)| v.parse::<i32>().map(|v| (k, v))),
+  ///   Ok(HashMap::from([(1, 1), (2, 2), (3, 3),]))
+  /// );
+  /// assert_eq!(a.try_map_ref(|(&k, &v)| if k == 2 { Err(v) } else { Ok((k, v)) }), Err("2"));
+  /// ```
+  #[inline]
+  fn try_map_ref<L, W, E>(
+    &self, function: impl FnMut((&Key, &Value)) -> Result<(L, W), E>,
+  ) -> Result<Self::This<L, W>, E>
+  where
+    Self::This<L, W>: FromIterator<(L, W)>,
+  {
+    self.into_iter().map(function).collect()
+  }
+
   /// Creates a new map containing a single element.
   ///
+  /// Hash-based maps can only be created this way with the default hasher,
+  /// use [`unit_with_hasher()`] to specify a custom hasher.
+  ///
+  /// [`unit_with_hasher()`]: Map::unit_with_hasher
+  ///
   /// # Example
   ///
   /// ```
@@ -2007,10 +3189,90 @@ where
   #[must_use]
   fn unit(key: Key, value: Value) -> Self
   where
-    Self: FromIterator<(Key, Value)>,
+    Self: FromIterator<(Key, Value)> + Constructible,
   {
     iter::once((key, value)).collect()
   }
+
+  /// Creates a new hash-based map containing a single element using the specified hasher.
+  ///
+  /// This is a variant of [`unit()`] for hash-based maps with a custom hasher.
+  ///
+  /// [`unit()`]: Map::unit
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  /// use std::hash::RandomState;
+  ///
+  /// use cantrip::*;
+  ///
+  /// assert_eq!(HashMap::unit_with_hasher(1, 1, RandomState::new()), HashMap::from([(1, 1),]));
+  /// ```
+  #[inline]
+  #[must_use]
+  fn unit_with_hasher<S>(key: Key, value: Value, hash_builder: S) -> Self
+  where
+    Self: Extend<(Key, Value)> + HasherConstructible<S>,
+  {
+    let mut result = Self::from_hasher(hash_builder);
+    result.extend(iter::once((key, value)));
+    result
+  }
+
+  /// Computes the sample variance of key function values for entries of this map.
+  ///
+  /// Uses Welford's online algorithm which is numerically stable.
+  /// If the map contains fewer than two entries, [`None`] is returned.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = HashMap::from([(1, 1), (2, 3)]);
+  /// let e = HashMap::<i32, i32>::new();
+  ///
+  /// assert_eq!(a.variance_by_key(|(_, &v)| f64::from(v)), Some(2.0));
+  ///
+  /// assert_eq!(e.variance_by_key(|(_, &v)| f64::from(v)), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn variance_by_key(&self, to_key: impl FnMut((&Key, &Value)) -> f64) -> Option<f64> {
+    variance(self.into_iter().map(to_key))
+  }
+
+  /// Computes the sample variance of values of this map.
+  ///
+  /// Uses Welford's online algorithm which is numerically stable.
+  /// If the map contains fewer than two entries, [`None`] is returned.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = HashMap::from([(1, 1), (2, 3)]);
+  /// let e = HashMap::<i32, i32>::new();
+  ///
+  /// assert_eq!(a.variance_values(), Some(2.0));
+  ///
+  /// assert_eq!(e.variance_values(), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn variance_values(&self) -> Option<f64>
+  where
+    Value: Number,
+  {
+    self.variance_by_key(|(_, v)| v.as_f64())
+  }
 }
 
 pub(crate) fn minmax_by_pairs<'a, K: 'a, V: 'a>(
@@ -1,17 +1,24 @@
+pub use array::*;
 pub use collection::*;
 pub use collection_to::*;
 pub use convert::*;
 pub use list::*;
 pub use map::*;
 pub use sequence::*;
+pub use sequence_mut::*;
 pub use sequence_to::*;
 pub use slice::*;
+pub use text::*;
 
+pub(crate) mod array;
 pub(crate) mod collection;
 pub(crate) mod collection_to;
 pub(crate) mod convert;
 pub(crate) mod list;
 pub(crate) mod map;
 pub(crate) mod sequence;
+pub(crate) mod sequence_mut;
 pub(crate) mod sequence_to;
 pub(crate) mod slice;
+pub(crate) mod text;
+pub(crate) mod traversal;
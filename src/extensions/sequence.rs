@@ -1,8 +1,17 @@
-use crate::Iterable;
+use crate::Parallelism;
+use crate::core::diff::{diff, longest_common_subsequence};
+use crate::core::distance::{damerau_levenshtein_distance, hamming_distance, levenshtein_distance};
+use crate::core::parallel::parallel_chunks;
+use crate::core::statistics::{
+  covariance, dot, mean_absolute_error, pearson_correlation, root_mean_squared_error, spearman_correlation,
+};
 use crate::extensions::frequencies;
-use std::collections::{HashMap, HashSet};
+use crate::{DiffHunk, Iterable, LengthMismatch, Number};
+use std::array;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fmt::{Display, Write};
-use std::hash::Hash;
+use std::hash::{BuildHasher, Hash, RandomState};
+use std::ops::Range;
 
 /// Ordered collection operations.
 ///
@@ -13,6 +22,94 @@ pub trait Sequence<Item>
 where
   for<'i> &'i Self: IntoIterator<Item = &'i Item>,
 {
+  /// Searches this sorted sequence for an element with the specified key, returning its index.
+  ///
+  /// If there are multiple matching elements, the index of the first one is returned.
+  /// If there is no matching element, [`None`] is returned.
+  ///
+  /// This sequence must be sorted in ascending order, otherwise the result is unspecified.
+  /// Sequences supporting random access are searched in *O*(log *n*) time,
+  /// other sequences are searched in *O*(*n*) time.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![(1, 'a'), (2, 'b'), (2, 'c'), (4, 'd')];
+  ///
+  /// assert_eq!(a.binary_position_by_key(&2, |&(x, _)| x), Some(1));
+  ///
+  /// assert_eq!(a.binary_position_by_key(&3, |&(x, _)| x), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn binary_position_by_key<K: Ord>(&self, key: &K, to_key: impl FnMut(&Item) -> K) -> Option<usize> {
+    let range = self.equal_range_by_key(key, to_key);
+    if range.is_empty() { None } else { Some(range.start) }
+  }
+
+  /// Searches this sorted sequence for an element, returning its index.
+  ///
+  /// If there are multiple matching elements, the index of the first one is returned.
+  /// If there is no matching element, [`None`] is returned.
+  ///
+  /// This sequence must be sorted in ascending order, otherwise the result is unspecified.
+  /// Sequences supporting random access are searched in *O*(log *n*) time,
+  /// other sequences are searched in *O*(*n*) time.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 2, 4];
+  ///
+  /// assert_eq!(a.binary_position_of(&2), Some(1));
+  /// assert_eq!(a.binary_position_of(&4), Some(3));
+  ///
+  /// assert_eq!(a.binary_position_of(&3), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn binary_position_of(&self, element: &Item) -> Option<usize>
+  where
+    Item: Ord,
+  {
+    let range = self.equal_range(element);
+    if range.is_empty() { None } else { Some(range.start) }
+  }
+
+  /// Creates a new vector of arrays by splitting this sequence's elements
+  /// into non-overlapping chunks of `K` elements.
+  ///
+  /// The chunks do not overlap. If `K` does not divide the length of this sequence,
+  /// then the last up to `K-1` elements will be omitted.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `K` is 0.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3];
+  ///
+  /// assert_eq!(a.chunked_array::<2>(), vec![[1, 2]]);
+  /// assert_eq!(a.chunked_array::<1>(), vec![[1], [2], [3]]);
+  /// ```
+  #[must_use]
+  fn chunked_array<const K: usize>(&self) -> Vec<[Item; K]>
+  where
+    Item: Clone,
+  {
+    assert_ne!(K, 0, "chunk size must be non-zero");
+    let values = self.into_iter().collect::<Vec<_>>();
+    values.chunks_exact(K).map(|chunk| array::from_fn(|index| chunk[index].clone())).collect()
+  }
+
   /// Computes the length of the longest common prefix shared by this sequence and another collection.
   ///
   /// # Example
@@ -25,125 +122,990 @@ where
   /// assert_eq!(a.common_prefix_length(&vec![1, 2, 3, 4]), 3);
   /// assert_eq!(a.common_prefix_length(&vec![1, 2]), 2);
   ///
-  /// assert_eq!(a.common_prefix_length(&vec![]), 0);
+  /// assert_eq!(a.common_prefix_length(&vec![]), 0);
+  /// ```
+  #[must_use]
+  fn common_prefix_length<'a>(&'a self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> usize
+  where
+    Item: PartialEq + 'a;
+
+  /// Computes the length of the longest common suffix shared by this sequence and another collection.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3];
+  ///
+  /// assert_eq!(a.common_suffix_length(&vec![0, 1, 2, 3]), 3);
+  /// assert_eq!(a.common_suffix_length(&vec![2, 3]), 2);
+  ///
+  /// assert_eq!(a.common_suffix_length(&vec![]), 0);
+  /// ```
+  #[must_use]
+  fn common_suffix_length<'a, I>(&'a self, elements: &'a impl Iterable<Item<'a> = &'a Item, Iterator<'a> = I>) -> usize
+  where
+    I: DoubleEndedIterator<Item = &'a Item>,
+    Item: PartialEq + 'a;
+
+  /// Tests if this sequence contains a subsequence.
+  ///
+  /// Returns `true` if the specified sequence is empty.
+  ///
+  /// The search uses the Knuth-Morris-Pratt algorithm and takes *O*(*n* + *m*) time.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 1, 3];
+  ///
+  /// assert!(a.contains_sequence(&vec![1, 3]));
+  /// assert!(a.contains_sequence(&vec![]));
+  ///
+  /// assert!(!a.contains_sequence(&vec![3, 1]));
+  /// ```
+  #[inline]
+  #[must_use]
+  fn contains_sequence<'a>(&'a self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> bool
+  where
+    Item: PartialEq + 'a,
+  {
+    self.position_sequence(elements).is_some()
+  }
+
+  /// Counts the number of unique elements in this sequence.
+  ///
+  /// Returns `0` for an empty sequence.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 2, 3];
+  /// let e = Vec::<i32>::new();
+  ///
+  /// assert_eq!(a.count_unique(), 3);
+  ///
+  /// assert_eq!(e.count_unique(), 0);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn count_unique(&self) -> usize
+  where
+    Item: Eq + Hash,
+  {
+    let items = self.into_iter().collect::<HashSet<_>>();
+    items.len()
+  }
+
+  /// Counts the number of unique elements in this sequence.
+  ///
+  /// Returns `0` for an empty sequence.
+  ///
+  /// This is a variant of [`count_unique()`] for elements which can only be compared for equality.
+  /// It takes *O*(*n*<sup>2</sup>) time.
+  ///
+  /// [`count_unique()`]: Sequence::count_unique
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1.0, 2.0, 2.0, 3.0];
+  ///
+  /// assert_eq!(a.count_unique_eq(), 3);
+  /// ```
+  #[must_use]
+  fn count_unique_eq(&self) -> usize
+  where
+    Item: PartialEq,
+  {
+    let mut occurred = Vec::<&Item>::new();
+    for item in self {
+      if !occurred.contains(&item) {
+        occurred.push(item);
+      }
+    }
+    occurred.len()
+  }
+
+  /// Counts the number of unique elements in this sequence.
+  ///
+  /// Returns `0` for an empty sequence.
+  ///
+  /// This is a variant of [`count_unique()`] for elements which are ordered but not hashable.
+  ///
+  /// [`count_unique()`]: Sequence::count_unique
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 2, 3];
+  ///
+  /// assert_eq!(a.count_unique_ord(), 3);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn count_unique_ord(&self) -> usize
+  where
+    Item: Ord,
+  {
+    let items = self.into_iter().collect::<BTreeSet<_>>();
+    items.len()
+  }
+
+  /// Computes the sample covariance of this sequence and another collection.
+  ///
+  /// Elements are paired by their position.
+  /// If the sequences contain fewer than two elements, `Ok(None)` is returned.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`LengthMismatch`] if this sequence and the other collection have different lengths.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3, 4];
+  ///
+  /// assert_eq!(a.covariance(&vec![2, 4, 6, 8]), Ok(Some(10.0 / 3.0)));
+  /// assert_eq!(a.covariance(&vec![4, 3, 2, 1]), Ok(Some(-5.0 / 3.0)));
+  ///
+  /// assert_eq!(a.covariance(&vec![1, 2]), Err(LengthMismatch { left: 4, right: 2 }));
+  /// assert_eq!(Vec::<i32>::new().covariance(&vec![]), Ok(None));
+  /// ```
+  #[inline]
+  fn covariance<'a>(&'a self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> Result<Option<f64>, LengthMismatch>
+  where
+    Item: Number + 'a,
+  {
+    covariance(self.into_iter().map(|x| x.as_f64()), elements.iterator().map(|x| x.as_f64()))
+  }
+
+  /// Computes the Damerau-Levenshtein distance between this sequence and another collection.
+  ///
+  /// The distance is the minimum number of element insertions, deletions, substitutions
+  /// and transpositions of two adjacent elements required to change this sequence into the other collection.
+  /// Transposed elements may be further edited, so the result never exceeds the [`levenshtein_distance()`].
+  ///
+  /// This operation takes *O*(*n* \* *m*) time and memory.
+  ///
+  /// [`levenshtein_distance()`]: Sequence::levenshtein_distance
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec!['c', 'a'];
+  ///
+  /// assert_eq!(a.damerau_levenshtein_distance(&vec!['a', 'c']), 1);
+  /// assert_eq!(a.damerau_levenshtein_distance(&vec!['a', 'b', 'c']), 2);
+  /// assert_eq!(a.damerau_levenshtein_distance(&vec!['c', 'a']), 0);
+  /// assert_eq!(a.damerau_levenshtein_distance(&vec![]), 2);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn damerau_levenshtein_distance<'a>(&'a self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> usize
+  where
+    Item: PartialEq + 'a,
+  {
+    damerau_levenshtein_distance(self.into_iter(), elements.iterator())
+  }
+
+  /// Computes an edit script transforming this sequence into another collection.
+  ///
+  /// The edit script consists of hunks describing ranges of elements which are equal,
+  /// deleted from this sequence, inserted from the other collection or replaced.
+  /// A deletion directly followed by an insertion is reported as a replacement.
+  /// Applying the edit script to this sequence using [`apply_diff()`] yields the other collection.
+  ///
+  /// The common prefix and suffix are trimmed first and the remaining elements are compared using
+  /// the linear space variant of the Myers difference algorithm which finds a shortest edit script
+  /// in *O*((*n* + *m*) \* *d*) time and *O*(*n* + *m*) space,
+  /// where *d* is the number of deleted and inserted elements.
+  ///
+  /// [`apply_diff()`]: crate::SequenceTo::apply_diff
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3, 4];
+  ///
+  /// assert_eq!(a.diff(&vec![0, 2, 3, 4, 5]), vec![
+  ///   DiffHunk::Replace { source_index: 0, target_index: 0, length: 1, elements: vec![0] },
+  ///   DiffHunk::Equal { source_index: 1, target_index: 1, length: 3 },
+  ///   DiffHunk::Insert { source_index: 4, target_index: 4, elements: vec![5] },
+  /// ]);
+  /// assert_eq!(a.diff(&vec![1, 2, 3, 4]), vec![
+  ///   DiffHunk::Equal { source_index: 0, target_index: 0, length: 4 },
+  /// ]);
+  /// assert_eq!(a.diff(&vec![1, 4]), vec![
+  ///   DiffHunk::Equal { source_index: 0, target_index: 0, length: 1 },
+  ///   DiffHunk::Delete { source_index: 1, target_index: 1, length: 2 },
+  ///   DiffHunk::Equal { source_index: 3, target_index: 1, length: 1 },
+  /// ]);
+  /// ```
+  #[must_use]
+  fn diff<'a, I>(&'a self, elements: &'a impl Iterable<Item<'a> = &'a Item, Iterator<'a> = I>) -> Vec<DiffHunk<Item>>
+  where
+    I: DoubleEndedIterator<Item = &'a Item>,
+    Item: Clone + PartialEq + 'a,
+  {
+    let prefix_length = self.common_prefix_length(elements);
+    let suffix_length = self.common_suffix_length(elements);
+    diff(self.into_iter(), elements.iterator(), prefix_length, suffix_length)
+  }
+
+  /// Computes the dot product of this sequence and another collection.
+  ///
+  /// Elements are paired by their position.
+  /// If the sequences are empty, `Ok(0.0)` is returned.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`LengthMismatch`] if this sequence and the other collection have different lengths.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3];
+  ///
+  /// assert_eq!(a.dot(&vec![4, 5, 6]), Ok(32.0));
+  ///
+  /// assert_eq!(a.dot(&vec![4, 5]), Err(LengthMismatch { left: 3, right: 2 }));
+  /// assert_eq!(Vec::<i32>::new().dot(&vec![]), Ok(0.0));
+  /// ```
+  #[inline]
+  fn dot<'a>(&'a self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> Result<f64, LengthMismatch>
+  where
+    Item: Number + 'a,
+  {
+    dot(self.into_iter().map(|x| x.as_f64()), elements.iterator().map(|x| x.as_f64()))
+  }
+
+  /// Searches this sorted sequence for the range of indices of elements equal to the specified element.
+  ///
+  /// If there is no matching element, an empty range starting at the index
+  /// where the element could be inserted while maintaining the sorted order is returned.
+  ///
+  /// This sequence must be sorted in ascending order, otherwise the result is unspecified.
+  /// Sequences supporting random access are searched in *O*(log *n*) time,
+  /// other sequences are searched in *O*(*n*) time.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 2, 4];
+  ///
+  /// assert_eq!(a.equal_range(&2), 1..3);
+  /// assert_eq!(a.equal_range(&3), 3..3);
+  /// assert_eq!(a.equal_range(&5), 4..4);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn equal_range(&self, element: &Item) -> Range<usize>
+  where
+    Item: Ord,
+  {
+    self.lower_bound(element)..self.upper_bound(element)
+  }
+
+  /// Searches this sorted sequence for the range of indices of elements with the specified key.
+  ///
+  /// If there is no matching element, an empty range starting at the index
+  /// where an element with the key could be inserted while maintaining the sorted order is returned.
+  ///
+  /// This sequence must be sorted in ascending order by the key, otherwise the result is unspecified.
+  /// Sequences supporting random access are searched in *O*(log *n*) time,
+  /// other sequences are searched in *O*(*n*) time.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![(1, 'a'), (2, 'b'), (2, 'c'), (4, 'd')];
+  ///
+  /// assert_eq!(a.equal_range_by_key(&2, |&(x, _)| x), 1..3);
+  /// assert_eq!(a.equal_range_by_key(&3, |&(x, _)| x), 3..3);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn equal_range_by_key<K: Ord>(&self, key: &K, mut to_key: impl FnMut(&Item) -> K) -> Range<usize> {
+    self.lower_bound_by_key(key, &mut to_key)..self.upper_bound_by_key(key, to_key)
+  }
+
+  /// Tests if this sequence contains all elements of another collection exactly
+  /// as many times as they appear in the other collection and vice versa.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 2, 3];
+  ///
+  /// assert!(a.equivalent(&vec![3, 2, 1, 2]));
+  ///
+  /// assert!(!a.equivalent(&vec![1, 3, 3]));
+  /// assert!(!a.equivalent(&vec![1, 1, 2, 2, 3]));
+  /// assert!(!a.equivalent(&vec![]));
+  /// ```
+  #[must_use]
+  fn equivalent<'a>(&'a self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> bool
+  where
+    Item: Eq + Hash + 'a;
+
+  /// Tests if this sequence contains all elements of another collection exactly
+  /// as many times as they appear in the other collection and vice versa.
+  ///
+  /// This is a variant of [`equivalent()`] for elements which can only be compared for equality.
+  /// It takes *O*(*n* \* *m*) time.
+  ///
+  /// [`equivalent()`]: Sequence::equivalent
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1.0, 2.0, 2.0, 3.0];
+  ///
+  /// assert!(a.equivalent_eq(&vec![3.0, 2.0, 1.0, 2.0]));
+  ///
+  /// assert!(!a.equivalent_eq(&vec![1.0, 3.0, 3.0]));
+  /// ```
+  #[must_use]
+  fn equivalent_eq<'a>(&'a self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> bool
+  where
+    Item: PartialEq + 'a,
+  {
+    let mut remaining = elements.iterator().collect::<Vec<_>>();
+    for item in self {
+      match remaining.iter().position(|&x| x == item) {
+        Some(index) => {
+          let _unused = remaining.swap_remove(index);
+        }
+        None => return false,
+      }
+    }
+    remaining.is_empty()
+  }
+
+  /// Tests if this sequence contains all elements of another collection exactly
+  /// as many times as they appear in the other collection and vice versa.
+  ///
+  /// This is a variant of [`equivalent()`] for elements which are ordered but not hashable.
+  ///
+  /// [`equivalent()`]: Sequence::equivalent
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 2, 3];
+  ///
+  /// assert!(a.equivalent_ord(&vec![3, 2, 1, 2]));
+  ///
+  /// assert!(!a.equivalent_ord(&vec![1, 3, 3]));
+  /// ```
+  #[must_use]
+  fn equivalent_ord<'a>(&'a self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> bool
+  where
+    Item: Ord + 'a,
+  {
+    let mut items = self.into_iter().collect::<Vec<_>>();
+    let mut other = elements.iterator().collect::<Vec<_>>();
+    if items.len() != other.len() {
+      return false;
+    }
+    items.sort_unstable();
+    other.sort_unstable();
+    items == other
+  }
+
+  /// Find the position and value of the first element in this sequence satisfying a predicate.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3];
+  ///
+  /// assert_eq!(a.find_position(|&x| x == 2), Some((1, &2)));
+  ///
+  /// assert_eq!(a.find_position(|&x| x == 5), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn find_position(&self, mut predicate: impl FnMut(&Item) -> bool) -> Option<(usize, &Item)> {
+    self.into_iter().enumerate().find(|(_, x)| predicate(x))
+  }
+
+  /// Compute the number of occurrences for each element in this sequence.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 2, 3];
+  ///
+  /// assert_eq!(a.frequencies(), HashMap::from([(&1, 1), (&2, 2), (&3, 1)]));
+  /// ```
+  #[inline]
+  #[must_use]
+  fn frequencies<'a>(&'a self) -> HashMap<&'a Item, usize>
+  where
+    Item: Eq + Hash + 'a,
+  {
+    frequencies(self.into_iter())
+  }
+
+  /// Compute the number of occurrences for each element in this sequence.
+  ///
+  /// The resulting pairs are listed in the order of the first occurrence of each element.
+  ///
+  /// This is a variant of [`frequencies()`] for elements which can only be compared for equality.
+  /// It takes *O*(*n*<sup>2</sup>) time.
+  ///
+  /// [`frequencies()`]: Sequence::frequencies
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1.0, 2.0, 2.0, 3.0];
+  ///
+  /// assert_eq!(a.frequencies_eq(), vec![(&1.0, 1), (&2.0, 2), (&3.0, 1)]);
+  /// ```
+  #[must_use]
+  fn frequencies_eq<'a>(&'a self) -> Vec<(&'a Item, usize)>
+  where
+    Item: PartialEq + 'a,
+  {
+    let mut result = Vec::<(&Item, usize)>::new();
+    for item in self {
+      match result.iter_mut().find(|(x, _)| *x == item) {
+        Some((_, count)) => *count += 1,
+        None => result.push((item, 1)),
+      }
+    }
+    result
+  }
+
+  /// Compute the number of occurrences for each element in this sequence.
+  ///
+  /// This is a variant of [`frequencies()`] for elements which are ordered but not hashable.
+  ///
+  /// [`frequencies()`]: Sequence::frequencies
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::BTreeMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 2, 3];
+  ///
+  /// assert_eq!(a.frequencies_ord(), BTreeMap::from([(&1, 1), (&2, 2), (&3, 1)]));
+  /// ```
+  #[must_use]
+  fn frequencies_ord<'a>(&'a self) -> BTreeMap<&'a Item, usize>
+  where
+    Item: Ord + 'a,
+  {
+    let mut result = BTreeMap::new();
+    for item in self {
+      *result.entry(item).or_default() += 1;
+    }
+    result
+  }
+
+  /// Compute the number of occurrences for each element in this sequence
+  /// using the specified hash builder for the resulting map.
+  ///
+  /// This is a variant of [`frequencies()`] supporting custom hashing algorithms.
+  ///
+  /// [`frequencies()`]: Sequence::frequencies
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  /// use std::hash::{BuildHasherDefault, DefaultHasher};
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 2, 3];
+  /// let hash_builder = BuildHasherDefault::<DefaultHasher>::default();
+  ///
+  /// assert_eq!(a.frequencies_with_hasher(hash_builder), HashMap::from_iter([(&1, 1), (&2, 2), (&3, 1)]));
+  /// ```
+  #[must_use]
+  fn frequencies_with_hasher<'a, S>(&'a self, hash_builder: S) -> HashMap<&'a Item, usize, S>
+  where
+    Item: Eq + Hash + 'a,
+    S: BuildHasher,
+  {
+    let iterator = self.into_iter();
+    let mut result = HashMap::with_capacity_and_hasher(iterator.size_hint().0, hash_builder);
+    for item in iterator {
+      *result.entry(item).or_default() += 1;
+    }
+    result
+  }
+
+  /// Compute the number of occurrences for each group of elements in this sequence according to
+  /// the specified discriminator function.
+  ///
+  /// The discriminator function takes a reference to an element and returns a group key.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 2, 3];
+  ///
+  /// assert_eq!(a.frequencies_by(|x| x % 2), HashMap::from([(0, 2), (1, 2)]));
+  /// ```
+  #[inline]
+  #[must_use]
+  fn frequencies_by<K: Eq + Hash>(&self, to_key: impl FnMut(&Item) -> K) -> HashMap<K, usize> {
+    self.frequencies_by_with_hasher(to_key, RandomState::new())
+  }
+
+  /// Compute the number of occurrences for each group of elements in this sequence according to
+  /// the specified discriminator function using the specified hash builder for the resulting map.
+  ///
+  /// This is a variant of [`frequencies_by()`] supporting custom hashing algorithms.
+  ///
+  /// [`frequencies_by()`]: Sequence::frequencies_by
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  /// use std::hash::{BuildHasherDefault, DefaultHasher};
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 2, 3];
+  /// let hash_builder = BuildHasherDefault::<DefaultHasher>::default();
+  ///
+  /// assert_eq!(a.frequencies_by_with_hasher(|x| x % 2, hash_builder), HashMap::from_iter([(0, 2), (1, 2)]));
+  /// ```
+  #[must_use]
+  fn frequencies_by_with_hasher<K: Eq + Hash, S: BuildHasher>(
+    &self, mut to_key: impl FnMut(&Item) -> K, hash_builder: S,
+  ) -> HashMap<K, usize, S> {
+    frequencies_by(self.into_iter(), |&item| to_key(item), hash_builder)
+  }
+
+  /// Computes the Hamming distance between this sequence and another collection.
+  ///
+  /// The distance is the number of positions at which the corresponding elements are different.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`LengthMismatch`] if this sequence and the other collection have different lengths.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3, 4];
+  ///
+  /// assert_eq!(a.hamming_distance(&vec![1, 3, 3, 5]), Ok(2));
+  /// assert_eq!(a.hamming_distance(&vec![1, 2, 3, 4]), Ok(0));
+  ///
+  /// assert_eq!(a.hamming_distance(&vec![1, 2]), Err(LengthMismatch { left: 4, right: 2 }));
+  /// ```
+  #[inline]
+  fn hamming_distance<'a>(&'a self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> Result<usize, LengthMismatch>
+  where
+    Item: PartialEq + 'a,
+  {
+    hamming_distance(self.into_iter(), elements.iterator())
+  }
+
+  /// Tests if the elements of this sequence are sorted in ascending order.
+  ///
+  /// Consecutive equal elements are considered sorted. An empty sequence
+  /// or a sequence with a single element is always sorted.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let e = Vec::<i32>::new();
+  ///
+  /// assert!(vec![1, 2, 2, 3].is_sorted());
+  /// assert!(e.is_sorted());
+  ///
+  /// assert!(!vec![1, 3, 2].is_sorted());
+  /// assert!(!vec![0.0, f64::NAN].is_sorted());
+  /// ```
+  #[inline]
+  #[must_use]
+  fn is_sorted(&self) -> bool
+  where
+    Item: PartialOrd,
+  {
+    self.into_iter().is_sorted()
+  }
+
+  /// Tests if the key function values for elements of this sequence are sorted in ascending order.
+  ///
+  /// Consecutive equal keys are considered sorted. An empty sequence
+  /// or a sequence with a single element is always sorted.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![-1, 2, -3];
+  ///
+  /// assert!(a.is_sorted_by_key(|x: &i32| x.abs()));
+  ///
+  /// assert!(!a.is_sorted_by_key(|&x| x));
+  /// ```
+  #[inline]
+  #[must_use]
+  fn is_sorted_by_key<K: PartialOrd>(&self, to_key: impl FnMut(&Item) -> K) -> bool {
+    self.into_iter().is_sorted_by_key(to_key)
+  }
+
+  /// Tests if all elements of this sequence appear in another collection in the same order,
+  /// but not necessarily adjacent to each other.
+  ///
+  /// An empty sequence is a subsequence of any collection.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 3];
+  /// let e = Vec::<i32>::new();
+  ///
+  /// assert!(a.is_subsequence_of(&vec![1, 2, 3]));
+  /// assert!(e.is_subsequence_of(&vec![1]));
+  ///
+  /// assert!(!a.is_subsequence_of(&vec![3, 2, 1]));
+  /// assert!(!a.is_subsequence_of(&vec![1]));
+  /// ```
+  #[inline]
+  #[must_use]
+  fn is_subsequence_of<'a>(&'a self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> bool
+  where
+    Item: PartialEq + 'a,
+  {
+    let mut elements_iterator = elements.iterator();
+    self.into_iter().all(|item| elements_iterator.any(|element| element == item))
+  }
+
+  /// Combine all elements of this sequence into one `String`, separated by `sep`.
+  ///
+  /// Use the `Display` implementation of each element.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3];
+  /// let e = Vec::<i32>::new();
+  ///
+  /// assert_eq!(a.joined(", "), "1, 2, 3");
+  /// assert_eq!(e.joined(", "), "");
+  /// ```
+  #[must_use]
+  fn joined(&self, separator: &str) -> String
+  where
+    Item: Display,
+  {
+    joined(self.into_iter(), separator)
+  }
+
+  /// Computes the Levenshtein distance between this sequence and another collection.
+  ///
+  /// The distance is the minimum number of element insertions, deletions and substitutions
+  /// required to change this sequence into the other collection.
+  ///
+  /// This operation takes *O*(*n* \* *m*) time and *O*(*m*) memory.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec!['k', 'i', 't', 't', 'e', 'n'];
+  ///
+  /// assert_eq!(a.levenshtein_distance(&vec!['s', 'i', 't', 't', 'i', 'n', 'g']), 3);
+  /// assert_eq!(a.levenshtein_distance(&vec!['k', 'i', 't', 't', 'e', 'n']), 0);
+  /// assert_eq!(a.levenshtein_distance(&vec![]), 6);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn levenshtein_distance<'a>(&'a self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> usize
+  where
+    Item: PartialEq + 'a,
+  {
+    levenshtein_distance(self.into_iter(), elements.iterator())
+  }
+
+  /// Finds a longest common subsequence of this sequence and another collection.
+  ///
+  /// The subsequence is returned as pairs of indices of matching elements in this sequence
+  /// and in the other collection, ordered by their position.
+  /// If there are multiple longest common subsequences, it is unspecified which one is returned.
+  ///
+  /// The common prefix and suffix are matched first and the remaining elements are compared using
+  /// the linear space variant of the Myers difference algorithm which takes *O*((*n* + *m*) \* *d*) time
+  /// and *O*(*n* + *m*) space, where *d* is the number of unmatched elements.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3, 4, 5];
+  ///
+  /// assert_eq!(a.longest_common_subsequence(&vec![1, 3, 5, 6]), vec![(0, 0), (2, 1), (4, 2)]);
+  /// assert_eq!(a.longest_common_subsequence(&vec![6, 7]), vec![]);
+  /// ```
+  #[must_use]
+  fn longest_common_subsequence<'a, I>(
+    &'a self, elements: &'a impl Iterable<Item<'a> = &'a Item, Iterator<'a> = I>,
+  ) -> Vec<(usize, usize)>
+  where
+    I: DoubleEndedIterator<Item = &'a Item>,
+    Item: PartialEq + 'a,
+  {
+    let prefix_length = self.common_prefix_length(elements);
+    let suffix_length = self.common_suffix_length(elements);
+    longest_common_subsequence(self.into_iter(), elements.iterator(), prefix_length, suffix_length)
+  }
+
+  /// Finds a longest strictly increasing subsequence of this sequence.
+  ///
+  /// The subsequence is returned as indices of its elements in this sequence.
+  /// If there are multiple longest increasing subsequences, it is unspecified which one is returned.
+  ///
+  /// This operation takes *O*(*n* \* log(*n*)) time.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![3, 1, 4, 1, 5, 9, 2, 6];
+  /// let e = Vec::<i32>::new();
+  ///
+  /// assert_eq!(a.longest_increasing_subsequence().len(), 4);
+  /// assert_eq!(vec![2, 1, 3].longest_increasing_subsequence().len(), 2);
+  /// assert_eq!(vec![1, 2, 3].longest_increasing_subsequence(), vec![0, 1, 2]);
+  ///
+  /// assert_eq!(e.longest_increasing_subsequence(), vec![]);
   /// ```
   #[must_use]
-  fn common_prefix_length<'a>(&'a self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> usize
+  fn longest_increasing_subsequence(&self) -> Vec<usize>
   where
-    Item: PartialEq + 'a;
+    Item: Ord,
+  {
+    longest_increasing_subsequence(&self.into_iter().collect::<Vec<_>>())
+  }
 
-  /// Computes the length of the longest common suffix shared by this sequence and another collection.
+  /// Finds a longest subsequence of this sequence with strictly increasing key function values.
+  ///
+  /// The subsequence is returned as indices of its elements in this sequence.
+  /// If there are multiple longest increasing subsequences, it is unspecified which one is returned.
+  ///
+  /// This operation takes *O*(*n* \* log(*n*)) time and calls the key function once for each element.
   ///
   /// # Example
   ///
   /// ```
   /// use cantrip::*;
   ///
-  /// let a = vec![1, 2, 3];
-  ///
-  /// assert_eq!(a.common_suffix_length(&vec![0, 1, 2, 3]), 3);
-  /// assert_eq!(a.common_suffix_length(&vec![2, 3]), 2);
+  /// let a = vec![("a", 3), ("b", 1), ("c", 2), ("d", 4)];
   ///
-  /// assert_eq!(a.common_suffix_length(&vec![]), 0);
+  /// assert_eq!(a.longest_increasing_subsequence_by_key(|&(_, x)| x), vec![1, 2, 3]);
+  /// assert_eq!(a.longest_increasing_subsequence_by_key(|&(x, _)| x), vec![0, 1, 2, 3]);
   /// ```
   #[must_use]
-  fn common_suffix_length<'a, I>(&'a self, elements: &'a impl Iterable<Item<'a> = &'a Item, Iterator<'a> = I>) -> usize
-  where
-    I: DoubleEndedIterator<Item = &'a Item>,
-    Item: PartialEq + 'a;
+  fn longest_increasing_subsequence_by_key<K: Ord>(&self, to_key: impl FnMut(&Item) -> K) -> Vec<usize> {
+    longest_increasing_subsequence(&self.into_iter().map(to_key).collect::<Vec<_>>())
+  }
 
-  /// Counts the number of unique elements in this sequence.
+  /// Finds the longest contiguous run of elements in this sequence according to the specified predicate.
   ///
-  /// Returns `0` for an empty sequence.
+  /// The `predicate` is called for every pair of consecutive elements,
+  /// meaning that it is called on `slice[0]` and `slice[1]`,
+  /// followed by `slice[1]` and `slice[2]`, and so on. A run continues
+  /// while the predicate returns `true` for its consecutive elements.
+  ///
+  /// Returns the index range of the first longest run, which is empty if this sequence is empty.
   ///
   /// # Example
   ///
   /// ```
   /// use cantrip::*;
   ///
-  /// let a = vec![1, 2, 2, 3];
+  /// let a = vec![1, 2, 5, 6, 7, 3];
   /// let e = Vec::<i32>::new();
   ///
-  /// assert_eq!(a.count_unique(), 3);
+  /// assert_eq!(a.longest_run_by(|&p, &n| n == p + 1), 2..5);
+  /// assert_eq!(a.longest_run_by(|_, _| false), 0..1);
+  /// assert_eq!(a.longest_run_by(|_, _| true), 0..6);
   ///
-  /// assert_eq!(e.count_unique(), 0);
+  /// assert_eq!(e.longest_run_by(|_, _| true), 0..0);
   /// ```
-  #[inline]
   #[must_use]
-  fn count_unique(&self) -> usize
-  where
-    Item: Eq + Hash,
-  {
-    let items = self.into_iter().collect::<HashSet<_>>();
-    items.len()
+  fn longest_run_by(&self, mut predicate: impl FnMut(&Item, &Item) -> bool) -> Range<usize> {
+    let mut result = 0..0;
+    let mut start = 0_usize;
+    let mut previous: Option<&Item> = None;
+    for (index, item) in self.into_iter().enumerate() {
+      if let Some(previous_item) = previous
+        && !predicate(previous_item, item)
+      {
+        start = index;
+      }
+      if index + 1 - start > result.len() {
+        result = start..index + 1;
+      }
+      previous = Some(item);
+    }
+    result
   }
 
-  /// Tests if this sequence contains all elements of another collection exactly
-  /// as many times as they appear in the other collection and vice versa.
+  /// Searches this sorted sequence for the index of the first element
+  /// which is not less than the specified element.
+  ///
+  /// This is the first index where the element could be inserted while maintaining the sorted order.
+  /// If all elements are less than the specified element, the sequence size is returned.
+  ///
+  /// This sequence must be sorted in ascending order, otherwise the result is unspecified.
+  /// Sequences supporting random access are searched in *O*(log *n*) time,
+  /// other sequences are searched in *O*(*n*) time.
   ///
   /// # Example
   ///
   /// ```
   /// use cantrip::*;
   ///
-  /// let a = vec![1, 2, 2, 3];
-  ///
-  /// assert!(a.equivalent(&vec![3, 2, 1, 2]));
+  /// let a = vec![1, 2, 2, 4];
   ///
-  /// assert!(!a.equivalent(&vec![1, 3, 3]));
-  /// assert!(!a.equivalent(&vec![1, 1, 2, 2, 3]));
-  /// assert!(!a.equivalent(&vec![]));
+  /// assert_eq!(a.lower_bound(&2), 1);
+  /// assert_eq!(a.lower_bound(&3), 3);
+  /// assert_eq!(a.lower_bound(&0), 0);
+  /// assert_eq!(a.lower_bound(&5), 4);
   /// ```
+  #[inline]
   #[must_use]
-  fn equivalent<'a>(&'a self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> bool
+  fn lower_bound(&self, element: &Item) -> usize
   where
-    Item: Eq + Hash + 'a;
+    Item: Ord,
+  {
+    self.into_iter().take_while(|&x| x < element).count()
+  }
 
-  /// Find the position and value of the first element in this sequence satisfying a predicate.
+  /// Searches this sorted sequence for the index of the first element
+  /// whose key is not less than the specified key.
+  ///
+  /// This is the first index where an element with the key could be inserted while maintaining the sorted order.
+  /// If all keys are less than the specified key, the sequence size is returned.
+  ///
+  /// This sequence must be sorted in ascending order by the key, otherwise the result is unspecified.
+  /// Sequences supporting random access are searched in *O*(log *n*) time,
+  /// other sequences are searched in *O*(*n*) time.
   ///
   /// # Example
   ///
   /// ```
   /// use cantrip::*;
   ///
-  /// let a = vec![1, 2, 3];
-  ///
-  /// assert_eq!(a.find_position(|&x| x == 2), Some((1, &2)));
+  /// let a = vec![(1, 'a'), (2, 'b'), (2, 'c'), (4, 'd')];
   ///
-  /// assert_eq!(a.find_position(|&x| x == 5), None);
+  /// assert_eq!(a.lower_bound_by_key(&2, |&(x, _)| x), 1);
+  /// assert_eq!(a.lower_bound_by_key(&5, |&(x, _)| x), 4);
   /// ```
   #[inline]
   #[must_use]
-  fn find_position(&self, mut predicate: impl FnMut(&Item) -> bool) -> Option<(usize, &Item)> {
-    self.into_iter().enumerate().find(|(_, x)| predicate(x))
+  fn lower_bound_by_key<K: Ord>(&self, key: &K, mut to_key: impl FnMut(&Item) -> K) -> usize {
+    self.into_iter().take_while(|&x| to_key(x) < *key).count()
   }
 
-  /// Compute the number of occurrences for each element in this sequence.
+  /// Computes the mean absolute error between this sequence and another collection.
+  ///
+  /// Elements are paired by their position.
+  /// If the sequences are empty, `Ok(None)` is returned.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`LengthMismatch`] if this sequence and the other collection have different lengths.
   ///
   /// # Example
   ///
   /// ```
-  /// use std::collections::HashMap;
-  ///
   /// use cantrip::*;
   ///
-  /// let a = vec![1, 2, 2, 3];
+  /// let predicted = vec![1, 2, 3, 4];
   ///
-  /// assert_eq!(a.frequencies(), HashMap::from([(&1, 1), (&2, 2), (&3, 1)]));
+  /// assert_eq!(predicted.mean_absolute_error(&vec![2, 2, 3, 6]), Ok(Some(0.75)));
+  ///
+  /// assert_eq!(predicted.mean_absolute_error(&vec![1]), Err(LengthMismatch { left: 4, right: 1 }));
+  /// assert_eq!(Vec::<i32>::new().mean_absolute_error(&vec![]), Ok(None));
   /// ```
   #[inline]
-  #[must_use]
-  fn frequencies<'a>(&'a self) -> HashMap<&'a Item, usize>
+  fn mean_absolute_error<'a>(
+    &'a self, elements: &'a impl Iterable<Item<'a> = &'a Item>,
+  ) -> Result<Option<f64>, LengthMismatch>
   where
-    Item: Eq + Hash + 'a,
+    Item: Number + 'a,
   {
-    frequencies(self.into_iter())
+    mean_absolute_error(self.into_iter().map(|x| x.as_f64()), elements.iterator().map(|x| x.as_f64()))
   }
 
-  /// Compute the number of occurrences for each group of elements in this sequence according to
-  /// the specified discriminator function.
+  /// Compute the number of occurrences for each element in this sequence
+  /// on multiple threads.
   ///
-  /// The discriminator function takes a reference to an element and returns a group key.
+  /// Produces the same result as [`frequencies()`]. The elements are divided into
+  /// contiguous chunks processed in parallel according to the specified
+  /// [`Parallelism`] settings.
+  ///
+  /// [`frequencies()`]: Sequence::frequencies
+  /// [`Parallelism`]: crate::Parallelism
   ///
   /// # Example
   ///
@@ -153,51 +1115,57 @@ where
   /// use cantrip::*;
   ///
   /// let a = vec![1, 2, 2, 3];
+  /// let parallelism = Parallelism { threads: 2, threshold: 0 };
   ///
-  /// assert_eq!(a.frequencies_by(|x| x % 2), HashMap::from([(0, 2), (1, 2)]));
+  /// assert_eq!(a.par_frequencies(parallelism), HashMap::from([(&1, 1), (&2, 2), (&3, 1)]));
   /// ```
   #[must_use]
-  fn frequencies_by<K: Eq + Hash>(&self, mut to_key: impl FnMut(&Item) -> K) -> HashMap<K, usize> {
-    let iterator = self.into_iter();
-    let mut result = HashMap::with_capacity(iterator.size_hint().0);
-    for item in iterator {
-      *result.entry(to_key(item)).or_default() += 1;
+  fn par_frequencies<'a>(&'a self, parallelism: Parallelism) -> HashMap<&'a Item, usize>
+  where
+    Item: Eq + Hash + Sync + 'a,
+  {
+    let values = self.into_iter().collect::<Vec<_>>();
+    let partial_frequencies = parallel_chunks(values, parallelism, |chunk| frequencies(chunk.into_iter()));
+    let mut result = HashMap::new();
+    for frequencies in partial_frequencies {
+      for (item, count) in frequencies {
+        *result.entry(item).or_default() += count;
+      }
     }
     result
   }
 
-  /// Combine all elements of this sequence into one `String`, separated by `sep`.
+  /// Computes the Pearson correlation coefficient of this sequence and another collection.
   ///
-  /// Use the `Display` implementation of each element.
+  /// Elements are paired by their position.
+  /// If the sequences contain fewer than two elements or either of them is constant,
+  /// `Ok(None)` is returned.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`LengthMismatch`] if this sequence and the other collection have different lengths.
   ///
   /// # Example
   ///
   /// ```
   /// use cantrip::*;
   ///
-  /// let a = vec![1, 2, 3];
-  /// let e = Vec::<i32>::new();
+  /// let a = vec![1, 2, 3, 4];
   ///
-  /// assert_eq!(a.joined(", "), "1, 2, 3");
-  /// assert_eq!(e.joined(", "), "");
+  /// assert_eq!(a.pearson_correlation(&vec![2, 4, 6, 8]), Ok(Some(1.0)));
+  /// assert_eq!(a.pearson_correlation(&vec![4, 3, 2, 1]), Ok(Some(-1.0)));
+  /// assert_eq!(a.pearson_correlation(&vec![1, 1, 1, 1]), Ok(None));
+  ///
+  /// assert_eq!(a.pearson_correlation(&vec![1, 2]), Err(LengthMismatch { left: 4, right: 2 }));
   /// ```
-  #[must_use]
-  fn joined(&self, separator: &str) -> String
+  #[inline]
+  fn pearson_correlation<'a>(
+    &'a self, elements: &'a impl Iterable<Item<'a> = &'a Item>,
+  ) -> Result<Option<f64>, LengthMismatch>
   where
-    Item: Display,
+    Item: Number + 'a,
   {
-    let mut iterator = self.into_iter();
-    if let Some(item) = iterator.next() {
-      let mut result = String::with_capacity((separator.len() + 1) * iterator.size_hint().0);
-      let _unused = write!(&mut result, "{item}");
-      for item in iterator {
-        result.push_str(separator);
-        let _unused = write!(&mut result, "{item}");
-      }
-      result
-    } else {
-      String::new()
-    }
+    pearson_correlation(self.into_iter().map(|x| x.as_f64()), elements.iterator().map(|x| x.as_f64()))
   }
 
   /// Searches for an element in this sequence, returning its index.
@@ -355,14 +1323,16 @@ where
 
   /// Searches for a subsequence in this sequence, returning its index.
   ///
-  /// After finding a starting element of the specified sequence in this sequence,
-  /// `position_sequence()` compares each element of this sequence with the specified value,
-  /// and if all of them match, then `position_sequence()` returns [`Some(start_index)`].
-  /// If any of the elements do not match, it returns [`None`].
+  /// `position_sequence()` searches for the first index where consecutive elements
+  /// of this sequence match all elements of the specified sequence, and if there is one,
+  /// then `position_sequence()` returns [`Some(start_index)`].
+  /// If there is no such index, it returns [`None`].
   ///
   /// `position_sequence()` is short-circuiting; in other words, it will stop
   /// processing as soon as it finds a matching sequence.
   ///
+  /// The search uses the Knuth-Morris-Pratt algorithm and takes *O*(*n* + *m*) time.
+  ///
   /// Returns `Some(0)` if the specified sequence is empty.
   ///
   /// # Overflow Behavior
@@ -387,15 +1357,46 @@ where
   /// let a = vec![1, 2, 2, 3];
   ///
   /// assert_eq!(a.position_sequence(&vec![2, 2]), Some(1));
+  /// assert_eq!(a.position_sequence(&vec![2, 3]), Some(2));
   /// assert_eq!(a.position_sequence(&vec![]), Some(0));
   ///
   /// assert_eq!(a.position_sequence(&vec![1, 3]), None);
+  /// assert_eq!(a.position_sequence(&vec![3, 4]), None);
   /// ```
   #[must_use]
   fn position_sequence<'a>(&'a self, sequence: &'a impl Iterable<Item<'a> = &'a Item>) -> Option<usize>
   where
     Item: PartialEq + 'a;
 
+  /// Searches for all occurrences of a subsequence in this sequence, returning their indices.
+  ///
+  /// The occurrences may overlap. If the specified sequence is empty,
+  /// every index from `0` to the size of this sequence inclusive is returned.
+  ///
+  /// The search uses the Knuth-Morris-Pratt algorithm and takes *O*(*n* + *m*) time.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 1, 1, 2];
+  ///
+  /// assert_eq!(a.position_sequence_multi(&vec![1, 1]), vec![0, 1]);
+  /// assert_eq!(a.position_sequence_multi(&vec![1, 2]), vec![2]);
+  /// assert_eq!(vec![1, 2].position_sequence_multi(&vec![]), vec![0, 1, 2]);
+  ///
+  /// assert_eq!(a.position_sequence_multi(&vec![2, 1]), vec![]);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn position_sequence_multi<'a>(&'a self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> Vec<usize>
+  where
+    Item: PartialEq + 'a,
+  {
+    sequence_positions(self.into_iter(), elements.iterator()).collect()
+  }
+
   /// Searches for an element of this sequence that satisfies a predicate, starting from the back.
   ///
   /// `rfind()` takes a closure that returns `true` or `false`. It applies
@@ -489,6 +1490,37 @@ where
   #[must_use]
   fn rfold_ref<B>(&self, initial_value: B, function: impl FnMut(B, &Item) -> B) -> B;
 
+  /// Computes the root mean squared error between this sequence and another collection.
+  ///
+  /// Elements are paired by their position.
+  /// If the sequences are empty, `Ok(None)` is returned.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`LengthMismatch`] if this sequence and the other collection have different lengths.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let predicted = vec![1, 2, 3, 4];
+  ///
+  /// assert_eq!(predicted.root_mean_squared_error(&vec![2, 2, 3, 6]), Ok(Some(1.25_f64.sqrt())));
+  ///
+  /// assert_eq!(predicted.root_mean_squared_error(&vec![1]), Err(LengthMismatch { left: 4, right: 1 }));
+  /// assert_eq!(Vec::<i32>::new().root_mean_squared_error(&vec![]), Ok(None));
+  /// ```
+  #[inline]
+  fn root_mean_squared_error<'a>(
+    &'a self, elements: &'a impl Iterable<Item<'a> = &'a Item>,
+  ) -> Result<Option<f64>, LengthMismatch>
+  where
+    Item: Number + 'a,
+  {
+    root_mean_squared_error(self.into_iter().map(|x| x.as_f64()), elements.iterator().map(|x| x.as_f64()))
+  }
+
   /// Searches for an element in this sequence from the right, returning its index.
   ///
   /// `rposition()` takes a closure that returns `true` or `false`. It applies
@@ -527,6 +1559,160 @@ where
   /// ```
   #[must_use]
   fn rposition(&self, predicate: impl FnMut(&Item) -> bool) -> Option<usize>;
+
+  /// Searches for a subsequence in this sequence starting from the back, returning its index.
+  ///
+  /// `rposition_sequence()` searches for the last index where consecutive elements
+  /// of this sequence match all elements of the specified sequence, and if there is one,
+  /// then `rposition_sequence()` returns [`Some(start_index)`].
+  /// If there is no such index, it returns [`None`].
+  ///
+  /// Returns the size of this sequence if the specified sequence is empty.
+  ///
+  /// The search uses the Knuth-Morris-Pratt algorithm and takes *O*(*n* + *m*) time.
+  ///
+  /// [`Some(start_index)`]: Some
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 1, 2];
+  ///
+  /// assert_eq!(a.rposition_sequence(&vec![1, 2]), Some(2));
+  /// assert_eq!(a.rposition_sequence(&vec![2, 1]), Some(1));
+  /// assert_eq!(a.rposition_sequence(&vec![]), Some(4));
+  ///
+  /// assert_eq!(a.rposition_sequence(&vec![2, 2]), None);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn rposition_sequence<'a>(&'a self, elements: &'a impl Iterable<Item<'a> = &'a Item>) -> Option<usize>
+  where
+    Item: PartialEq + 'a,
+  {
+    sequence_positions(self.into_iter(), elements.iterator()).last()
+  }
+
+  /// Computes the Spearman rank correlation coefficient of this sequence and another collection.
+  ///
+  /// Elements are paired by their position and tied values are assigned their average rank.
+  /// If the sequences contain fewer than two elements or either of them is constant,
+  /// `Ok(None)` is returned.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`LengthMismatch`] if this sequence and the other collection have different lengths.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3, 4];
+  ///
+  /// assert_eq!(a.spearman_correlation(&vec![1, 10, 100, 1000]), Ok(Some(1.0)));
+  /// assert_eq!(a.spearman_correlation(&vec![4, 3, 2, 1]), Ok(Some(-1.0)));
+  /// assert_eq!(a.spearman_correlation(&vec![1, 1, 1, 1]), Ok(None));
+  ///
+  /// assert_eq!(a.spearman_correlation(&vec![1, 2]), Err(LengthMismatch { left: 4, right: 2 }));
+  /// ```
+  #[inline]
+  fn spearman_correlation<'a>(
+    &'a self, elements: &'a impl Iterable<Item<'a> = &'a Item>,
+  ) -> Result<Option<f64>, LengthMismatch>
+  where
+    Item: Number + 'a,
+  {
+    spearman_correlation(self.into_iter().map(|x| x.as_f64()), elements.iterator().map(|x| x.as_f64()))
+  }
+
+  /// Searches this sorted sequence for the index of the first element
+  /// which is greater than the specified element.
+  ///
+  /// This is the last index where the element could be inserted while maintaining the sorted order.
+  /// If no element is greater than the specified element, the sequence size is returned.
+  ///
+  /// This sequence must be sorted in ascending order, otherwise the result is unspecified.
+  /// Sequences supporting random access are searched in *O*(log *n*) time,
+  /// other sequences are searched in *O*(*n*) time.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 2, 4];
+  ///
+  /// assert_eq!(a.upper_bound(&2), 3);
+  /// assert_eq!(a.upper_bound(&3), 3);
+  /// assert_eq!(a.upper_bound(&0), 0);
+  /// assert_eq!(a.upper_bound(&4), 4);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn upper_bound(&self, element: &Item) -> usize
+  where
+    Item: Ord,
+  {
+    self.into_iter().take_while(|&x| x <= element).count()
+  }
+
+  /// Searches this sorted sequence for the index of the first element
+  /// whose key is greater than the specified key.
+  ///
+  /// This is the last index where an element with the key could be inserted while maintaining the sorted order.
+  /// If no key is greater than the specified key, the sequence size is returned.
+  ///
+  /// This sequence must be sorted in ascending order by the key, otherwise the result is unspecified.
+  /// Sequences supporting random access are searched in *O*(log *n*) time,
+  /// other sequences are searched in *O*(*n*) time.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![(1, 'a'), (2, 'b'), (2, 'c'), (4, 'd')];
+  ///
+  /// assert_eq!(a.upper_bound_by_key(&2, |&(x, _)| x), 3);
+  /// assert_eq!(a.upper_bound_by_key(&0, |&(x, _)| x), 0);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn upper_bound_by_key<K: Ord>(&self, key: &K, mut to_key: impl FnMut(&Item) -> K) -> usize {
+    self.into_iter().take_while(|&x| to_key(x) <= *key).count()
+  }
+
+  /// Creates a new vector of arrays consisting of overlapping windows
+  /// of `K` consecutive elements of this sequence.
+  ///
+  /// If this sequence is shorter than `K`, the result is empty.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `K` is 0.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3];
+  ///
+  /// assert_eq!(a.windowed_array::<2>(), vec![[1, 2], [2, 3]]);
+  /// assert_eq!(a.windowed_array::<4>(), Vec::<[i32; 4]>::new());
+  /// ```
+  #[must_use]
+  fn windowed_array<const K: usize>(&self) -> Vec<[Item; K]>
+  where
+    Item: Clone,
+  {
+    assert_ne!(K, 0, "window size must be non-zero");
+    let values = self.into_iter().collect::<Vec<_>>();
+    values.windows(K).map(|window| array::from_fn(|index| window[index].clone())).collect()
+  }
 }
 
 pub(crate) fn common_prefix_length<'a, Item: PartialEq + 'a>(
@@ -584,22 +1770,112 @@ pub(crate) fn equivalent<'a, Item: Eq + Hash + 'a>(
   remaining == 0
 }
 
+pub(crate) fn frequencies_by<Item, K: Eq + Hash, S: BuildHasher>(
+  iterator: impl Iterator<Item = Item>, mut to_key: impl FnMut(&Item) -> K, hash_builder: S,
+) -> HashMap<K, usize, S> {
+  let mut result = HashMap::with_capacity_and_hasher(iterator.size_hint().0, hash_builder);
+  for item in iterator {
+    *result.entry(to_key(&item)).or_default() += 1;
+  }
+  result
+}
+
+pub(crate) fn joined<Item: Display>(mut iterator: impl Iterator<Item = Item>, separator: &str) -> String {
+  if let Some(item) = iterator.next() {
+    let mut result = String::with_capacity((separator.len() + 1) * iterator.size_hint().0);
+    let _unused = write!(&mut result, "{item}");
+    for item in iterator {
+      result.push_str(separator);
+      let _unused = write!(&mut result, "{item}");
+    }
+    result
+  } else {
+    String::new()
+  }
+}
+
+/// Finds a longest strictly increasing subsequence using patience sorting with predecessor links.
+pub(crate) fn longest_increasing_subsequence<K: Ord>(keys: &[K]) -> Vec<usize> {
+  // Index of the smallest last element of an increasing subsequence of each length
+  let mut tails = Vec::<usize>::new();
+  let mut predecessors = vec![None; keys.len()];
+  for (index, key) in keys.iter().enumerate() {
+    let length = tails.partition_point(|&tail| keys[tail] < *key);
+    predecessors[index] = length.checked_sub(1).map(|previous| tails[previous]);
+    if length == tails.len() {
+      tails.push(index);
+    } else {
+      tails[length] = index;
+    }
+  }
+  let mut result = Vec::with_capacity(tails.len());
+  let mut current = tails.last().copied();
+  while let Some(index) = current {
+    result.push(index);
+    current = predecessors[index];
+  }
+  result.reverse();
+  result
+}
+
 pub(crate) fn position_sequence<'a, Item: PartialEq + 'a>(
-  mut iterator: impl Iterator<Item = &'a Item>, elements: &'a impl Iterable<Item<'a> = &'a Item>,
+  iterator: impl Iterator<Item = &'a Item>, elements: &'a impl Iterable<Item<'a> = &'a Item>,
 ) -> Option<usize> {
-  let mut elements_iterator = elements.iterator();
-  if let Some(first_element) = elements_iterator.next() {
-    if let Some(start_index) = iterator.position(|item| item == first_element) {
-      for (item, element) in iterator.zip(elements_iterator) {
-        if item != element {
-          return None;
-        }
-      }
-      Some(start_index)
+  sequence_positions(iterator, elements.iterator()).next()
+}
+
+pub(crate) fn rposition_sequence<'a, Item: PartialEq + 'a>(
+  reversed_iterator: impl Iterator<Item = &'a Item>, size: usize, elements: &'a impl Iterable<Item<'a> = &'a Item>,
+) -> Option<usize> {
+  let mut reversed_elements = elements.iterator().collect::<Vec<_>>();
+  reversed_elements.reverse();
+  let elements_size = reversed_elements.len();
+  sequence_positions(reversed_iterator, reversed_elements.into_iter())
+    .next()
+    .map(|reversed_index| size - reversed_index - elements_size)
+}
+
+/// Finds the start indices of all, possibly overlapping, occurrences of a subsequence
+/// using the Knuth-Morris-Pratt algorithm.
+/// An empty pattern matches at every index from `0` to the number of elements inclusive.
+pub(crate) fn sequence_positions<'a, Item: PartialEq + 'a>(
+  iterator: impl Iterator<Item = &'a Item>, elements: impl Iterator<Item = &'a Item>,
+) -> impl Iterator<Item = usize> {
+  let pattern = elements.collect::<Vec<_>>();
+  let borders = border_lengths(&pattern);
+  let mut matched = 0_usize;
+  let empty_match = if pattern.is_empty() { Some(0) } else { None };
+  empty_match.into_iter().chain(iterator.enumerate().filter_map(move |(index, item)| {
+    if pattern.is_empty() {
+      return Some(index + 1);
+    }
+    while matched > 0 && pattern[matched] != item {
+      matched = borders[matched - 1];
+    }
+    if pattern[matched] == item {
+      matched += 1;
+    }
+    if matched == pattern.len() {
+      matched = borders[matched - 1];
+      Some(index + 1 - pattern.len())
     } else {
       None
     }
-  } else {
-    Some(0)
+  }))
+}
+
+/// Computes the lengths of the longest proper prefixes of each pattern prefix which are also its suffixes.
+fn border_lengths<Item: PartialEq>(pattern: &[&Item]) -> Vec<usize> {
+  let mut result = vec![0; pattern.len()];
+  let mut border = 0_usize;
+  for index in 1..pattern.len() {
+    while border > 0 && pattern[index] != pattern[border] {
+      border = result[border - 1];
+    }
+    if pattern[index] == pattern[border] {
+      border += 1;
+    }
+    result[index] = border;
   }
+  result
 }
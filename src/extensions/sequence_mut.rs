@@ -0,0 +1,321 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Mutable ordered collection operations.
+///
+/// Methods have the following properties:
+///
+/// - Requires the collection to represent an ordered collection
+/// - Modifies the collection in place
+/// - Shares semantics and panic conditions with the equivalent consuming operations
+pub trait SequenceMut<Item> {
+  /// Inserts an element at the specified index in this sequence.
+  ///
+  /// This is an in-place variant of [`add_at()`].
+  ///
+  /// [`add_at()`]: crate::SequenceTo::add_at
+  ///
+  /// # Panics
+  ///
+  /// Panics if `index > len`.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let mut a = vec![1, 2, 3];
+  ///
+  /// a.add_at_mut(1, 4);
+  /// assert_eq!(a, vec![1, 4, 2, 3]);
+  /// ```
+  fn add_at_mut(&mut self, index: usize, element: Item);
+
+  /// Inserts all elements of another collection at the specified index in this sequence.
+  ///
+  /// This is an in-place variant of [`add_at_multi()`].
+  ///
+  /// [`add_at_multi()`]: crate::SequenceTo::add_at_multi
+  ///
+  /// # Panics
+  ///
+  /// Panics if `index > len`.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let mut a = vec![1, 2, 3];
+  ///
+  /// a.add_at_multi_mut(1, vec![4, 5]);
+  /// assert_eq!(a, vec![1, 4, 5, 2, 3]);
+  /// ```
+  fn add_at_multi_mut(&mut self, index: usize, elements: impl IntoIterator<Item = Item>);
+
+  /// Removes the element at the specified index from this sequence.
+  ///
+  /// This is an in-place variant of [`delete_at()`].
+  ///
+  /// [`delete_at()`]: crate::SequenceTo::delete_at
+  ///
+  /// # Panics
+  ///
+  /// Panics if `index` is out of bounds.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let mut a = vec![1, 2, 3];
+  ///
+  /// a.delete_at_mut(1);
+  /// assert_eq!(a, vec![1, 3]);
+  /// ```
+  fn delete_at_mut(&mut self, index: usize);
+
+  /// Removes the elements at the specified indices from this sequence.
+  ///
+  /// This is an in-place variant of [`delete_at_multi()`].
+  ///
+  /// [`delete_at_multi()`]: crate::SequenceTo::delete_at_multi
+  ///
+  /// # Panics
+  ///
+  /// Panics if any of the `indices` is out of bounds.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let mut a = vec![1, 2, 3];
+  ///
+  /// a.delete_at_multi_mut(vec![0, 2]);
+  /// assert_eq!(a, vec![2]);
+  /// ```
+  fn delete_at_multi_mut(&mut self, indices: impl IntoIterator<Item = usize>);
+
+  /// Removes the first occurrence of an element from this sequence.
+  ///
+  /// This is an in-place variant of [`delete()`].
+  ///
+  /// [`delete()`]: crate::CollectionTo::delete
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let mut a = vec![1, 2, 2, 3];
+  ///
+  /// a.delete_mut(&2);
+  /// assert_eq!(a, vec![1, 2, 3]);
+  /// ```
+  fn delete_mut(&mut self, element: &Item)
+  where
+    Item: PartialEq;
+
+  /// Moves an element at an index into the specified index in this sequence.
+  ///
+  /// This is an in-place variant of [`move_at()`].
+  ///
+  /// [`move_at()`]: crate::SequenceTo::move_at
+  ///
+  /// # Panics
+  ///
+  /// Panics if `source_index` or `target_index` are out of bounds.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let mut a = vec![1, 2, 3];
+  ///
+  /// a.move_at_mut(0, 2);
+  /// assert_eq!(a, vec![2, 3, 1]);
+  /// ```
+  fn move_at_mut(&mut self, source_index: usize, target_index: usize);
+
+  /// Pads this sequence to a minimum length of `size` by filling
+  /// missing elements with the specified value.
+  ///
+  /// This is an in-place variant of [`pad_right()`].
+  ///
+  /// [`pad_right()`]: crate::SequenceTo::pad_right
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let mut a = vec![1, 2, 3];
+  ///
+  /// a.pad_right_mut(5, 4);
+  /// assert_eq!(a, vec![1, 2, 3, 4, 4]);
+  /// ```
+  fn pad_right_mut(&mut self, size: usize, element: Item)
+  where
+    Item: Clone;
+
+  /// Sorts this sequence.
+  ///
+  /// This sort is stable (i.e., does not reorder equal elements) and *O*(*n* \* log(*n*)) worst-case.
+  /// This is an in-place variant of [`sorted()`].
+  ///
+  /// [`sorted()`]: crate::SequenceTo::sorted
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let mut a = vec![2, 3, 1];
+  ///
+  /// a.sorted_mut();
+  /// assert_eq!(a, vec![1, 2, 3]);
+  /// ```
+  fn sorted_mut(&mut self)
+  where
+    Item: Ord;
+
+  /// Sorts this sequence with a comparator function.
+  ///
+  /// This sort is stable (i.e., does not reorder equal elements) and *O*(*n* \* log(*n*)) worst-case.
+  /// This is an in-place variant of [`sorted_by()`].
+  ///
+  /// [`sorted_by()`]: crate::SequenceTo::sorted_by
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let mut a = vec![2, 3, 1];
+  ///
+  /// a.sorted_by_mut(|x, y| y.cmp(x));
+  /// assert_eq!(a, vec![3, 2, 1]);
+  /// ```
+  fn sorted_by_mut(&mut self, compare: impl FnMut(&Item, &Item) -> Ordering);
+
+  /// Sorts this sequence with a key extraction function.
+  ///
+  /// This sort is stable (i.e., does not reorder equal elements) and *O*(*m* \* *n* \* log(*n*))
+  /// worst-case, where the key function is *O*(*m*).
+  /// This is an in-place variant of [`sorted_by_key()`].
+  ///
+  /// [`sorted_by_key()`]: crate::SequenceTo::sorted_by_key
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let mut a = vec![2, 3, 1];
+  ///
+  /// a.sorted_by_key_mut(|&k| -k);
+  /// assert_eq!(a, vec![3, 2, 1]);
+  /// ```
+  fn sorted_by_key_mut<K: Ord>(&mut self, to_key: impl FnMut(&Item) -> K);
+
+  /// Replaces the first occurrence of an element in this sequence with another value.
+  ///
+  /// This is an in-place variant of [`substitute()`].
+  ///
+  /// [`substitute()`]: crate::CollectionTo::substitute
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let mut a = vec![1, 2, 2, 3];
+  ///
+  /// a.substitute_mut(&2, 4);
+  /// assert_eq!(a, vec![1, 4, 2, 3]);
+  /// ```
+  fn substitute_mut(&mut self, element: &Item, replacement: Item)
+  where
+    Item: PartialEq;
+
+  /// Replaces an element at the specified index in this sequence.
+  ///
+  /// This is an in-place variant of [`substitute_at()`].
+  ///
+  /// [`substitute_at()`]: crate::SequenceTo::substitute_at
+  ///
+  /// # Panics
+  ///
+  /// Panics if `index` is out of bounds.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let mut a = vec![1, 2, 3];
+  ///
+  /// a.substitute_at_mut(1, 4);
+  /// assert_eq!(a, vec![1, 4, 3]);
+  /// ```
+  fn substitute_at_mut(&mut self, index: usize, replacement: Item);
+
+  /// Swaps elements at specified indices in this sequence.
+  ///
+  /// This is an in-place variant of [`swap_at()`].
+  ///
+  /// [`swap_at()`]: crate::SequenceTo::swap_at
+  ///
+  /// # Panics
+  ///
+  /// Panics if `source_index` or `target_index` are out of bounds.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let mut a = vec![1, 2, 3];
+  ///
+  /// a.swap_at_mut(0, 2);
+  /// assert_eq!(a, vec![3, 2, 1]);
+  /// ```
+  fn swap_at_mut(&mut self, source_index: usize, target_index: usize);
+
+  /// Removes all but the first occurrence of each element from this sequence.
+  ///
+  /// This is an in-place variant of [`unique()`].
+  ///
+  /// [`unique()`]: crate::SequenceTo::unique
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let mut a = vec![1, 2, 2, 3];
+  ///
+  /// a.unique_mut();
+  /// assert_eq!(a, vec![1, 2, 3]);
+  /// ```
+  fn unique_mut(&mut self)
+  where
+    Item: Eq + Hash;
+}
+
+pub(crate) fn unique_flags<'a, Item: Eq + Hash + 'a>(iterator: impl Iterator<Item = &'a Item>) -> Vec<bool> {
+  let mut occurred = HashSet::with_capacity(iterator.size_hint().0);
+  iterator.map(|item| occurred.insert(item)).collect()
+}
+
+pub(crate) fn retained_flags(indices: impl IntoIterator<Item = usize>, size: usize) -> Vec<bool> {
+  let mut result = vec![true; size];
+  for index in indices {
+    assert!(index < size, "removal index (is {index:?}) should be < len (is {size:?})");
+    result[index] = false;
+  }
+  result
+}
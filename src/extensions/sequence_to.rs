@@ -3,7 +3,16 @@ use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
 use std::hash::Hash;
 use std::iter;
 
-use crate::core::unfold::unfold;
+use crate::DiffHunk;
+use crate::Float;
+use crate::Iterable;
+use crate::NanPolicy;
+use crate::Parallelism;
+use crate::core::diff::apply_diff;
+use crate::core::ordering::float_order;
+use crate::core::parallel::{merge_sorted_chunks, parallel_chunks};
+use crate::core::unfold::{unfold, unfold_sized};
+use crate::extensions::{binomial, sequence_positions};
 
 pub(crate) const MAX_SIZE: usize = usize::MAX / 2 - 1;
 
@@ -75,6 +84,44 @@ where
   #[must_use]
   fn add_at_multi(self, index: usize, elements: impl IntoIterator<Item = Item>) -> Self;
 
+  /// Creates a new sequence by applying an edit script to this sequence.
+  ///
+  /// The edit script is usually computed using [`diff()`] from this sequence and a target sequence,
+  /// in which case the target sequence is returned. Elements not covered by any hunk of the edit script
+  /// are retained unchanged, so [`DiffHunk::Equal`] hunks may be omitted.
+  ///
+  /// [`diff()`]: crate::Sequence::diff
+  ///
+  /// # Panics
+  ///
+  /// Panics if the hunks of the edit script are not ordered by their source index,
+  /// overlap or refer to elements beyond the end of this sequence.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// # let a_source = vec![1, 2, 3, 4];
+  /// let a = vec![1, 2, 3, 4];
+  /// let script = a.diff(&vec![0, 2, 4, 5]);
+  ///
+  /// assert_eq!(a.apply_diff(&script), vec![0, 2, 4, 5]);
+  /// # let a = a_source.clone();
+  /// assert_eq!(
+  ///   a.apply_diff(&vec![DiffHunk::Insert { source_index: 2, target_index: 2, elements: vec![7, 8] }]),
+  ///   vec![1, 2, 7, 8, 3, 4]
+  /// );
+  /// ```
+  #[must_use]
+  fn apply_diff<'a>(self, script: &'a impl Iterable<Item<'a> = &'a DiffHunk<Item>>) -> Self
+  where
+    Item: Clone + 'a,
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+  {
+    apply_diff(self.into_iter(), script.iterator()).collect()
+  }
+
   /// Creates a new sequence containing tuples for k-fold cartesian product of specified size
   /// from the elements of this sequence.
   ///
@@ -84,6 +131,9 @@ where
   ///
   /// The order or tuple values is preserved.
   ///
+  /// Since elements can repeat within a tuple, `k` may exceed the number of elements
+  /// in which case all `len^k` tuples are still generated.
+  ///
   /// [`unique()`]: SequenceTo::unique
   ///
   /// # Example
@@ -107,28 +157,64 @@ where
   ///   vec![3, 2],
   ///   vec![3, 3],
   /// ]);
+  /// assert_eq!(a.cartesian_product(4).len(), 81);
   ///
+  /// assert_eq!(e.cartesian_product(0), vec![vec![]]);
   /// assert_eq!(e.cartesian_product(2), Vec::<Vec<i32>>::new());
   /// ```
-  #[allow(clippy::cast_possible_wrap)]
   #[must_use]
   fn cartesian_product(&self, k: usize) -> Vec<Self>
   where
     Self: FromIterator<Item> + Sized,
     Item: Clone,
+  {
+    self.cartesian_product_iter(k).collect()
+  }
+
+  /// Creates an iterator lazily generating all tuples of specified size from the elements of this sequence.
+  ///
+  /// This is a lazy variant of [`cartesian_product()`] which yields each tuple on demand.
+  /// The size hint reports the exact number of remaining tuples unless it exceeds `usize::MAX`.
+  ///
+  /// [`cartesian_product()`]: SequenceTo::cartesian_product
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3];
+  /// let e = Vec::<i32>::new();
+  ///
+  /// let mut product = a.cartesian_product_iter(2);
+  /// assert_eq!(product.size_hint(), (9, Some(9)));
+  /// assert_eq!(product.next(), Some(vec![1, 1]));
+  /// assert_eq!(product.next(), Some(vec![1, 2]));
+  /// assert_eq!(product.size_hint(), (7, Some(7)));
+  ///
+  /// assert_eq!(a.cartesian_product_iter(0).collect::<Vec<_>>(), vec![vec![]]);
+  /// assert_eq!(e.cartesian_product_iter(2).size_hint(), (0, Some(0)));
+  /// ```
+  #[allow(clippy::cast_possible_wrap)]
+  fn cartesian_product_iter<'a>(&'a self, k: usize) -> impl Iterator<Item = Self>
+  where
+    Self: FromIterator<Item> + Sized,
+    Item: Clone,
+    Item: 'a,
   {
     assert!(k <= MAX_SIZE, "k (is {k:?}) should be <= {MAX_SIZE:?})");
     let values = self.into_iter().collect::<Vec<_>>();
     let size = values.len();
+    let count = u32::try_from(k).ok().and_then(|exponent| size.checked_pow(exponent));
     let mut product = iter::once(i64::MIN).chain(iter::repeat_n(0, k)).collect::<Vec<_>>();
-    let mut current_slot = (size + 1).saturating_sub(k);
-    unfold(|| {
+    let mut current_slot = usize::from(size > 0 || k == 0);
+    unfold_sized(count, move || {
       if current_slot == 0 {
         return None;
       }
       current_slot = k;
       let tuple = Some(collect_by_index(&values, &product[1..]));
-      while product[current_slot] >= (size - 1) as i64 {
+      while product[current_slot] >= size as i64 - 1 {
         current_slot -= 1;
       }
       product[current_slot] += 1;
@@ -137,7 +223,6 @@ where
       }
       tuple
     })
-    .collect()
   }
 
   /// Creates a new sequence by splitting elements of this sequence
@@ -175,7 +260,7 @@ where
   where
     Self: IntoIterator<Item = Item> + FromIterator<Item>,
   {
-    chunked(self, size, false)
+    chunked(self.into_iter(), size, false).collect()
   }
 
   /// Creates a new sequence by splitting this sequence into non-overlapping
@@ -272,7 +357,7 @@ where
   where
     Self: IntoIterator<Item = Item> + FromIterator<Item>,
   {
-    chunked(self, size, true)
+    chunked(self.into_iter(), size, true).collect()
   }
 
   /// Creates a new sequence by using the compression closure to
@@ -296,32 +381,11 @@ where
   /// assert_eq!(coalesced, vec![4, 1, 4, 3]);
   /// ```
   #[must_use]
-  fn coalesce(self, mut function: impl FnMut(Item, Item) -> Result<Item, (Item, Item)>) -> Self
+  fn coalesce(self, function: impl FnMut(Item, Item) -> Result<Item, (Item, Item)>) -> Self
   where
     Self: IntoIterator<Item = Item> + FromIterator<Item>,
   {
-    let mut iterator = self.into_iter();
-    let mut last = iterator.next();
-    unfold(|| {
-      loop {
-        if let Some(previous) = last.take() {
-          if let Some(current) = iterator.next() {
-            match function(previous, current) {
-              Ok(merged) => last = Some(merged),
-              Err((new_previous, new_current)) => {
-                last = Some(new_current);
-                return Some(new_previous);
-              }
-            }
-          } else {
-            return Some(previous);
-          }
-        } else {
-          return None;
-        }
-      }
-    })
-    .collect()
+    coalesce(self.into_iter(), function).collect()
   }
 
   /// Creates a new sequence containing combinations with repetition of specified size
@@ -333,6 +397,9 @@ where
   ///
   /// The order or combination values is preserved.
   ///
+  /// Since elements can repeat within a combination, `k` may exceed the number of elements
+  /// in which case all such combinations are still generated.
+  ///
   /// [`unique()`]: SequenceTo::unique
   ///
   /// # Example
@@ -365,28 +432,64 @@ where
   ///   vec![2, 3, 3],
   ///   vec![3, 3, 3],
   /// ]);
+  /// assert_eq!(a.combinations_multi(4).len(), 15);
   ///
+  /// assert_eq!(e.combinations_multi(0), vec![vec![]]);
   /// assert_eq!(e.combinations_multi(1), Vec::<Vec<i32>>::new());
   /// ```
-  #[allow(clippy::cast_possible_wrap)]
   #[must_use]
   fn combinations_multi(&self, k: usize) -> Vec<Self>
   where
     Self: FromIterator<Item> + Sized,
     Item: Clone,
+  {
+    self.combinations_multi_iter(k).collect()
+  }
+
+  /// Creates an iterator lazily generating combinations with repetition of specified size from the elements of this sequence.
+  ///
+  /// This is a lazy variant of [`combinations_multi()`] which yields each combination on demand.
+  /// The size hint reports the exact number of remaining combinations unless it exceeds `usize::MAX`.
+  ///
+  /// [`combinations_multi()`]: SequenceTo::combinations_multi
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3];
+  /// let e = Vec::<i32>::new();
+  ///
+  /// let mut combinations = a.combinations_multi_iter(2);
+  /// assert_eq!(combinations.size_hint(), (6, Some(6)));
+  /// assert_eq!(combinations.next(), Some(vec![1, 1]));
+  /// assert_eq!(combinations.next(), Some(vec![1, 2]));
+  /// assert_eq!(combinations.size_hint(), (4, Some(4)));
+  ///
+  /// assert_eq!(a.combinations_multi_iter(0).collect::<Vec<_>>(), vec![vec![]]);
+  /// assert_eq!(e.combinations_multi_iter(1).size_hint(), (0, Some(0)));
+  /// ```
+  #[allow(clippy::cast_possible_wrap)]
+  fn combinations_multi_iter<'a>(&'a self, k: usize) -> impl Iterator<Item = Self>
+  where
+    Self: FromIterator<Item> + Sized,
+    Item: Clone,
+    Item: 'a,
   {
     assert!(k <= MAX_SIZE, "k (is {k:?}) should be <= {MAX_SIZE:?})");
     let values = self.into_iter().collect::<Vec<_>>();
     let size = values.len();
+    let count = if size == 0 { Some(usize::from(k == 0)) } else { binomial(size + k - 1, k) };
     let mut multi_combination = iter::once(i64::MIN).chain(iter::repeat_n(0, k)).collect::<Vec<_>>();
-    let mut current_slot = (size + 1).saturating_sub(k);
-    unfold(|| {
+    let mut current_slot = usize::from(size > 0 || k == 0);
+    unfold_sized(count, move || {
       if current_slot == 0 {
         return None;
       }
       current_slot = k;
       let tuple = Some(collect_by_index(&values, &multi_combination[1..]));
-      while multi_combination[current_slot] >= (size - 1) as i64 {
+      while multi_combination[current_slot] >= size as i64 - 1 {
         current_slot -= 1;
       }
       let new_index = multi_combination[current_slot] + 1;
@@ -395,7 +498,6 @@ where
       }
       tuple
     })
-    .collect()
   }
 
   /// Creates a new sequence by omitting an element at the specified index
@@ -543,30 +645,51 @@ where
   /// ```
   #[inline]
   #[must_use]
-  fn divide_by(self, mut separator: impl FnMut(&Item) -> bool) -> Vec<Self>
+  fn divide_by(self, separator: impl FnMut(&Item) -> bool) -> Vec<Self>
   where
     Self: IntoIterator<Item = Item> + FromIterator<Item>,
   {
-    let mut iterator = self.into_iter();
-    let mut empty = false;
-    unfold(|| {
-      if empty {
-        return None;
-      }
-      let chunk = unfold(|| {
-        if let Some(item) = iterator.next() {
-          if !separator(&item) {
-            return Some(item);
-          }
-        } else {
-          empty = true;
-        }
-        None
-      })
-      .collect();
-      Some(chunk)
-    })
-    .collect()
+    divide_by(self.into_iter(), separator).collect()
+  }
+
+  /// Creates a new sequence by splitting this sequence into subsequences separated
+  /// by occurrences of the `delimiter` sequence.
+  /// Matched elements are not contained in the subsequences.
+  ///
+  /// Occurrences are matched from left to right without overlapping.
+  /// If the first elements are matched, an empty sequence will be the first
+  /// element of the result. Similarly, if the last elements are matched,
+  /// an empty sequence will be the last element of the result.
+  ///
+  /// An empty `delimiter` matches before each element and after the last element,
+  /// so each element is placed into a separate subsequence between two empty ones.
+  ///
+  /// The search uses the Knuth-Morris-Pratt algorithm and takes *O*(*n* + *m*) time.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// # let a_source = vec![1, 2, 3, 1, 2, 4];
+  /// let a = vec![1, 2, 3, 1, 2, 4];
+  ///
+  /// assert_eq!(a.divide_sequence(&vec![1, 2]), vec![vec![], vec![3], vec![4]]);
+  /// # let a = a_source.clone();
+  /// assert_eq!(a.divide_sequence(&vec![2, 3]), vec![vec![1], vec![1, 2, 4]]);
+  /// # let a = a_source.clone();
+  /// assert_eq!(a.divide_sequence(&vec![0]), vec![vec![1, 2, 3, 1, 2, 4]]);
+  ///
+  /// assert_eq!(vec![1, 2].divide_sequence(&vec![]), vec![vec![], vec![1], vec![2], vec![]]);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn divide_sequence<'a>(self, delimiter: &'a impl Iterable<Item<'a> = &'a Item>) -> Vec<Self>
+  where
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+    Item: PartialEq + 'a,
+  {
+    divide_sequence(self.into_iter(), delimiter)
   }
 
   /// Creates a new collection by including only the elements of this collection
@@ -589,21 +712,7 @@ where
     Self: IntoIterator<Item = Item> + FromIterator<Item>,
     Item: Eq + Hash,
   {
-    let iterator = self.into_iter();
-    let mut occurred = HashSet::with_capacity(iterator.size_hint().0);
-    let mut duplicated = HashSet::with_capacity(iterator.size_hint().0);
-    iterator
-      .filter_map(|item| {
-        if !duplicated.contains(&item) {
-          if let Some(result) = occurred.take(&item) {
-            let _ = duplicated.insert(item);
-            return Some(result);
-          }
-          let _ = occurred.insert(item);
-        }
-        None
-      })
-      .collect()
+    duplicates(self.into_iter()).collect()
   }
 
   /// Creates a new collection by including only the elements of this collection
@@ -649,6 +758,89 @@ where
     .collect()
   }
 
+  /// Creates a new collection by including only the elements of this collection
+  /// that appear more than once.
+  ///
+  /// Duplicates are detected using equality, and each duplicate is included exactly once.
+  ///
+  /// The order or duplicate values is preserved.
+  ///
+  /// This is a variant of [`duplicates()`] for elements which can only be compared for equality.
+  /// It takes *O*(*n*<sup>2</sup>) time.
+  ///
+  /// [`duplicates()`]: SequenceTo::duplicates
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1.0, 2.0, 2.0, 3.0];
+  ///
+  /// assert_eq!(a.duplicates_eq(), vec![2.0]);
+  /// ```
+  #[must_use]
+  fn duplicates_eq(self) -> Self
+  where
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+    Item: PartialEq,
+  {
+    let mut occurred = Vec::<Item>::new();
+    let mut duplicated = Vec::<Item>::new();
+    self
+      .into_iter()
+      .filter_map(|item| {
+        if !duplicated.contains(&item) {
+          if let Some(index) = occurred.iter().position(|x| x == &item) {
+            duplicated.push(item);
+            return Some(occurred.swap_remove(index));
+          }
+          occurred.push(item);
+        }
+        None
+      })
+      .collect()
+  }
+
+  /// Creates a new collection by including only the elements of this collection
+  /// that appear more than once.
+  ///
+  /// Duplicates are detected using ordering, and each duplicate is included exactly once.
+  ///
+  /// The order or duplicate values is preserved.
+  ///
+  /// This is a variant of [`duplicates()`] for elements which are ordered but not hashable.
+  ///
+  /// [`duplicates()`]: SequenceTo::duplicates
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 2, 3];
+  ///
+  /// assert_eq!(a.duplicates_ord(), vec![2]);
+  /// ```
+  #[must_use]
+  fn duplicates_ord(self) -> Self
+  where
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+    Item: Ord,
+  {
+    let mut occurred = BTreeSet::new();
+    let mut duplicated = BTreeSet::new();
+    self
+      .into_iter()
+      .filter_map(|item| {
+        if !duplicated.contains(&item) {
+          if let Some(result) = occurred.take(&item) {
+            let _unused = duplicated.insert(item);
+            return Some(result);
+          }
+          let _unused = occurred.insert(item);
+        }
+        None
+      })
+      .collect()
+  }
+
   /// Creates a new sequence which contains elements of this sequence
   /// and their indices.
   ///
@@ -754,19 +946,7 @@ where
   where
     Self: IntoIterator<Item = Item> + FromIterator<Item>,
   {
-    let mut iterator_left = self.into_iter();
-    let mut iterator_right = elements.into_iter();
-    let mut left = true;
-    unfold(|| {
-      let new_item = if left {
-        iterator_left.next().or_else(|| iterator_right.next())
-      } else {
-        iterator_right.next().or_else(|| iterator_left.next())
-      };
-      left = !left;
-      new_item
-    })
-    .collect()
+    interleave(self.into_iter(), elements).collect()
   }
 
   /// Create a new sequence by interleaving the elements of this sequence with
@@ -861,30 +1041,12 @@ where
   /// assert_eq!(a.intersperse_with(3, || 0), vec![1, 2, 3]);
   /// ```
   #[must_use]
-  fn intersperse_with(self, interval: usize, mut to_value: impl FnMut() -> Item) -> Self
+  fn intersperse_with(self, interval: usize, to_value: impl FnMut() -> Item) -> Self
   where
     Self: IntoIterator<Item = Item> + FromIterator<Item>,
     Item: Clone,
   {
-    assert_ne!(interval, 0, "interval must be non-zero");
-    let mut iterator = self.into_iter();
-    let mut index = 0_usize;
-    let mut stored: Option<Item> = None;
-    unfold(|| {
-      stored.take().or_else(|| {
-        iterator.next().map(|item| {
-          let new_item = if index != 0 && index.is_multiple_of(interval) {
-            stored = Some(item);
-            to_value()
-          } else {
-            item
-          };
-          index += 1;
-          new_item
-        })
-      })
-    })
-    .collect()
+    intersperse_with(self.into_iter(), interval, to_value).collect()
   }
 
   /// Creates a new sequence without trailing elements based on a predicate
@@ -932,6 +1094,53 @@ where
     self.into_iter().map_while(predicate).collect()
   }
 
+  /// Creates a new sequence by applying the given fallible closure `function`
+  /// to each element in this sequence, collecting all errors together with
+  /// the positions of the failed elements.
+  ///
+  /// Unlike [`try_map()`], the closure is applied to every element even after
+  /// an error occurs, so that all errors can be reported at once.
+  ///
+  /// [`try_map()`]: crate::CollectionTo::try_map
+  ///
+  /// # Errors
+  ///
+  /// Returns a vector of all errors produced by the closure paired with the
+  /// indices of the corresponding elements if there are any.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec!["1", "2", "3"];
+  /// let b = vec!["1", "x", "3", "y"];
+  ///
+  /// assert_eq!(a.map_validated_indexed(|x| x.parse::<i32>().map_err(|_| x)), Ok(vec![1, 2, 3]));
+  /// assert_eq!(b.map_validated_indexed(|x| x.parse::<i32>().map_err(|_| x)), Err(vec![(1, "x"), (3, "y")]));
+  /// ```
+  fn map_validated_indexed<B, E>(
+    self, mut function: impl FnMut(Item) -> Result<B, E>,
+  ) -> Result<Self::This<B>, Vec<(usize, E)>>
+  where
+    Self: IntoIterator<Item = Item> + Sized,
+    Self::This<B>: Default + Extend<B>,
+  {
+    let mut result: Self::This<B> = Self::This::default();
+    let mut errors = Vec::new();
+    for (index, item) in self.into_iter().enumerate() {
+      match function(item) {
+        Ok(value) => {
+          if errors.is_empty() {
+            result.extend(iter::once(value));
+          }
+        }
+        Err(error) => errors.push((index, error)),
+      }
+    }
+    if errors.is_empty() { Ok(result) } else { Err(errors) }
+  }
+
   /// Create a new sequence by merging it with another sequence in ascending order.
   ///
   /// ```
@@ -1076,54 +1285,218 @@ where
     .collect()
   }
 
-  /// Creates a new sequence by padding this sequence to a minimum length of
-  /// `size` and filling missing elements with specified value.
+  /// Creates a new sequence by padding this sequence to a minimum length of
+  /// `size` and filling missing elements with specified value.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3];
+  ///
+  /// assert_eq!(a.pad_right(5, 4), vec![1, 2, 3, 4, 4]);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn pad_right(self, size: usize, element: Item) -> Self
+  where
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+    Item: Clone,
+  {
+    self.pad_right_with(size, |_| element.clone())
+  }
+
+  /// Creates a new sequence by padding this sequence to a minimum length of
+  /// `size` and filling missing elements using a closure `to_element`.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3];
+  ///
+  /// assert_eq!(a.pad_right_with(5, |x| 2 * x), vec![1, 2, 3, 6, 8]);
+  /// ```
+  #[must_use]
+  fn pad_right_with(self, size: usize, to_element: impl FnMut(usize) -> Item) -> Self
+  where
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+    Item: Clone,
+  {
+    pad_right_with(self.into_iter(), size, to_element).collect()
+  }
+
+  /// Creates a new sequence by sorting this sequence on multiple threads.
+  ///
+  /// Produces the same result as [`sorted()`]. The elements are divided into
+  /// contiguous chunks sorted in parallel according to the specified
+  /// [`Parallelism`] settings and then merged.
+  ///
+  /// This sort is stable (i.e., does not reorder equal elements).
+  ///
+  /// [`sorted()`]: SequenceTo::sorted
+  /// [`Parallelism`]: crate::Parallelism
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![2, 3, 1];
+  /// let parallelism = Parallelism { threads: 2, threshold: 0 };
+  ///
+  /// assert_eq!(a.par_sorted(parallelism), vec![1, 2, 3]);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn par_sorted(self, parallelism: Parallelism) -> Self
+  where
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+    Item: Ord + Send,
+  {
+    self.par_sorted_by(parallelism, Ord::cmp)
+  }
+
+  /// Creates a new sequence by sorting this sequence with a comparator function
+  /// on multiple threads.
+  ///
+  /// Produces the same result as [`sorted_by()`]. The elements are divided into
+  /// contiguous chunks sorted in parallel according to the specified
+  /// [`Parallelism`] settings and then merged.
+  ///
+  /// This sort is stable (i.e., does not reorder equal elements).
+  ///
+  /// [`sorted_by()`]: SequenceTo::sorted_by
+  /// [`Parallelism`]: crate::Parallelism
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![2, 3, 1];
+  /// let parallelism = Parallelism { threads: 2, threshold: 0 };
+  ///
+  /// assert_eq!(a.par_sorted_by(parallelism, |x, y| y.cmp(x)), vec![3, 2, 1]);
+  /// ```
+  #[must_use]
+  fn par_sorted_by(self, parallelism: Parallelism, compare: impl Fn(&Item, &Item) -> Ordering + Sync) -> Self
+  where
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+    Item: Send,
+  {
+    let values = self.into_iter().collect::<Vec<_>>();
+    let chunks = parallel_chunks(values, parallelism, |mut chunk| {
+      chunk.sort_by(&compare);
+      chunk
+    });
+    merge_sorted_chunks(chunks, compare).into_iter().collect()
+  }
+
+  /// Creates a new sequence by sorting this sequence with a key extraction function
+  /// on multiple threads.
+  ///
+  /// Produces the same result as [`sorted_by_key()`]. The elements are divided into
+  /// contiguous chunks sorted in parallel according to the specified
+  /// [`Parallelism`] settings and then merged.
+  ///
+  /// This sort is stable (i.e., does not reorder equal elements).
+  ///
+  /// [`sorted_by_key()`]: SequenceTo::sorted_by_key
+  /// [`Parallelism`]: crate::Parallelism
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![2, 3, 1];
+  /// let parallelism = Parallelism { threads: 2, threshold: 0 };
+  ///
+  /// assert_eq!(a.par_sorted_by_key(parallelism, |&k| -k), vec![3, 2, 1]);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn par_sorted_by_key<K: Ord>(self, parallelism: Parallelism, to_key: impl Fn(&Item) -> K + Sync) -> Self
+  where
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+    Item: Send,
+  {
+    self.par_sorted_by(parallelism, |x, y| to_key(x).cmp(&to_key(y)))
+  }
+
+  /// Creates a new sequence by replacing all occurrences of the `pattern` sequence
+  /// with the `replacement` elements.
+  ///
+  /// Occurrences are matched from left to right without overlapping.
+  /// An empty `pattern` matches before each element and after the last element,
+  /// so the `replacement` elements are inserted at all these positions.
+  ///
+  /// The search uses the Knuth-Morris-Pratt algorithm and takes *O*(*n* + *m*) time.
   ///
   /// # Example
   ///
   /// ```
   /// use cantrip::*;
   ///
-  /// let a = vec![1, 2, 3];
+  /// # let a_source = vec![1, 2, 1, 1, 2];
+  /// let a = vec![1, 2, 1, 1, 2];
   ///
-  /// assert_eq!(a.pad_right(5, 4), vec![1, 2, 3, 4, 4]);
+  /// assert_eq!(a.replace_sequence(&vec![1, 2], vec![0]), vec![0, 1, 0]);
+  /// # let a = a_source.clone();
+  /// assert_eq!(a.replace_sequence(&vec![1, 1], vec![]), vec![1, 2, 2]);
+  /// # let a = a_source.clone();
+  /// assert_eq!(a.replace_sequence(&vec![3], vec![4]), vec![1, 2, 1, 1, 2]);
+  ///
+  /// assert_eq!(vec![1, 2].replace_sequence(&vec![], vec![0]), vec![0, 1, 0, 2, 0]);
   /// ```
   #[inline]
   #[must_use]
-  fn pad_right(self, size: usize, element: Item) -> Self
+  fn replace_sequence<'a>(
+    self, pattern: &'a impl Iterable<Item<'a> = &'a Item>, replacement: impl IntoIterator<Item = Item>,
+  ) -> Self
   where
     Self: IntoIterator<Item = Item> + FromIterator<Item>,
-    Item: Clone,
+    Item: Clone + PartialEq + 'a,
   {
-    self.pad_right_with(size, |_| element.clone())
+    replace_sequence(self.into_iter(), pattern, replacement, usize::MAX)
   }
 
-  /// Creates a new sequence by padding this sequence to a minimum length of
-  /// `size` and filling missing elements using a closure `to_element`.
+  /// Creates a new sequence by replacing the first `n` occurrences of the `pattern` sequence
+  /// with the `replacement` elements.
+  ///
+  /// Occurrences are matched from left to right without overlapping.
+  /// An empty `pattern` matches before each element and after the last element,
+  /// so the `replacement` elements are inserted at the first `n` of these positions.
+  ///
+  /// The search uses the Knuth-Morris-Pratt algorithm and takes *O*(*n* + *m*) time.
   ///
   /// # Example
   ///
   /// ```
   /// use cantrip::*;
   ///
-  /// let a = vec![1, 2, 3];
+  /// # let a_source = vec![1, 2, 1, 1, 2];
+  /// let a = vec![1, 2, 1, 1, 2];
   ///
-  /// assert_eq!(a.pad_right_with(5, |x| 2 * x), vec![1, 2, 3, 6, 8]);
+  /// assert_eq!(a.replace_sequence_n(&vec![1, 2], vec![0], 1), vec![0, 1, 1, 2]);
+  /// # let a = a_source.clone();
+  /// assert_eq!(a.replace_sequence_n(&vec![1, 2], vec![0], 0), vec![1, 2, 1, 1, 2]);
+  ///
+  /// assert_eq!(vec![1, 2].replace_sequence_n(&vec![], vec![0], 2), vec![0, 1, 0, 2]);
   /// ```
+  #[inline]
   #[must_use]
-  fn pad_right_with(self, size: usize, mut to_element: impl FnMut(usize) -> Item) -> Self
+  fn replace_sequence_n<'a>(
+    self, pattern: &'a impl Iterable<Item<'a> = &'a Item>, replacement: impl IntoIterator<Item = Item>, n: usize,
+  ) -> Self
   where
     Self: IntoIterator<Item = Item> + FromIterator<Item>,
-    Item: Clone,
+    Item: Clone + PartialEq + 'a,
   {
-    let mut iterator = self.into_iter();
-    let mut index = 0_usize;
-    unfold(|| {
-      let new_item = iterator.next().or_else(|| if index < size { Some(to_element(index)) } else { None });
-      index += 1;
-      new_item
-    })
-    .collect()
+    replace_sequence(self.into_iter(), pattern, replacement, n)
   }
 
   /// Creates a new sequence by reversing this sequence's direction.
@@ -1315,6 +1688,45 @@ where
     self.into_iter().scan(initial_state, function).collect()
   }
 
+  /// Creates a new sequence by reordering this sequence so that the element at position `n`
+  /// is the one which would be there if this sequence were sorted.
+  ///
+  /// All elements before position `n` are less than or equal to that element and all elements
+  /// after it are greater than or equal to it. The order of elements on either side is unspecified.
+  ///
+  /// This operation uses a selection algorithm with expected *O*(*n*) time
+  /// instead of sorting the whole sequence.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `n` is not smaller than the sequence size.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![5, 1, 4, 2, 3];
+  ///
+  /// let selected = a.select_nth(2);
+  ///
+  /// assert_eq!(selected[2], 3);
+  /// assert!(selected[..2].iter().all(|&x| x < 3));
+  /// assert!(selected[3..].iter().all(|&x| x > 3));
+  /// ```
+  #[must_use]
+  fn select_nth(self, n: usize) -> Self
+  where
+    Item: Ord,
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+  {
+    let mut result = self.into_iter().collect::<Vec<Item>>();
+    let size = result.len();
+    assert!(n < size, "index (is {n:?}) should be < len (is {size:?})");
+    let _unused = result.select_nth_unstable(n);
+    result.into_iter().collect()
+  }
+
   /// Creates a new sequence that skips the first `n` elements from this sequence.
   ///
   /// `skip(n)` skips elements until `n` elements are skipped or the end of this
@@ -1588,6 +2000,84 @@ where
     result.into_iter().collect()
   }
 
+  /// Creates a new sequence by sorting this sequence of floating point numbers.
+  ///
+  /// This is a variant of [`sorted()`] for floating point numbers which do not implement [`Ord`].
+  ///
+  /// NaN values are treated according to the specified [`NanPolicy`]:
+  ///
+  /// - [`NanPolicy::Total`] orders them using the IEEE 754 `totalOrder` predicate which places
+  ///   positive NaN values last and negative NaN values first
+  /// - [`NanPolicy::Skip`] omits them
+  /// - [`NanPolicy::Propagate`] places them first in their original order
+  ///
+  /// This sort is stable (i.e., does not reorder equal elements) and *O*(*n* \* log(*n*)) worst-case.
+  ///
+  /// [`sorted()`]: SequenceTo::sorted
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![2.0, -0.0, 1.0, 0.0];
+  ///
+  /// assert_eq!(a.sorted_floats(NanPolicy::Total), vec![-0.0, 0.0, 1.0, 2.0]);
+  /// assert_eq!(vec![2.0, f64::NAN, 1.0].sorted_floats(NanPolicy::Skip), vec![1.0, 2.0]);
+  /// assert!(vec![2.0, f64::NAN, 1.0].sorted_floats(NanPolicy::Propagate)[0].is_nan());
+  /// ```
+  #[inline]
+  #[must_use]
+  fn sorted_floats(self, nan: NanPolicy) -> Self
+  where
+    Item: Float,
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+  {
+    let mut result = self.into_iter().filter(|x| nan != NanPolicy::Skip || !x.is_nan()).collect::<Vec<Item>>();
+    result.sort_by(float_order(nan, false));
+    result.into_iter().collect()
+  }
+
+  /// Creates a new sequence by inserting an element into this sorted sequence
+  /// while maintaining the sorted order.
+  ///
+  /// The element is inserted after all elements equal to it.
+  /// This sequence must be sorted in ascending order, otherwise the insertion position is unspecified.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// # let a_source = vec![1, 2, 4];
+  /// let a = vec![1, 2, 4];
+  ///
+  /// assert_eq!(a.sorted_insert(3), vec![1, 2, 3, 4]);
+  /// # let a = a_source.clone();
+  /// assert_eq!(a.sorted_insert(5), vec![1, 2, 4, 5]);
+  /// # let a = a_source.clone();
+  /// assert_eq!(a.sorted_insert(0), vec![0, 1, 2, 4]);
+  /// ```
+  #[inline]
+  #[must_use]
+  fn sorted_insert(self, element: Item) -> Self
+  where
+    Item: Ord,
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+  {
+    let mut iterator = self.into_iter().peekable();
+    let mut inserted = Some(element);
+    unfold(|| {
+      if let Some(element) = &inserted
+        && iterator.peek().is_none_or(|x| x > element)
+      {
+        return inserted.take();
+      }
+      iterator.next()
+    })
+    .collect()
+  }
+
   /// Creates a new sequence by sorting this sequence, but might not preserve the order of equal elements.
   ///
   /// This sort is unstable (i.e., may reorder equal elements), in-place
@@ -1975,23 +2465,78 @@ where
   /// assert_eq!(a.unique_by(|x| x % 2), vec![1, 2]);
   /// ```
   #[must_use]
-  fn unique_by<K>(self, mut to_key: impl FnMut(&Item) -> K) -> Self
+  fn unique_by<K>(self, to_key: impl FnMut(&Item) -> K) -> Self
   where
     K: Eq + Hash,
     Self: IntoIterator<Item = Item> + FromIterator<Item>,
   {
-    let iterator = self.into_iter();
-    let mut occurred = HashSet::with_capacity(iterator.size_hint().0);
-    iterator
-      .filter(|item| {
-        let key = to_key(item);
-        if occurred.contains(&key) {
-          false
-        } else {
-          let _unused = occurred.insert(key);
-          true
-        }
-      })
+    unique_by(self.into_iter(), to_key).collect()
+  }
+
+  /// Creates a new sequence by omitting duplicate elements.
+  ///
+  /// Duplicates are detected using equality.
+  ///
+  /// The algorithm is stable, returning the non-duplicate items in the order
+  /// in which they occur in this sequence. In a set of duplicate
+  /// items, the first item encountered is the item retained.
+  ///
+  /// This is a variant of [`unique()`] for elements which can only be compared for equality.
+  /// It takes *O*(*n*<sup>2</sup>) time.
+  ///
+  /// [`unique()`]: SequenceTo::unique
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1.0, 2.0, 2.0, 3.0];
+  ///
+  /// assert_eq!(a.unique_eq(), vec![1.0, 2.0, 3.0]);
+  /// ```
+  #[must_use]
+  fn unique_eq(self) -> Self
+  where
+    Item: PartialEq,
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+  {
+    let mut result = Vec::<Item>::new();
+    for item in self {
+      if !result.contains(&item) {
+        result.push(item);
+      }
+    }
+    result.into_iter().collect()
+  }
+
+  /// Creates a new sequence by omitting duplicate elements.
+  ///
+  /// Duplicates are detected using ordering.
+  ///
+  /// The algorithm is stable, returning the non-duplicate items in the order
+  /// in which they occur in this sequence. In a set of duplicate
+  /// items, the first item encountered is the item retained.
+  ///
+  /// This is a variant of [`unique()`] for elements which are ordered but not hashable.
+  ///
+  /// [`unique()`]: SequenceTo::unique
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 2, 3];
+  ///
+  /// assert_eq!(a.unique_ord(), vec![1, 2, 3]);
+  /// ```
+  #[must_use]
+  fn unique_ord(self) -> Self
+  where
+    Item: Ord + Clone,
+    Self: IntoIterator<Item = Item> + FromIterator<Item>,
+  {
+    let mut occurred = BTreeSet::new();
+    self
+      .into_iter()
+      .filter(|item| if occurred.contains(item) { false } else { occurred.insert(item.clone()) })
       .collect()
   }
 
@@ -2072,12 +2617,47 @@ where
   /// ```
   #[allow(clippy::cast_sign_loss)]
   #[allow(clippy::cast_possible_truncation)]
-  #[allow(clippy::cast_possible_wrap)]
   #[must_use]
   fn variations(&self, k: usize) -> Vec<Self>
   where
     Item: Clone,
     Self: FromIterator<Item> + Sized,
+  {
+    self.variations_iter(k).collect()
+  }
+
+  /// Creates an iterator lazily generating all variations of specified size from the elements of this sequence.
+  ///
+  /// This is a lazy variant of [`variations()`] which yields each variation on demand.
+  /// The size hint reports the exact number of remaining variations unless it exceeds `usize::MAX`.
+  ///
+  /// [`variations()`]: SequenceTo::variations
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = vec![1, 2, 3];
+  /// let e = Vec::<i32>::new();
+  ///
+  /// let mut variations = a.variations_iter(2);
+  /// assert_eq!(variations.size_hint(), (6, Some(6)));
+  /// assert_eq!(variations.next(), Some(vec![1, 2]));
+  /// assert_eq!(variations.next(), Some(vec![1, 3]));
+  /// assert_eq!(variations.size_hint(), (4, Some(4)));
+  ///
+  /// assert_eq!(a.variations_iter(3).size_hint(), (6, Some(6)));
+  /// assert_eq!(e.variations_iter(1).size_hint(), (0, Some(0)));
+  /// ```
+  #[allow(clippy::cast_sign_loss)]
+  #[allow(clippy::cast_possible_truncation)]
+  #[allow(clippy::cast_possible_wrap)]
+  fn variations_iter<'a>(&'a self, k: usize) -> impl Iterator<Item = Self>
+  where
+    Item: Clone,
+    Self: FromIterator<Item> + Sized,
+    Item: 'a,
   {
     assert!(k <= MAX_SIZE, "k (is {k:?}) should be <= {MAX_SIZE:?})");
     let values = self.into_iter().collect::<Vec<_>>();
@@ -2085,8 +2665,9 @@ where
     let mut variation = iter::once(i64::MIN).chain(0..(k as i64)).collect::<Vec<_>>();
     let mut used_indices =
       iter::repeat_n(true, k).chain(iter::repeat_n(false, size.saturating_sub(k))).collect::<Vec<_>>();
+    let count = if k > size { Some(0) } else { ((size - k + 1)..=size).try_fold(1_usize, usize::checked_mul) };
     let mut current_slot = (size + 1).saturating_sub(k);
-    unfold(|| {
+    unfold_sized(count, move || {
       if current_slot == 0 {
         return None;
       }
@@ -2110,7 +2691,6 @@ where
       }
       tuple
     })
-    .collect()
   }
 
   /// Creates a new sequence consisting of overlapping `N` element windows
@@ -2151,24 +2731,7 @@ where
     Item: Clone,
     Self: IntoIterator<Item = Item> + FromIterator<Item>,
   {
-    assert_ne!(size, 0, "window size must be non-zero");
-    assert_ne!(step, 0, "step must be non-zero");
-    let mut window = VecDeque::<Item>::with_capacity(size);
-    self
-      .into_iter()
-      .filter_map(|item| {
-        window.push_back(item.clone());
-        if window.len() >= size {
-          let tuple = Some(Self::from_iter(window.clone()));
-          for _ in 0..step {
-            let _unused = window.pop_front();
-          }
-          tuple
-        } else {
-          None
-        }
-      })
-      .collect()
+    windowed(self.into_iter().cloned(), size, step).collect()
   }
 
   /// Creates a new sequence consisting of overlapping `N` element windows
@@ -2303,22 +2866,13 @@ where
   #[inline]
   #[must_use]
   fn zip_padded<T>(
-    self, elements: impl IntoIterator<Item = T>, mut to_left_value: impl FnMut() -> Item,
-    mut to_right_value: impl FnMut() -> T,
+    self, elements: impl IntoIterator<Item = T>, to_left_value: impl FnMut() -> Item, to_right_value: impl FnMut() -> T,
   ) -> Self::This<(Item, T)>
   where
     Self: IntoIterator<Item = Item> + Sized,
     Self::This<(Item, T)>: FromIterator<(Item, T)>,
   {
-    let mut left_iterator = self.into_iter();
-    let mut right_iterator = elements.into_iter();
-    unfold(|| match (left_iterator.next(), right_iterator.next()) {
-      (Some(left), Some(right)) => Some((left, right)),
-      (Some(left), None) => Some((left, to_right_value())),
-      (None, Some(right)) => Some((to_left_value(), right)),
-      (None, None) => None,
-    })
-    .collect()
+    zip_padded(self.into_iter(), elements, to_left_value, to_right_value).collect()
   }
 }
 
@@ -2333,13 +2887,11 @@ where
   indices.iter().map(|index| values[*index as usize].clone()).collect::<Result>()
 }
 
-pub(crate) fn chunked<Item, Collection>(collection: Collection, size: usize, exact: bool) -> Vec<Collection>
-where
-  Collection: FromIterator<Item> + IntoIterator<Item = Item>,
-{
+pub(crate) fn chunked<Item, Collection: FromIterator<Item>>(
+  mut iterator: impl Iterator<Item = Item>, size: usize, exact: bool,
+) -> impl Iterator<Item = Collection> {
   assert_ne!(size, 0, "chunk size must be non-zero");
-  let mut iterator = collection.into_iter();
-  unfold(|| {
+  unfold(move || {
     let mut chunk_size = 0;
     let chunk = unfold(|| {
       if chunk_size < size
@@ -2353,5 +2905,210 @@ where
     .collect();
     if chunk_size == size || (!exact && chunk_size > 0) { Some(chunk) } else { None }
   })
-  .collect()
+}
+
+pub(crate) fn coalesce<Item>(
+  mut iterator: impl Iterator<Item = Item>, mut function: impl FnMut(Item, Item) -> Result<Item, (Item, Item)>,
+) -> impl Iterator<Item = Item> {
+  let mut last = iterator.next();
+  unfold(move || {
+    loop {
+      if let Some(previous) = last.take() {
+        if let Some(current) = iterator.next() {
+          match function(previous, current) {
+            Ok(merged) => last = Some(merged),
+            Err((new_previous, new_current)) => {
+              last = Some(new_current);
+              return Some(new_previous);
+            }
+          }
+        } else {
+          return Some(previous);
+        }
+      } else {
+        return None;
+      }
+    }
+  })
+}
+
+pub(crate) fn divide_by<Item, Collection: FromIterator<Item>>(
+  mut iterator: impl Iterator<Item = Item>, mut separator: impl FnMut(&Item) -> bool,
+) -> impl Iterator<Item = Collection> {
+  let mut empty = false;
+  unfold(move || {
+    if empty {
+      return None;
+    }
+    let chunk = unfold(|| {
+      if let Some(item) = iterator.next() {
+        if !separator(&item) {
+          return Some(item);
+        }
+      } else {
+        empty = true;
+      }
+      None
+    })
+    .collect();
+    Some(chunk)
+  })
+}
+
+pub(crate) fn divide_sequence<'a, Item: PartialEq + 'a, Collection: FromIterator<Item>>(
+  iterator: impl Iterator<Item = Item>, delimiter: &'a impl Iterable<Item<'a> = &'a Item>,
+) -> Vec<Collection> {
+  sequence_segments(iterator, delimiter, usize::MAX).into_iter().map(|segment| segment.into_iter().collect()).collect()
+}
+
+pub(crate) fn duplicates<Item: Eq + Hash>(iterator: impl Iterator<Item = Item>) -> impl Iterator<Item = Item> {
+  let mut occurred = HashSet::with_capacity(iterator.size_hint().0);
+  let mut duplicated = HashSet::with_capacity(iterator.size_hint().0);
+  iterator.filter_map(move |item| {
+    if !duplicated.contains(&item) {
+      if let Some(result) = occurred.take(&item) {
+        let _ = duplicated.insert(item);
+        return Some(result);
+      }
+      let _ = occurred.insert(item);
+    }
+    None
+  })
+}
+
+pub(crate) fn interleave<Item>(
+  mut iterator: impl Iterator<Item = Item>, elements: impl IntoIterator<Item = Item>,
+) -> impl Iterator<Item = Item> {
+  let mut elements_iterator = elements.into_iter();
+  let mut left = true;
+  unfold(move || {
+    let new_item = if left {
+      iterator.next().or_else(|| elements_iterator.next())
+    } else {
+      elements_iterator.next().or_else(|| iterator.next())
+    };
+    left = !left;
+    new_item
+  })
+}
+
+pub(crate) fn intersperse_with<Item>(
+  mut iterator: impl Iterator<Item = Item>, interval: usize, mut to_value: impl FnMut() -> Item,
+) -> impl Iterator<Item = Item> {
+  assert_ne!(interval, 0, "interval must be non-zero");
+  let mut index = 0_usize;
+  let mut stored: Option<Item> = None;
+  unfold(move || {
+    stored.take().or_else(|| {
+      iterator.next().map(|item| {
+        let new_item = if index != 0 && index.is_multiple_of(interval) {
+          stored = Some(item);
+          to_value()
+        } else {
+          item
+        };
+        index += 1;
+        new_item
+      })
+    })
+  })
+}
+
+pub(crate) fn pad_right_with<Item>(
+  mut iterator: impl Iterator<Item = Item>, size: usize, mut to_element: impl FnMut(usize) -> Item,
+) -> impl Iterator<Item = Item> {
+  let mut index = 0_usize;
+  unfold(move || {
+    let new_item = iterator.next().or_else(|| if index < size { Some(to_element(index)) } else { None });
+    index += 1;
+    new_item
+  })
+}
+
+pub(crate) fn unique_by<Item, K: Eq + Hash>(
+  iterator: impl Iterator<Item = Item>, mut to_key: impl FnMut(&Item) -> K,
+) -> impl Iterator<Item = Item> {
+  let mut occurred = HashSet::with_capacity(iterator.size_hint().0);
+  iterator.filter(move |item| {
+    let key = to_key(item);
+    if occurred.contains(&key) {
+      false
+    } else {
+      let _unused = occurred.insert(key);
+      true
+    }
+  })
+}
+
+pub(crate) fn windowed<Item: Clone, Collection: FromIterator<Item>>(
+  iterator: impl Iterator<Item = Item>, size: usize, step: usize,
+) -> impl Iterator<Item = Collection> {
+  assert_ne!(size, 0, "window size must be non-zero");
+  assert_ne!(step, 0, "step must be non-zero");
+  let mut window = VecDeque::<Item>::with_capacity(size);
+  iterator.filter_map(move |item| {
+    window.push_back(item);
+    if window.len() >= size {
+      let result = Some(Collection::from_iter(window.clone()));
+      for _ in 0..step {
+        let _unused = window.pop_front();
+      }
+      result
+    } else {
+      None
+    }
+  })
+}
+
+pub(crate) fn replace_sequence<'a, Item: Clone + PartialEq + 'a, Collection: FromIterator<Item>>(
+  iterator: impl Iterator<Item = Item>, pattern: &'a impl Iterable<Item<'a> = &'a Item>,
+  replacement: impl IntoIterator<Item = Item>, n: usize,
+) -> Collection {
+  let replacement = replacement.into_iter().collect::<Vec<_>>();
+  let mut segments = sequence_segments(iterator, pattern, n).into_iter();
+  let first_segment = segments.next().unwrap_or_default();
+  first_segment.into_iter().chain(segments.flat_map(|segment| replacement.iter().cloned().chain(segment))).collect()
+}
+
+/// Splits elements into segments separated by the first `n` non-overlapping occurrences of a subsequence
+/// found from left to right.
+fn sequence_segments<'a, Item: PartialEq + 'a>(
+  iterator: impl Iterator<Item = Item>, pattern: &'a impl Iterable<Item<'a> = &'a Item>, n: usize,
+) -> Vec<Vec<Item>> {
+  let values = iterator.collect::<Vec<_>>();
+  let pattern = pattern.iterator().collect::<Vec<_>>();
+  let mut next_index = 0_usize;
+  let positions = sequence_positions(values.iter(), pattern.iter().copied())
+    .filter(|&position| {
+      let disjoint = position >= next_index;
+      if disjoint {
+        next_index = position + pattern.len();
+      }
+      disjoint
+    })
+    .take(n)
+    .collect::<Vec<_>>();
+  let mut result = Vec::with_capacity(positions.len() + 1);
+  let mut remaining = values.into_iter();
+  let mut index = 0_usize;
+  for position in positions {
+    result.push(remaining.by_ref().take(position - index).collect());
+    remaining.by_ref().take(pattern.len()).for_each(drop);
+    index = position + pattern.len();
+  }
+  result.push(remaining.collect());
+  result
+}
+
+pub(crate) fn zip_padded<Item, T>(
+  mut iterator: impl Iterator<Item = Item>, elements: impl IntoIterator<Item = T>,
+  mut to_left_value: impl FnMut() -> Item, mut to_right_value: impl FnMut() -> T,
+) -> impl Iterator<Item = (Item, T)> {
+  let mut elements_iterator = elements.into_iter();
+  unfold(move || match (iterator.next(), elements_iterator.next()) {
+    (Some(left), Some(right)) => Some((left, right)),
+    (Some(left), None) => Some((left, to_right_value())),
+    (None, Some(right)) => Some((to_left_value(), right)),
+    (None, None) => None,
+  })
 }
@@ -0,0 +1,733 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// String operations treating strings as sequences of characters.
+///
+/// Methods have the following properties:
+///
+/// - Requires the collection to represent a string
+/// - Does not consume the string
+/// - Positions and sizes are measured in characters, not bytes
+/// - Creates a new string or a collection of new strings
+///
+/// The generic [`Collection`](crate::Collection), [`Sequence`](crate::Sequence) and
+/// [`SequenceTo`](crate::SequenceTo) traits require `&Self` to iterate over `&Item` references,
+/// which is impossible for strings since they store their content as UTF-8 bytes instead
+/// of a sequence of `char` values. This trait provides their character-based equivalents instead,
+/// with closures receiving references to decoded characters just like their generic counterparts.
+/// Strings implement [`Iterable`](crate::Iterable) over characters directly.
+pub trait Text {
+  /// Creates a new string by inserting a character at the specified character index
+  /// of this string.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `index` is greater than the number of characters of this string.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "abc";
+  ///
+  /// assert_eq!(a.add_at(0, 'd'), "dabc");
+  /// assert_eq!(a.add_at(3, 'd'), "abcd");
+  /// ```
+  #[must_use]
+  fn add_at(&self, index: usize, element: char) -> String;
+
+  /// Tests if every character of this string matches a predicate.
+  ///
+  /// An empty string returns `true`.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "abc";
+  ///
+  /// assert!(a.all(|x| x.is_alphabetic()));
+  /// assert!("".all(|x| x.is_alphabetic()));
+  ///
+  /// assert!(!a.all(|&x| x > 'b'));
+  /// ```
+  #[must_use]
+  fn all(&self, predicate: impl FnMut(&char) -> bool) -> bool;
+
+  /// Tests if any character of this string matches a predicate.
+  ///
+  /// An empty string returns `false`.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "abc";
+  ///
+  /// assert!(a.any(|&x| x > 'b'));
+  ///
+  /// assert!(!a.any(|x| x.is_numeric()));
+  /// assert!(!"".any(|x| x.is_alphabetic()));
+  /// ```
+  #[must_use]
+  fn any(&self, predicate: impl FnMut(&char) -> bool) -> bool;
+
+  /// Creates a vector of strings by splitting this string into non-overlapping chunks,
+  /// starting at the beginning of this string.
+  ///
+  /// The chunks do not overlap. If `size` does not divide the number of characters
+  /// of this string, then the last chunk will not have length `size`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if chunk `size` is 0.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "abcde";
+  ///
+  /// assert_eq!(a.chunked(2), vec!["ab", "cd", "e"]);
+  /// assert_eq!("".chunked(2), Vec::<String>::new());
+  /// ```
+  #[must_use]
+  fn chunked(&self, size: usize) -> Vec<String>;
+
+  /// Creates a vector of strings by splitting this string into non-overlapping chunks,
+  /// starting at the beginning of this string.
+  ///
+  /// The chunks do not overlap. If `size` does not divide the number of characters
+  /// of this string, then the last up to `size - 1` characters will be omitted.
+  ///
+  /// # Panics
+  ///
+  /// Panics if chunk `size` is 0.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "abcde";
+  ///
+  /// assert_eq!(a.chunked_exact(2), vec!["ab", "cd"]);
+  /// ```
+  #[must_use]
+  fn chunked_exact(&self, size: usize) -> Vec<String>;
+
+  /// Counts characters of this string that satisfy a predicate.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "abcb";
+  ///
+  /// assert_eq!(a.count_by(|&x| x == 'b'), 2);
+  /// assert_eq!(a.count_by(|&x| x == 'd'), 0);
+  /// ```
+  #[must_use]
+  fn count_by(&self, predicate: impl FnMut(&char) -> bool) -> usize;
+
+  /// Counts characters of this string that are unique.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "abbc";
+  ///
+  /// assert_eq!(a.count_unique(), 3);
+  /// ```
+  fn count_unique(&self) -> usize;
+
+  /// Creates a new string from this string without
+  /// the first occurrence of a character.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "abcb";
+  ///
+  /// assert_eq!(a.delete('b'), "acb");
+  /// assert_eq!(a.delete('d'), "abcb");
+  /// ```
+  #[must_use]
+  fn delete(&self, element: char) -> String;
+
+  /// Creates a new string by omitting a character at the specified character index
+  /// of this string.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `index` is out of bounds.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "abc";
+  ///
+  /// assert_eq!(a.delete_at(0), "bc");
+  /// assert_eq!(a.delete_at(2), "ab");
+  /// ```
+  #[must_use]
+  fn delete_at(&self, index: usize) -> String;
+
+  /// Creates a new string containing only characters occurring more than once in this string.
+  ///
+  /// Each duplicated character is included only once, in the order of its second occurrence.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "abcbca";
+  ///
+  /// assert_eq!(a.duplicates(), "bca");
+  /// ```
+  #[must_use]
+  fn duplicates(&self) -> String;
+
+  /// Creates a new string containing only characters of this string
+  /// which satisfy a predicate.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "a1b2";
+  ///
+  /// assert_eq!(a.filter(|x| x.is_alphabetic()), "ab");
+  /// ```
+  #[must_use]
+  fn filter(&self, predicate: impl FnMut(&char) -> bool) -> String;
+
+  /// Creates a new string by filtering and mapping characters of this string.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "a1b2";
+  ///
+  /// assert_eq!(a.filter_map(|x| x.is_alphabetic().then(|| x.to_ascii_uppercase())), "AB");
+  /// ```
+  #[must_use]
+  fn filter_map(&self, function: impl FnMut(&char) -> Option<char>) -> String;
+
+  /// Applies a function to the characters of this string and returns
+  /// the first non-none result.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "ab1c2";
+  ///
+  /// assert_eq!(a.find_map(|x| x.to_digit(10)), Some(1));
+  /// assert_eq!("abc".find_map(|x| x.to_digit(10)), None);
+  /// ```
+  fn find_map<B>(&self, function: impl FnMut(&char) -> Option<B>) -> Option<B>;
+
+  /// Folds every character of this string into an accumulator by applying an operation,
+  /// returning the final result.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "123";
+  ///
+  /// assert_eq!(a.fold(0, |r, x| r * 10 + x.to_digit(10).unwrap_or(0)), 123);
+  /// ```
+  fn fold<B>(&self, initial_value: B, function: impl FnMut(B, &char) -> B) -> B;
+
+  /// Computes the number of occurrences of each character of this string.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = "abb";
+  ///
+  /// assert_eq!(a.frequencies(), HashMap::from([('a', 1), ('b', 2)]));
+  /// ```
+  fn frequencies(&self) -> HashMap<char, usize>;
+
+  /// Creates a map of keys mapped to strings of characters of this string according
+  /// to the specified discriminator function.
+  ///
+  /// The discriminator function takes a reference to a character and returns a group key.
+  /// The characters within each group retain their order in this string.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::*;
+  ///
+  /// let a = "a1b2c";
+  ///
+  /// assert_eq!(a.group_by(|x| x.is_numeric()), HashMap::from([(false, "abc".to_string()), (true, "12".to_string())]));
+  /// ```
+  fn group_by<K: Eq + Hash>(&self, to_key: impl FnMut(&char) -> K) -> HashMap<K, String>;
+
+  /// Creates a new string from this string without the last character.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// assert_eq!("abč".init(), "ab");
+  /// assert_eq!("".init(), "");
+  /// ```
+  #[must_use]
+  fn init(&self) -> String;
+
+  /// Creates a new string which places `element` between characters of this string
+  /// with the distance between the inserted characters determined by the specified `interval`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `interval` is 0.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "abcde";
+  ///
+  /// assert_eq!(a.intersperse(1, '-'), "a-b-c-d-e");
+  /// assert_eq!(a.intersperse(2, '-'), "ab-cd-e");
+  /// ```
+  #[must_use]
+  fn intersperse(&self, interval: usize, element: char) -> String;
+
+  /// Creates a new string by applying the given closure `function` to each character
+  /// of this string.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "abc";
+  ///
+  /// assert_eq!(a.map(|x| x.to_ascii_uppercase()), "ABC");
+  /// ```
+  #[must_use]
+  fn map(&self, function: impl FnMut(&char) -> char) -> String;
+
+  /// Returns the character that gives the maximum value with respect to the
+  /// specified comparison function.
+  ///
+  /// If several characters are equally maximum, the last character is
+  /// returned. If the string is empty, [`None`] is returned.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "bca";
+  ///
+  /// assert_eq!(a.max_by(|x, y| x.cmp(y)), Some('c'));
+  /// assert_eq!("".max_by(|x, y| x.cmp(y)), None);
+  /// ```
+  fn max_by(&self, compare: impl FnMut(&char, &char) -> Ordering) -> Option<char>;
+
+  /// Returns the character that gives the maximum value from the
+  /// specified function.
+  ///
+  /// If several characters are equally maximum, the last character is
+  /// returned. If the string is empty, [`None`] is returned.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "bCa";
+  ///
+  /// assert_eq!(a.max_by_key(|x| x.to_ascii_lowercase()), Some('C'));
+  /// ```
+  fn max_by_key<K: Ord>(&self, to_key: impl FnMut(&char) -> K) -> Option<char>;
+
+  /// Returns the maximum character of this string.
+  ///
+  /// If the string is empty, [`None`] is returned.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// assert_eq!("bca".max_of(), Some('c'));
+  /// assert_eq!("".max_of(), None);
+  /// ```
+  fn max_of(&self) -> Option<char>;
+
+  /// Returns the character that gives the minimum value with respect to the
+  /// specified comparison function.
+  ///
+  /// If several characters are equally minimum, the first character is
+  /// returned. If the string is empty, [`None`] is returned.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "bca";
+  ///
+  /// assert_eq!(a.min_by(|x, y| x.cmp(y)), Some('a'));
+  /// assert_eq!("".min_by(|x, y| x.cmp(y)), None);
+  /// ```
+  fn min_by(&self, compare: impl FnMut(&char, &char) -> Ordering) -> Option<char>;
+
+  /// Returns the character that gives the minimum value from the
+  /// specified function.
+  ///
+  /// If several characters are equally minimum, the first character is
+  /// returned. If the string is empty, [`None`] is returned.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "bCa";
+  ///
+  /// assert_eq!(a.min_by_key(|x| x.to_ascii_lowercase()), Some('a'));
+  /// ```
+  fn min_by_key<K: Ord>(&self, to_key: impl FnMut(&char) -> K) -> Option<char>;
+
+  /// Returns the minimum character of this string.
+  ///
+  /// If the string is empty, [`None`] is returned.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// assert_eq!("bca".min_of(), Some('a'));
+  /// assert_eq!("".min_of(), None);
+  /// ```
+  fn min_of(&self) -> Option<char>;
+
+  /// Returns the minimum and maximum character with respect to the specified comparison function.
+  ///
+  /// If several characters are equally minimum, the first character is returned.
+  /// If several characters are equally maximum, the last character is returned.
+  /// If the string is empty, [`None`] is returned.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "bca";
+  ///
+  /// assert_eq!(a.minmax_by(|x, y| x.cmp(y)), Some(('a', 'c')));
+  /// assert_eq!("".minmax_by(|x, y| x.cmp(y)), None);
+  /// ```
+  fn minmax_by(&self, compare: impl FnMut(&char, &char) -> Ordering) -> Option<(char, char)>;
+
+  /// Returns the minimum and maximum character with respect to the specified key function.
+  ///
+  /// If several characters are equally minimum, the first character is returned.
+  /// If several characters are equally maximum, the last character is returned.
+  /// If the string is empty, [`None`] is returned.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "bCa";
+  ///
+  /// assert_eq!(a.minmax_by_key(|x| x.to_ascii_lowercase()), Some(('a', 'C')));
+  /// ```
+  fn minmax_by_key<K: Ord>(&self, to_key: impl FnMut(&char) -> K) -> Option<(char, char)>;
+
+  /// Returns the minimum and maximum character of this string.
+  ///
+  /// If the string is empty, [`None`] is returned.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// assert_eq!("bca".minmax_of(), Some(('a', 'c')));
+  /// assert_eq!("".minmax_of(), None);
+  /// ```
+  fn minmax_of(&self) -> Option<(char, char)>;
+
+  /// Creates a new string by padding this string to a minimum length of `size` characters
+  /// and filling missing characters with the specified character, starting from the back.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "abc";
+  ///
+  /// assert_eq!(a.pad_left(5, '_'), "__abc");
+  /// assert_eq!(a.pad_left(2, '_'), "abc");
+  /// ```
+  #[must_use]
+  fn pad_left(&self, size: usize, element: char) -> String;
+
+  /// Creates a new string by padding this string to a minimum length of `size` characters
+  /// and filling missing characters with the specified character.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "abc";
+  ///
+  /// assert_eq!(a.pad_right(5, '_'), "abc__");
+  /// assert_eq!(a.pad_right(2, '_'), "abc");
+  /// ```
+  #[must_use]
+  fn pad_right(&self, size: usize, element: char) -> String;
+
+  /// Searches for a character of this string that satisfies a predicate, returning its character index.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "čaj";
+  ///
+  /// assert_eq!(a.position(|&x| x == 'j'), Some(2));
+  /// assert_eq!(a.position(|&x| x == 'k'), None);
+  /// ```
+  fn position(&self, predicate: impl FnMut(&char) -> bool) -> Option<usize>;
+
+  /// Searches for characters of this string that satisfy a predicate, returning their character indices.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "abcb";
+  ///
+  /// assert_eq!(a.position_multi(|&x| x == 'b'), vec![1, 3]);
+  /// assert_eq!(a.position_multi(|&x| x == 'd'), Vec::<usize>::new());
+  /// ```
+  fn position_multi(&self, predicate: impl FnMut(&char) -> bool) -> Vec<usize>;
+
+  /// Searches for a substring in this string, returning the character index of its first occurrence.
+  ///
+  /// Unlike [`str::find`], the index is measured in characters instead of bytes.
+  /// An empty substring is found at index 0.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "čaj a káva";
+  ///
+  /// assert_eq!(a.position_sequence("a"), Some(1));
+  /// assert_eq!(a.position_sequence("káva"), Some(6));
+  /// assert_eq!(a.position_sequence("kava"), None);
+  /// ```
+  fn position_sequence(&self, sequence: &str) -> Option<usize>;
+
+  /// Searches for all, possibly overlapping, occurrences of a substring in this string,
+  /// returning their character indices.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "ababa";
+  ///
+  /// assert_eq!(a.position_sequence_multi("aba"), vec![0, 2]);
+  /// assert_eq!(a.position_sequence_multi("c"), Vec::<usize>::new());
+  /// ```
+  fn position_sequence_multi(&self, sequence: &str) -> Vec<usize>;
+
+  /// Creates a new string with characters of this string in reverse order.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "abc";
+  ///
+  /// assert_eq!(a.rev(), "cba");
+  /// ```
+  #[must_use]
+  fn rev(&self) -> String;
+
+  /// Searches for a character of this string that satisfies a predicate, starting from the back
+  /// and returning its character index.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "abcb";
+  ///
+  /// assert_eq!(a.rposition(|&x| x == 'b'), Some(3));
+  /// assert_eq!(a.rposition(|&x| x == 'd'), None);
+  /// ```
+  fn rposition(&self, predicate: impl FnMut(&char) -> bool) -> Option<usize>;
+
+  /// Creates a new string with characters of this string sorted by their Unicode scalar values.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "cab";
+  ///
+  /// assert_eq!(a.sorted(), "abc");
+  /// ```
+  #[must_use]
+  fn sorted(&self) -> String;
+
+  /// Creates a new string from this string by replacing the first occurrence of a character
+  /// with a replacement character.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "abcb";
+  ///
+  /// assert_eq!(a.substitute('b', 'd'), "adcb");
+  /// assert_eq!(a.substitute('e', 'd'), "abcb");
+  /// ```
+  #[must_use]
+  fn substitute(&self, element: char, replacement: char) -> String;
+
+  /// Creates a new string from this string by replacing a character at the specified
+  /// character index with a replacement character.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `index` is out of bounds.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "abc";
+  ///
+  /// assert_eq!(a.substitute_at(1, 'd'), "adc");
+  /// ```
+  #[must_use]
+  fn substitute_at(&self, index: usize, replacement: char) -> String;
+
+  /// Creates a new string from this string by swapping characters at the specified
+  /// character indices.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `source_index` or `target_index` are out of bounds.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "abc";
+  ///
+  /// assert_eq!(a.swap_at(0, 2), "cba");
+  /// ```
+  #[must_use]
+  fn swap_at(&self, source_index: usize, target_index: usize) -> String;
+
+  /// Creates a new string from this string without the first character.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// assert_eq!("čab".tail(), "ab");
+  /// assert_eq!("".tail(), "");
+  /// ```
+  #[must_use]
+  fn tail(&self) -> String;
+
+  /// Creates a new string containing only the first occurrence of each character of this string.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "abcbca";
+  ///
+  /// assert_eq!(a.unique(), "abc");
+  /// ```
+  #[must_use]
+  fn unique(&self) -> String;
+
+  /// Creates a vector of strings consisting of overlapping windows of `size` characters
+  /// of this string, starting at the beginning of this string.
+  ///
+  /// The step parameter determines the distance between the first characters of
+  /// successive windows.
+  ///
+  /// If `size` is greater than the number of characters, it will return no windows.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `size` or `step` is 0.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::*;
+  ///
+  /// let a = "abcd";
+  ///
+  /// assert_eq!(a.windowed(2, 1), vec!["ab", "bc", "cd"]);
+  /// assert_eq!(a.windowed(2, 2), vec!["ab", "cd"]);
+  /// assert_eq!(a.windowed(5, 1), Vec::<String>::new());
+  /// ```
+  #[must_use]
+  fn windowed(&self, size: usize, step: usize) -> Vec<String>;
+}
@@ -0,0 +1,341 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::{Hash, RandomState};
+
+use crate::extensions::{
+  chunked, coalesce, divide_by, duplicates, frequencies_by, group_by, interleave, intersperse_with, joined,
+  pad_right_with, unique_by, windowed, zip_padded,
+};
+
+/// Iterator operations.
+///
+/// Methods have the following properties:
+///
+/// - Requires the collection to represent an iterator
+/// - Consumes the iterator and its elements
+/// - Adapters produce elements lazily, terminal operations consume the whole iterator
+///
+/// This trait is not exported from the crate root since it is implemented for every iterator
+/// and its method names match those of other iterator extension traits such as `Itertools`.
+/// It has to be imported explicitly from the [`traversal`](crate::traversal) module.
+pub trait Traversal<Item>: Iterator<Item = Item> + Sized {
+  /// Creates an iterator over non-overlapping chunks of elements of this iterator,
+  /// starting at the beginning of this iterator.
+  ///
+  /// The chunks are vectors and do not overlap. If `size` does not divide the number
+  /// of elements, then the last chunk will not have length `size`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `size` is 0.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::traversal::Traversal;
+  ///
+  /// assert_eq!((1..=5).chunked(2).collect::<Vec<_>>(), vec![vec![1, 2], vec![3, 4], vec![5]]);
+  /// ```
+  #[inline]
+  fn chunked(self, size: usize) -> impl Iterator<Item = Vec<Item>> {
+    chunked(self, size, false)
+  }
+
+  /// Creates an iterator which uses the compression closure to
+  /// optionally merge consecutive elements of this iterator.
+  ///
+  /// The closure `function` is passed two elements, `previous` and `current` and may
+  /// return either (1) `Ok(merged)` to merge the two values or
+  /// (2) `Err((previous, current)` to indicate they can't be merged.
+  /// In (2), the value `previous` is yielded by the new iterator.
+  /// Either (1) `merged` or (2) `current` becomes the previous value
+  /// when coalescing continues with the next pair of elements to merge. The
+  /// value that remains at the end is also yielded.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::traversal::Traversal;
+  ///
+  /// let a = vec![1, 1, 2, 1, 2, 2, 3];
+  ///
+  /// assert_eq!(
+  ///   a.into_iter().coalesce(|p, c| if p == c { Ok(p) } else { Err((p, c)) }).collect::<Vec<_>>(),
+  ///   vec![1, 2, 1, 2, 3]
+  /// );
+  /// ```
+  #[inline]
+  fn coalesce(self, function: impl FnMut(Item, Item) -> Result<Item, (Item, Item)>) -> impl Iterator<Item = Item> {
+    coalesce(self, function)
+  }
+
+  /// Creates an iterator over chunks of elements of this iterator
+  /// separated by elements that match a separator predicate.
+  ///
+  /// The separator elements are not included in the chunks.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::traversal::Traversal;
+  ///
+  /// let a = vec![1, 2, 0, 3, 0, 4];
+  ///
+  /// assert_eq!(a.into_iter().divide_by(|&x| x == 0).collect::<Vec<_>>(), vec![vec![1, 2], vec![3], vec![4]]);
+  /// ```
+  #[inline]
+  fn divide_by(self, separator: impl FnMut(&Item) -> bool) -> impl Iterator<Item = Vec<Item>> {
+    divide_by(self, separator)
+  }
+
+  /// Creates an iterator which yields only the second occurrence of each
+  /// element of this iterator occurring more than once.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::traversal::Traversal;
+  ///
+  /// let a = vec![1, 2, 2, 3, 3, 3];
+  ///
+  /// assert_eq!(a.into_iter().duplicates().collect::<Vec<_>>(), vec![2, 3]);
+  /// ```
+  #[inline]
+  fn duplicates(self) -> impl Iterator<Item = Item>
+  where
+    Item: Eq + Hash,
+  {
+    duplicates(self)
+  }
+
+  /// Compute the number of occurrences for each group of elements of this iterator according to
+  /// the specified discriminator function.
+  ///
+  /// The discriminator function takes a reference to an element and returns a group key.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::traversal::Traversal;
+  ///
+  /// let a = vec![1, 2, 2, 3];
+  ///
+  /// assert_eq!(a.into_iter().frequencies_by(|x| x % 2), HashMap::from([(0, 2), (1, 2)]));
+  /// ```
+  #[inline]
+  fn frequencies_by<K: Eq + Hash>(self, to_key: impl FnMut(&Item) -> K) -> HashMap<K, usize> {
+    frequencies_by(self, to_key, RandomState::new())
+  }
+
+  /// Creates a map of keys mapped to vectors of elements of this iterator according to
+  /// the specified discriminator function.
+  ///
+  /// The discriminator function takes a reference to an element and returns a group key.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use cantrip::traversal::Traversal;
+  ///
+  /// let a = vec![1, 2, 3];
+  ///
+  /// assert_eq!(a.into_iter().group_by(|x| x % 2), HashMap::from([(0, vec![2]), (1, vec![1, 3])]));
+  /// ```
+  #[inline]
+  fn group_by<K: Eq + Hash>(self, to_key: impl FnMut(&Item) -> K) -> HashMap<K, Vec<Item>> {
+    group_by(self, to_key, RandomState::new())
+  }
+
+  /// Creates an iterator which interleaves elements of this iterator
+  /// with elements of another collection.
+  ///
+  /// If one of the sources is exhausted, the remaining elements of the other one are yielded.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::traversal::Traversal;
+  ///
+  /// let a = vec![1, 2, 3];
+  ///
+  /// assert_eq!(a.into_iter().interleave(vec![4, 5]).collect::<Vec<_>>(), vec![1, 4, 2, 5, 3]);
+  /// ```
+  #[inline]
+  fn interleave(self, elements: impl IntoIterator<Item = Item>) -> impl Iterator<Item = Item> {
+    interleave(self, elements)
+  }
+
+  /// Creates an iterator which places a copy of `element` between
+  /// elements of this iterator with the distance between the inserted
+  /// values determined by the specified `interval`.
+  ///
+  /// This is a lazy equivalent of [`SequenceTo::intersperse()`] named differently to avoid
+  /// a clash with the `intersperse()` method of the standard library iterators.
+  ///
+  /// [`SequenceTo::intersperse()`]: crate::SequenceTo::intersperse
+  ///
+  /// # Panics
+  ///
+  /// Panics if `interval` is 0.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::traversal::Traversal;
+  ///
+  /// let a = vec![1, 2, 3];
+  ///
+  /// assert_eq!(a.into_iter().intersperse_every(2, 0).collect::<Vec<_>>(), vec![1, 2, 0, 3]);
+  /// ```
+  #[inline]
+  fn intersperse_every(self, interval: usize, element: Item) -> impl Iterator<Item = Item>
+  where
+    Item: Clone,
+  {
+    intersperse_with(self, interval, move || element.clone())
+  }
+
+  /// Combine all elements of this iterator into one `String`, separated by `separator`.
+  ///
+  /// Use the `Display` implementation of each element.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::traversal::Traversal;
+  ///
+  /// assert_eq!((1..=3).joined(", "), "1, 2, 3");
+  /// assert_eq!((1..1).joined(", "), "");
+  /// ```
+  #[inline]
+  fn joined(self, separator: &str) -> String
+  where
+    Item: Display,
+  {
+    joined(self, separator)
+  }
+
+  /// Returns the minimum and maximum element of this iterator with respect to the
+  /// specified comparison function.
+  ///
+  /// For the minimum, the first minimal element is returned. For the maximum,
+  /// the last maximal element is returned. If the iterator is empty, [`None`] is returned.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::traversal::Traversal;
+  ///
+  /// assert_eq!((1..=3).minmax_by(|x, y| x.cmp(y)), Some((1, 3)));
+  /// assert_eq!((1..1).minmax_by(|x, y| x.cmp(y)), None);
+  /// ```
+  #[inline]
+  fn minmax_by(mut self, mut compare: impl FnMut(&Item, &Item) -> Ordering) -> Option<(Item, Item)>
+  where
+    Item: Clone,
+  {
+    self.next().map(|item| {
+      let mut min = item.clone();
+      let mut max = item;
+      for item in self {
+        if compare(&item, &min) == Ordering::Less {
+          min = item;
+        } else if compare(&item, &max) != Ordering::Less {
+          max = item;
+        }
+      }
+      (min, max)
+    })
+  }
+
+  /// Creates an iterator which pads this iterator to a minimum length of
+  /// `size` and fills missing elements with the specified value.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::traversal::Traversal;
+  ///
+  /// assert_eq!((1..=3).pad_right(5, 4).collect::<Vec<_>>(), vec![1, 2, 3, 4, 4]);
+  /// ```
+  #[inline]
+  fn pad_right(self, size: usize, element: Item) -> impl Iterator<Item = Item>
+  where
+    Item: Clone,
+  {
+    pad_right_with(self, size, move |_| element.clone())
+  }
+
+  /// Creates an iterator which yields only the first element of this iterator
+  /// for each key produced by the specified key function.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::traversal::Traversal;
+  ///
+  /// let a = vec![1, 2, 3, 4];
+  ///
+  /// assert_eq!(a.into_iter().unique_by(|x| x % 2).collect::<Vec<_>>(), vec![1, 2]);
+  /// ```
+  #[inline]
+  fn unique_by<K: Eq + Hash>(self, to_key: impl FnMut(&Item) -> K) -> impl Iterator<Item = Item> {
+    unique_by(self, to_key)
+  }
+
+  /// Creates an iterator over overlapping windows of `size` elements of this iterator,
+  /// starting at the beginning of this iterator.
+  ///
+  /// The `step` parameter determines the distance between the first elements of
+  /// successive windows.
+  ///
+  /// If `size` is greater than the number of elements, it will yield no windows.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `size` or `step` is 0.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::traversal::Traversal;
+  ///
+  /// assert_eq!((1..=3).windowed(2, 1).collect::<Vec<_>>(), vec![vec![1, 2], vec![2, 3]]);
+  /// assert_eq!((1..=3).windowed(2, 2).collect::<Vec<_>>(), vec![vec![1, 2]]);
+  /// ```
+  #[inline]
+  fn windowed(self, size: usize, step: usize) -> impl Iterator<Item = Vec<Item>>
+  where
+    Item: Clone,
+  {
+    windowed(self, size, step)
+  }
+
+  /// 'Zips up' this iterator with another collection into a single iterator of pairs.
+  ///
+  /// If this iterator yields fewer elements than the other one, additional elements
+  /// are created by calling the `to_left_value` closure. If this iterator yields more
+  /// elements than the other one, additional elements are created by calling
+  /// the `to_right_value` closure.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use cantrip::traversal::Traversal;
+  ///
+  /// assert_eq!((1..=3).zip_padded(vec![4, 5], || 1, || 2).collect::<Vec<_>>(), vec![(1, 4), (2, 5), (3, 2)]);
+  /// ```
+  #[inline]
+  fn zip_padded<T>(
+    self, elements: impl IntoIterator<Item = T>, to_left_value: impl FnMut() -> Item, to_right_value: impl FnMut() -> T,
+  ) -> impl Iterator<Item = (Item, T)> {
+    zip_padded(self, elements, to_left_value, to_right_value)
+  }
+}
+
+impl<Item, I: Iterator<Item = Item>> Traversal<Item> for I {}
@@ -15,7 +15,10 @@
 //! - Existing standard library collections are extended with equivalents of iterator methods
 //! - Additional utility methods commonly found in collection libraries are also included
 //! - Methods which modify a collection return a new collection instead of an iterator
+//! - Lazy equivalents of sequence adapters are available for any iterator via `cantrip::traversal::Traversal`
 //! - All methods treat collection instances as immutable, although some consume them
+//! - In-place equivalents of common sequence operations are available via [`SequenceMut`]
+//! - Character sequence equivalents of sequence operations are available for strings via [`Text`]
 //! - Standard library method naming conventions are followed as closely as possible
 //! - Performance is near optimal with overhead limited to new collection creation
 //!
@@ -70,28 +73,48 @@
 //!
 //! | Method / Collection type                                          | `Vec`, `VecDeque`, `LinkedList` | `Slice` | `HashSet`, `BTreeSet`, `BinaryHeap` | `HashMap`, `BTreeMap` | Consuming |
 //! |:-----------------------------------------------------------------:|:-------------------------------:|:-------:|:-----------------------------------:|:---------------------:|:---------:|
+//! | [`binary_position_by_key`](Sequence::binary_position_by_key)      |                *                |    *    |                                     |                       |     N     |
+//! | [`binary_position_of`](Sequence::binary_position_of)              |                *                |    *    |                                     |                       |     N     |
+//! | [`contains_sequence`](Sequence::contains_sequence)                |                *                |    *    |                                     |                       |     N     |
+//! | [`equal_range`](Sequence::equal_range)                            |                *                |    *    |                                     |                       |     N     |
+//! | [`equal_range_by_key`](Sequence::equal_range_by_key)              |                *                |    *    |                                     |                       |     N     |
 //! | [`find`](Collection::find)                                        |                *                |    *    |                  *                  |           *           |     N     |
 //! | [`find_map`](CollectionTo::find_map)                              |                *                |         |                  *                  |           *           |     Y     |
 //! | [`find_map_ref`](Collection::find_map_ref)                        |                *                |    *    |                  *                  |           *           |     N     |
 //! | [`find_position`](Sequence::find_position)                        |                *                |    *    |                                     |                       |     N     |
 //! | [`first`](List::first)                                            |                *                |    *    |                                     |                       |     N     |
 //! | [`last`](List::last)                                              |                *                |         |                                     |                       |     N     |
+//! | [`lower_bound`](Sequence::lower_bound)                            |                *                |    *    |                                     |                       |     N     |
+//! | [`lower_bound_by_key`](Sequence::lower_bound_by_key)              |                *                |    *    |                                     |                       |     N     |
 //! | [`max_by`](Collection::max_by)                                    |                *                |    *    |                  *                  |           *           |     N     |
 //! | [`max_by_key`](Collection::max_by_key)                            |                *                |    *    |                  *                  |           *           |     N     |
 //! | [`max_of`](Collection::max_of)                                    |                *                |    *    |                  *                  |           *           |     N     |
+//! | [`max_of_float`](Collection::max_of_float)                        |                *                |    *    |                                     |                       |     N     |
+//! | [`max_of_float_values`](Map::max_of_float_values)                 |                                 |         |                                     |           *           |     N     |
 //! | [`min_by`](Collection::min_by)                                    |                *                |    *    |                  *                  |           *           |     N     |
 //! | [`min_by_key`](Collection::min_by_key)                            |                *                |    *    |                  *                  |           *           |     N     |
 //! | [`min_of`](Collection::min_of)                                    |                *                |    *    |                  *                  |           *           |     N     |
+//! | [`min_of_float`](Collection::min_of_float)                        |                *                |    *    |                                     |                       |     N     |
+//! | [`min_of_float_values`](Map::min_of_float_values)                 |                                 |         |                                     |           *           |     N     |
 //! | [`minmax_by`](Collection::minmax_by)                              |                *                |    *    |                  *                  |           *           |     N     |
 //! | [`minmax_by_key`](Collection::minmax_by_key)                      |                *                |    *    |                  *                  |           *           |     N     |
 //! | [`minmax_of`](Collection::minmax_of)                              |                *                |    *    |                  *                  |           *           |     N     |
+//! | [`minmax_of_float`](Collection::minmax_of_float)                  |                *                |    *    |                                     |                       |     N     |
+//! | [`minmax_of_float_values`](Map::minmax_of_float_values)           |                                 |         |                                     |           *           |     N     |
+//! | [`nth_by_key`](Collection::nth_by_key)                            |                *                |    *    |                  *                  |                       |     N     |
+//! | [`nth_largest`](Collection::nth_largest)                          |                *                |    *    |                  *                  |                       |     N     |
+//! | [`nth_smallest`](Collection::nth_smallest)                        |                *                |    *    |                  *                  |                       |     N     |
 //! | [`position`](Sequence::position)                                  |                *                |    *    |                                     |                       |     N     |
 //! | [`position_multi`](Sequence::position_multi)                      |                *                |    *    |                                     |                       |     N     |
 //! | [`position_of`](Sequence::position_of)                            |                *                |    *    |                                     |                       |     N     |
 //! | [`position_of_multi`](Sequence::position_of_multi)                |                *                |    *    |                                     |                       |     N     |
 //! | [`position_sequence`](Sequence::position_sequence)                |                *                |    *    |                                     |                       |     N     |
+//! | [`position_sequence_multi`](Sequence::position_sequence_multi)    |                *                |    *    |                                     |                       |     N     |
 //! | [`rfind`](Sequence::rfind)                                        |                *                |    *    |                                     |                       |     N     |
 //! | [`rposition`](Sequence::rposition)                                |                *                |    *    |                                     |                       |     N     |
+//! | [`rposition_sequence`](Sequence::rposition_sequence)              |                *                |    *    |                                     |                       |     N     |
+//! | [`upper_bound`](Sequence::upper_bound)                            |                *                |    *    |                                     |                       |     N     |
+//! | [`upper_bound_by_key`](Sequence::upper_bound_by_key)              |                *                |    *    |                                     |                       |     N     |
 //!
 //! ### Modifying
 //!
@@ -100,22 +123,38 @@
 //! | [`add`](CollectionTo::add)                                        |                *                |         |                  *                  |           *           |     Y     |
 //! | [`add_at`](SequenceTo::add_at)                                    |                *                |         |                                     |                       |     Y     |
 //! | [`add_at_multi`](SequenceTo::add_at_multi)                        |                *                |         |                                     |                       |     Y     |
+//! | [`add_at_multi_mut`](SequenceMut::add_at_multi_mut)               |                *                |         |                                     |                       |     N     |
+//! | [`add_at_mut`](SequenceMut::add_at_mut)                           |                *                |         |                                     |                       |     N     |
 //! | [`add_multi`](CollectionTo::add_multi)                            |                *                |         |                  *                  |           *           |     Y     |
+//! | [`apply_diff`](SequenceTo::apply_diff)                            |                *                |         |                                     |                       |     Y     |
 //! | [`delete`](CollectionTo::delete)                                  |                *                |         |                  *                  |           *           |     Y     |
 //! | [`delete_at`](SequenceTo::delete_at)                              |                *                |         |                                     |                       |     Y     |
 //! | [`delete_at_multi`](SequenceTo::delete_at_multi)                  |                *                |         |                                     |                       |     Y     |
+//! | [`delete_at_multi_mut`](SequenceMut::delete_at_multi_mut)         |                *                |         |                                     |                       |     N     |
+//! | [`delete_at_mut`](SequenceMut::delete_at_mut)                     |                *                |         |                                     |                       |     N     |
 //! | [`delete_multi`](CollectionTo::delete_multi)                      |                *                |         |                  *                  |           *           |     Y     |
+//! | [`delete_multi_eq`](CollectionTo::delete_multi_eq)                |                *                |         |                  *                  |                       |     Y     |
+//! | [`delete_multi_ord`](CollectionTo::delete_multi_ord)              |                *                |         |                  *                  |                       |     Y     |
+//! | [`delete_mut`](SequenceMut::delete_mut)                           |                *                |         |                                     |                       |     N     |
 //! | [`move_at`](SequenceTo::move_at)                                  |                *                |         |                                     |                       |     Y     |
+//! | [`move_at_mut`](SequenceMut::move_at_mut)                         |                *                |         |                                     |                       |     N     |
 //! | [`pad_left`](SequenceTo::pad_left)                                |                *                |         |                                     |                       |     Y     |
 //! | [`pad_left_with`](SequenceTo::pad_left_with)                      |                *                |         |                                     |                       |     Y     |
 //! | [`pad_right`](SequenceTo::pad_right)                              |                *                |         |                                     |                       |     Y     |
+//! | [`pad_right_mut`](SequenceMut::pad_right_mut)                     |                *                |         |                                     |                       |     N     |
 //! | [`pad_right_with`](SequenceTo::pad_right_with)                    |                *                |         |                                     |                       |     Y     |
+//! | [`replace_sequence`](SequenceTo::replace_sequence)                |                *                |         |                                     |                       |     Y     |
+//! | [`replace_sequence_n`](SequenceTo::replace_sequence_n)            |                *                |         |                                     |                       |     Y     |
 //! | [`rev`](SequenceTo::rev)                                          |                *                |         |                                     |                       |     Y     |
+//! | [`sorted_insert`](SequenceTo::sorted_insert)                      |                *                |         |                                     |                       |     Y     |
 //! | [`substitute`](CollectionTo::substitute)                          |                *                |         |                  *                  |           *           |     Y     |
 //! | [`substitute_at`](SequenceTo::substitute_at)                      |                *                |         |                                     |                       |     Y     |
 //! | [`substitute_at_multi`](SequenceTo::substitute_at_multi)          |                *                |         |                                     |                       |     Y     |
+//! | [`substitute_at_mut`](SequenceMut::substitute_at_mut)             |                *                |         |                                     |                       |     N     |
 //! | [`substitute_multi`](CollectionTo::substitute_multi)              |                *                |         |                  *                  |           *           |     Y     |
+//! | [`substitute_mut`](SequenceMut::substitute_mut)                   |                *                |         |                                     |                       |     N     |
 //! | [`swap_at`](SequenceTo::swap_at)                                  |                *                |         |                                     |                       |     Y     |
+//! | [`swap_at_mut`](SequenceMut::swap_at_mut)                         |                *                |         |                                     |                       |     N     |
 //!
 //! ### Filtering
 //!
@@ -123,6 +162,8 @@
 //! |:-----------------------------------------------------------------:|:-------------------------------:|:-------:|:-----------------------------------:|:---------------------:|:---------:|
 //! | [`duplicates`](SequenceTo::duplicates)                            |                *                |         |                                     |                       |     Y     |
 //! | [`duplicates_by`](SequenceTo::duplicates_by)                      |                *                |         |                                     |                       |     Y     |
+//! | [`duplicates_eq`](SequenceTo::duplicates_eq)                      |                *                |         |                                     |                       |     Y     |
+//! | [`duplicates_ord`](SequenceTo::duplicates_ord)                    |                *                |         |                                     |                       |     Y     |
 //! | [`filter`](CollectionTo::filter)                                  |                *                |         |                  *                  |           *           |     Y     |
 //! | [`filter_keys`](Map::filter_keys)                                 |                                 |         |                                     |           *           |     Y     |
 //! | [`filter_map`](CollectionTo::filter_map)                          |                *                |         |                  *                  |           *           |     Y     |
@@ -132,22 +173,38 @@
 //! | [`init`](SequenceTo::init)                                        |                *                |         |                                     |                       |     Y     |
 //! | [`init_ref`](Slice::init_ref)                                     |                                 |    *    |                                     |                       |     Y     |
 //! | [`intersect`](CollectionTo::intersect)                            |                *                |         |                  *                  |           *           |     Y     |
+//! | [`intersect_eq`](CollectionTo::intersect_eq)                      |                *                |         |                  *                  |                       |     Y     |
+//! | [`intersect_ord`](CollectionTo::intersect_ord)                    |                *                |         |                  *                  |                       |     Y     |
 //! | [`largest`](CollectionTo::largest)                                |                *                |         |                  *                  |                       |     Y     |
+//! | [`largest_by`](CollectionTo::largest_by)                          |                *                |         |                  *                  |                       |     Y     |
+//! | [`largest_by_key`](CollectionTo::largest_by_key)                  |                *                |         |                  *                  |                       |     Y     |
+//! | [`largest_by_value`](Map::largest_by_value)                       |                                 |         |                                     |           *           |     Y     |
+//! | [`largest_floats`](CollectionTo::largest_floats)                  |                *                |         |                                     |                       |     Y     |
+//! | [`par_filter`](CollectionTo::par_filter)                          |                *                |         |                  *                  |                       |     Y     |
 //! | [`slice`](SequenceTo::slice)                                      |                *                |         |                                     |                       |     Y     |
 //! | [`smallest`](CollectionTo::smallest)                              |                *                |         |                  *                  |                       |     Y     |
 //! | [`skip`](SequenceTo::skip)                                        |                *                |         |                                     |                       |     Y     |
 //! | [`skip_while`](SequenceTo::skip_while)                            |                *                |         |                                     |                       |     Y     |
 //! | [`skip_ref`](Slice::skip_ref)                                     |                                 |    *    |                                     |                       |     Y     |
 //! | [`skip_while_ref`](Slice::skip_while_ref)                         |                                 |    *    |                                     |                       |     Y     |
+//! | [`smallest_by`](CollectionTo::smallest_by)                        |                *                |         |                  *                  |                       |     Y     |
+//! | [`smallest_by_key`](CollectionTo::smallest_by_key)                |                *                |         |                  *                  |                       |     Y     |
+//! | [`smallest_by_value`](Map::smallest_by_value)                     |                                 |         |                                     |           *           |     Y     |
+//! | [`smallest_floats`](CollectionTo::smallest_floats)                |                *                |         |                                     |                       |     Y     |
 //! | [`step_by`](SequenceTo::step_by)                                  |                *                |         |                                     |                       |     Y     |
 //! | [`take`](SequenceTo::take)                                        |                *                |         |                                     |                       |     Y     |
 //! | [`take_while`](SequenceTo::take_while)                            |                *                |         |                                     |                       |     Y     |
 //! | [`take_ref`](Slice::take_ref)                                     |                                 |    *    |                                     |                       |     Y     |
 //! | [`take_while_ref`](Slice::take_while_ref)                         |                                 |    *    |                                     |                       |     Y     |
+//! | [`try_filter`](CollectionTo::try_filter)                          |                *                |         |                  *                  |           *           |     Y     |
+//! | [`try_filter_ref`](CollectionTo::try_filter_ref)                  |                *                |         |                  *                  |           *           |     N     |
 //! | [`unique`](SequenceTo::unique)                                    |                *                |         |                                     |                       |     Y     |
 //! | [`unique_by`](SequenceTo::unique_by)                              |                *                |         |                                     |                       |     Y     |
 //! | [`tail`](SequenceTo::tail)                                        |                *                |         |                                     |                       |     Y     |
 //! | [`tail_ref`](Slice::tail_ref)                                     |                                 |    *    |                                     |                       |     N     |
+//! | [`unique_eq`](SequenceTo::unique_eq)                              |                *                |         |                                     |                       |     Y     |
+//! | [`unique_mut`](SequenceMut::unique_mut)                           |                *                |         |                                     |                       |     N     |
+//! | [`unique_ord`](SequenceTo::unique_ord)                            |                *                |         |                                     |                       |     Y     |
 //!
 //! ### Mapping
 //!
@@ -160,10 +217,15 @@
 //! | [`map`](CollectionTo::map)                                        |                *                |         |                  *                  |           *           |     Y     |
 //! | [`map_ref`](CollectionTo::map_ref)                                |                *                |         |                  *                  |           *           |     N     |
 //! | [`map_keys`](Map::map_keys)                                       |                                 |         |                                     |           *           |     Y     |
+//! | [`map_validated`](CollectionTo::map_validated)                    |                *                |         |                  *                  |           *           |     Y     |
+//! | [`map_validated_indexed`](SequenceTo::map_validated_indexed)      |                *                |         |                                     |                       |     Y     |
 //! | [`map_values`](Map::map_values)                                   |                                 |         |                                     |           *           |     Y     |
 //! | [`map_while`](SequenceTo::map_while)                              |                *                |         |                                     |                       |     N     |
+//! | [`par_map`](CollectionTo::par_map)                                |                *                |         |                  *                  |                       |     Y     |
 //! | [`scan`](SequenceTo::scan)                                        |                *                |         |                                     |                       |     Y     |
 //! | [`scan_ref`](SequenceTo::scan_ref)                                |                *                |         |                                     |                       |     N     |
+//! | [`try_map`](CollectionTo::try_map)                                |                *                |         |                  *                  |           *           |     Y     |
+//! | [`try_map_ref`](CollectionTo::try_map_ref)                        |                *                |         |                  *                  |           *           |     N     |
 //!
 //! ### Inspecting
 //!
@@ -175,10 +237,32 @@
 //! | [`common_suffix_length`](Sequence::common_suffix_length)          |                *                |    *    |                                     |                       |     N     |
 //! | [`count_by`](Collection::count_by)                                |                *                |    *    |                  *                  |           *           |     N     |
 //! | [`count_unique`](Sequence::count_unique)                          |                *                |    *    |                                     |           *           |     N     |
+//! | [`count_unique_eq`](Sequence::count_unique_eq)                    |                *                |    *    |                                     |                       |     N     |
+//! | [`count_unique_ord`](Sequence::count_unique_ord)                  |                *                |    *    |                                     |                       |     N     |
+//! | [`damerau_levenshtein_distance`](Sequence::damerau_levenshtein_distance) |                *                |    *    |                                     |                       |     N     |
+//! | [`diff`](Sequence::diff)                                          |                *                |    *    |                                     |                       |     N     |
 //! | [`disjoint`](Collection::disjoint)                                |                *                |    *    |                  *                  |           *           |     N     |
+//! | [`disjoint_eq`](Collection::disjoint_eq)                          |                *                |    *    |                  *                  |                       |     N     |
+//! | [`disjoint_ord`](Collection::disjoint_ord)                        |                *                |    *    |                  *                  |                       |     N     |
 //! | [`equivalent`](Sequence::equivalent)                              |                *                |    *    |                                     |                       |     N     |
+//! | [`equivalent_eq`](Sequence::equivalent_eq)                        |                *                |    *    |                                     |                       |     N     |
+//! | [`equivalent_ord`](Sequence::equivalent_ord)                      |                *                |    *    |                                     |                       |     N     |
 //! | [`frequencies`](Sequence::frequencies)                            |                *                |    *    |                                     |                       |     N     |
 //! | [`frequencies_by`](Sequence::frequencies_by)                      |                *                |    *    |                                     |                       |     N     |
+//! | [`frequencies_by_with_hasher`](Sequence::frequencies_by_with_hasher) |                *                |    *    |                                     |                       |     N     |
+//! | [`frequencies_eq`](Sequence::frequencies_eq)                      |                *                |    *    |                                     |                       |     N     |
+//! | [`frequencies_ord`](Sequence::frequencies_ord)                    |                *                |    *    |                                     |                       |     N     |
+//! | [`frequencies_with_hasher`](Sequence::frequencies_with_hasher)    |                *                |    *    |                                     |                       |     N     |
+//! | [`hamming_distance`](Sequence::hamming_distance)                  |                *                |    *    |                                     |                       |     N     |
+//! | [`is_sorted`](Sequence::is_sorted)                                |                *                |    *    |                                     |                       |     N     |
+//! | [`is_sorted_by_key`](Sequence::is_sorted_by_key)                  |                *                |    *    |                                     |                       |     N     |
+//! | [`is_subsequence_of`](Sequence::is_subsequence_of)                |                *                |    *    |                                     |                       |     N     |
+//! | [`levenshtein_distance`](Sequence::levenshtein_distance)          |                *                |    *    |                                     |                       |     N     |
+//! | [`longest_common_subsequence`](Sequence::longest_common_subsequence) |                *                |    *    |                                     |                       |     N     |
+//! | [`longest_increasing_subsequence`](Sequence::longest_increasing_subsequence) |                *                |    *    |                                     |                       |     N     |
+//! | [`longest_increasing_subsequence_by_key`](Sequence::longest_increasing_subsequence_by_key) |                *                |    *    |                                     |                       |     N     |
+//! | [`longest_run_by`](Sequence::longest_run_by)                      |                *                |    *    |                                     |                       |     N     |
+//! | [`par_frequencies`](Sequence::par_frequencies)                    |                *                |    *    |                                     |                       |     N     |
 //! | [`subset`](Collection::subset)                                    |                *                |    *    |                  *                  |           *           |     N     |
 //! | [`superset`](Collection::superset)                                |                *                |    *    |                  *                  |           *           |     N     |
 //!
@@ -186,36 +270,90 @@
 //!
 //! | Method / Collection type                                          | `Vec`, `VecDeque`, `LinkedList` | `Slice` | `HashSet`, `BTreeSet`, `BinaryHeap` | `HashMap`, `BTreeMap` | Consuming |
 //! |:-----------------------------------------------------------------:|:-------------------------------:|:-------:|:-----------------------------------:|:---------------------:|:---------:|
+//! | [`checked_product`](CollectionTo::checked_product)                |                *                |         |                  *                  |                       |     Y     |
+//! | [`checked_product_keys`](Map::checked_product_keys)               |                                 |         |                                     |           *           |     Y     |
+//! | [`checked_product_values`](Map::checked_product_values)           |                                 |         |                                     |           *           |     Y     |
+//! | [`checked_sum`](CollectionTo::checked_sum)                        |                *                |         |                  *                  |                       |     Y     |
+//! | [`checked_sum_keys`](Map::checked_sum_keys)                       |                                 |         |                                     |           *           |     Y     |
+//! | [`checked_sum_values`](Map::checked_sum_values)                   |                                 |         |                                     |           *           |     Y     |
+//! | [`covariance`](Sequence::covariance)                              |                *                |    *    |                                     |                       |     N     |
+//! | [`dot`](Sequence::dot)                                            |                *                |    *    |                                     |                       |     N     |
 //! | [`fold`](CollectionTo::fold)                                      |                *                |         |                  *                  |           *           |     Y     |
 //! | [`fold_ref`](Collection::fold_ref)                                |                *                |    *    |                  *                  |           *           |     N     |
 //! | [`group_fold`](CollectionTo::group_fold)                          |                *                |         |                  *                  |                       |     Y     |
 //! | [`group_fold_ref`](Collection::group_fold_ref)                    |                *                |    *    |                  *                  |                       |     N     |
+//! | [`group_fold_ref_with_hasher`](Collection::group_fold_ref_with_hasher) |                *                |    *    |                  *                  |                       |     N     |
+//! | [`group_fold_with_hasher`](CollectionTo::group_fold_with_hasher)  |                *                |         |                  *                  |                       |     Y     |
 //! | [`group_reduce`](CollectionTo::group_reduce)                      |                *                |         |                  *                  |                       |     Y     |
 //! | [`group_reduce_ref`](Collection::group_reduce_ref)                |                *                |    *    |                  *                  |                       |     N     |
+//! | [`group_reduce_ref_with_hasher`](Collection::group_reduce_ref_with_hasher) |                *                |    *    |                  *                  |                       |     N     |
+//! | [`group_reduce_with_hasher`](CollectionTo::group_reduce_with_hasher) |                *                |         |                  *                  |                       |     Y     |
+//! | [`mean`](Collection::mean)                                        |                *                |    *    |                  *                  |                       |     N     |
+//! | [`mean_absolute_error`](Sequence::mean_absolute_error)            |                *                |    *    |                                     |                       |     N     |
+//! | [`mean_by_key`](Collection::mean_by_key)                          |                *                |    *    |                  *                  |           *           |     N     |
+//! | [`mean_values`](Map::mean_values)                                 |                                 |         |                                     |           *           |     N     |
+//! | [`median`](Collection::median)                                    |                *                |    *    |                  *                  |                       |     N     |
+//! | [`median_by_key`](Collection::median_by_key)                      |                *                |    *    |                  *                  |           *           |     N     |
+//! | [`median_values`](Map::median_values)                             |                                 |         |                                     |           *           |     N     |
+//! | [`mode`](Collection::mode)                                        |                *                |    *    |                  *                  |                       |     N     |
+//! | [`mode_by_key`](Collection::mode_by_key)                          |                *                |    *    |                  *                  |           *           |     N     |
+//! | [`mode_values`](Map::mode_values)                                 |                                 |         |                                     |           *           |     N     |
+//! | [`pearson_correlation`](Sequence::pearson_correlation)            |                *                |    *    |                                     |                       |     N     |
+//! | [`percentiles`](Collection::percentiles)                          |                *                |    *    |                  *                  |                       |     N     |
+//! | [`percentiles_by_key`](Collection::percentiles_by_key)            |                *                |    *    |                  *                  |           *           |     N     |
+//! | [`percentiles_values`](Map::percentiles_values)                   |                                 |         |                                     |           *           |     N     |
+//! | [`precise_sum`](CollectionTo::precise_sum)                        |                *                |         |                  *                  |                       |     Y     |
+//! | [`precise_sum_values`](Map::precise_sum_values)                   |                                 |         |                                     |           *           |     Y     |
 //! | [`product`](CollectionTo::product)                                |                *                |         |                  *                  |                       |     Y     |
 //! | [`product_keys`](Map::product_keys)                               |                                 |         |                                     |           *           |     Y     |
 //! | [`product_values`](Map::product_values)                           |                                 |         |                                     |           *           |     Y     |
+//! | [`quantile`](Collection::quantile)                                |                *                |    *    |                  *                  |                       |     N     |
+//! | [`quantile_by_key`](Collection::quantile_by_key)                  |                *                |    *    |                  *                  |           *           |     N     |
+//! | [`quantile_values`](Map::quantile_values)                         |                                 |         |                                     |           *           |     N     |
 //! | [`reduce`](CollectionTo::reduce)                                  |                *                |         |                  *                  |           *           |     Y     |
 //! | [`reduce_ref`](Collection::reduce_ref)                            |                *                |    *    |                  *                  |           *           |     N     |
 //! | [`rfold`](SequenceTo::rfold)                                      |                *                |         |                                     |                       |     Y     |
 //! | [`rfold_ref`](Sequence::rfold_ref)                                |                *                |    *    |                                     |                       |     N     |
+//! | [`root_mean_squared_error`](Sequence::root_mean_squared_error)    |                *                |    *    |                                     |                       |     N     |
+//! | [`saturating_sum`](CollectionTo::saturating_sum)                  |                *                |         |                  *                  |                       |     Y     |
+//! | [`saturating_sum_keys`](Map::saturating_sum_keys)                 |                                 |         |                                     |           *           |     Y     |
+//! | [`saturating_sum_values`](Map::saturating_sum_values)             |                                 |         |                                     |           *           |     Y     |
+//! | [`spearman_correlation`](Sequence::spearman_correlation)          |                *                |    *    |                                     |                       |     N     |
+//! | [`std_dev`](Collection::std_dev)                                  |                *                |    *    |                  *                  |                       |     N     |
+//! | [`std_dev_by_key`](Collection::std_dev_by_key)                    |                *                |    *    |                  *                  |           *           |     N     |
+//! | [`std_dev_values`](Map::std_dev_values)                           |                                 |         |                                     |           *           |     N     |
 //! | [`sum`](CollectionTo::sum)                                        |                *                |         |                  *                  |                       |     Y     |
 //! | [`sum_keys`](Map::sum_keys)                                       |                                 |         |                                     |           *           |     Y     |
 //! | [`sum_values`](Map::sum_values)                                   |                                 |         |                                     |           *           |     Y     |
+//! | [`summary`](Collection::summary)                                  |                *                |    *    |                  *                  |                       |     N     |
+//! | [`summary_by_key`](Collection::summary_by_key)                    |                *                |    *    |                  *                  |           *           |     N     |
+//! | [`summary_values`](Map::summary_values)                           |                                 |         |                                     |           *           |     N     |
+//! | [`try_fold`](CollectionTo::try_fold)                              |                *                |         |                  *                  |           *           |     Y     |
+//! | [`try_fold_ref`](Collection::try_fold_ref)                        |                *                |    *    |                  *                  |           *           |     N     |
+//! | [`variance`](Collection::variance)                                |                *                |    *    |                  *                  |                       |     N     |
+//! | [`variance_by_key`](Collection::variance_by_key)                  |                *                |    *    |                  *                  |           *           |     N     |
+//! | [`variance_values`](Map::variance_values)                         |                                 |         |                                     |           *           |     N     |
 //!
 //! ### Selecting
 //!
 //! | Method / Collection type                                          | `Vec`, `VecDeque`, `LinkedList` | `Slice` | `HashSet`, `BTreeSet`, `BinaryHeap` | `HashMap`, `BTreeMap` | Consuming |
 //! |:-----------------------------------------------------------------:|:-------------------------------:|:-------:|:-----------------------------------:|:---------------------:|:---------:|
 //! | [`chunked`](SequenceTo::chunked)                                  |                *                |         |                                     |                       |     Y     |
+//! | [`chunked_array`](Sequence::chunked_array)                        |                *                |    *    |                                     |                       |     N     |
 //! | [`chunked_by`](SequenceTo::chunked_by)                            |                *                |         |                                     |                       |     Y     |
 //! | [`chunked_exact`](SequenceTo::chunked_exact)                      |                *                |         |                                     |                       |     Y     |
 //! | [`cartesian_product`](SequenceTo::cartesian_product)              |                *                |         |                                     |                       |     N     |
+//! | [`cartesian_product_iter`](SequenceTo::cartesian_product_iter)    |                *                |         |                                     |                       |     N     |
 //! | [`combinations`](CollectionTo::combinations)                      |                *                |         |                  *                  |                       |     N     |
+//! | [`combinations_iter`](CollectionTo::combinations_iter)            |                *                |         |                  *                  |                       |     N     |
 //! | [`combinations_multi`](SequenceTo::combinations_multi)            |                *                |         |                                     |                       |     N     |
+//! | [`combinations_multi_iter`](SequenceTo::combinations_multi_iter)  |                *                |         |                                     |                       |     N     |
 //! | [`powerset`](CollectionTo::powerset)                              |                *                |         |                  *                  |                       |     N     |
+//! | [`powerset_iter`](CollectionTo::powerset_iter)                    |                *                |         |                  *                  |                       |     N     |
 //! | [`variations`](SequenceTo::variations)                            |                *                |         |                                     |                       |     N     |
+//! | [`variations_iter`](SequenceTo::variations_iter)                  |                *                |         |                                     |                       |     N     |
 //! | [`windowed`](SequenceTo::windowed)                                |                *                |         |                                     |                       |     N     |
+//! | [`windowed_array`](Sequence::windowed_array)                      |                *                |    *    |                                     |                       |     N     |
 //! | [`windowed_circular`](SequenceTo::windowed_circular)              |                *                |         |                                     |                       |     N     |
 //!
 //! ### Partitioning
@@ -224,11 +362,17 @@
 //! |:-----------------------------------------------------------------:|:-------------------------------:|:-------:|:-----------------------------------:|:---------------------:|:---------:|
 //! | [`divide`](SequenceTo::divide)                                    |                *                |         |                                     |                       |     Y     |
 //! | [`divide_by`](SequenceTo::divide_by)                              |                *                |         |                                     |                       |     Y     |
+//! | [`divide_sequence`](SequenceTo::divide_sequence)                  |                *                |         |                                     |                       |     Y     |
 //! | [`group_by`](CollectionTo::group_by)                              |                *                |         |                  *                  |                       |     Y     |
+//! | [`group_by_with_hasher`](CollectionTo::group_by_with_hasher)      |                *                |         |                  *                  |                       |     Y     |
+//! | [`par_group_by`](CollectionTo::par_group_by)                      |                *                |         |                  *                  |                       |     Y     |
 //! | [`partition`](CollectionTo::partition)                            |                *                |         |                  *                  |           *           |     Y     |
+//! | [`partition_results`](CollectionTo::partition_results)            |                *                |         |                  *                  |           *           |     Y     |
 //! | [`partitions`](CollectionTo::partitions)                          |                *                |         |                  *                  |                       |     N     |
+//! | [`partitions_iter`](CollectionTo::partitions_iter)                |                *                |         |                  *                  |                       |     N     |
 //! | [`partition_map`](CollectionTo::partition_map)                    |                *                |         |                  *                  |           *           |     Y     |
 //! | [`partition_map_ref`](CollectionTo::partition_map_ref)            |                *                |         |                  *                  |           *           |     N     |
+//! | [`try_group_by`](CollectionTo::try_group_by)                      |                *                |         |                  *                  |                       |     Y     |
 //! | [`unzip`](SequenceTo::unzip)                                      |                *                |         |                                     |                       |     Y     |
 //!
 //! ### Merging
@@ -250,10 +394,18 @@
 //!
 //! | Method / Collection type                                          | `Vec`, `VecDeque`, `LinkedList` | `Slice` | `HashSet`, `BTreeSet`, `BinaryHeap` | `HashMap`, `BTreeMap` | Consuming |
 //! |:-----------------------------------------------------------------:|:-------------------------------:|:-------:|:-----------------------------------:|:---------------------:|:---------:|
+//! | [`par_sorted`](SequenceTo::par_sorted)                            |                *                |         |                                     |                       |     Y     |
+//! | [`par_sorted_by`](SequenceTo::par_sorted_by)                      |                *                |         |                                     |                       |     Y     |
+//! | [`par_sorted_by_key`](SequenceTo::par_sorted_by_key)              |                *                |         |                                     |                       |     Y     |
+//! | [`select_nth`](SequenceTo::select_nth)                            |                *                |         |                                     |                       |     Y     |
 //! | [`sorted`](SequenceTo::sorted)                                    |                *                |         |                                     |                       |     Y     |
 //! | [`sorted_by`](SequenceTo::sorted_by)                              |                *                |         |                                     |                       |     Y     |
 //! | [`sorted_by_cached_key`](SequenceTo::sorted_by_cached_key)        |                *                |         |                                     |                       |     Y     |
 //! | [`sorted_by_key`](SequenceTo::sorted_by_key)                      |                *                |         |                                     |                       |     Y     |
+//! | [`sorted_by_key_mut`](SequenceMut::sorted_by_key_mut)             |                *                |         |                                     |                       |     N     |
+//! | [`sorted_by_mut`](SequenceMut::sorted_by_mut)                     |                *                |         |                                     |                       |     N     |
+//! | [`sorted_floats`](SequenceTo::sorted_floats)                      |                *                |         |                                     |                       |     Y     |
+//! | [`sorted_mut`](SequenceMut::sorted_mut)                           |                *                |         |                                     |                       |     N     |
 //! | [`sorted_unstable`](SequenceTo::sorted_unstable)                  |                *                |         |                                     |                       |     Y     |
 //! | [`sorted_unstable_by`](SequenceTo::sorted_unstable_by)            |                *                |         |                                     |                       |     Y     |
 //! | [`sorted_unstable_by_key`](SequenceTo::sorted_unstable_by_key)    |                *                |         |                                     |                       |     Y     |
@@ -269,7 +421,9 @@
 //! | [`to_keys`](Map::to_keys)                                         |                                 |         |                                     |           *           |     Y     |
 //! | [`to_list`](Convert::to_list)                                     |                *                |         |                  *                  |           *           |     Y     |
 //! | [`to_map`](Convert::to_map)                                       |                *                |         |                  *                  |           *           |     Y     |
+//! | [`to_map_with_hasher`](Convert::to_map_with_hasher)               |                *                |         |                  *                  |           *           |     Y     |
 //! | [`to_set`](Convert::to_set)                                       |                *                |         |                  *                  |           *           |     Y     |
+//! | [`to_set_with_hasher`](Convert::to_set_with_hasher)               |                *                |         |                  *                  |           *           |     Y     |
 //! | [`to_values`](Map::to_values)                                     |                                 |         |                                     |           *           |     Y     |
 //! | [`to_vec`](Convert::to_vec)                                       |                *                |         |                  *                  |           *           |     Y     |
 //! | [`to_deque`](Convert::to_deque)                                   |                *                |         |                  *                  |           *           |     Y     |
@@ -280,13 +434,32 @@
 //! |:-----------------------------------------------------------------:|:-------------------------------:|:-------:|:-----------------------------------:|:---------------------:|:---------:|
 //! | [`fill`](SequenceTo::fill)                                        |                *                |         |                                     |                       |     Y     |
 //! | [`fill_with`](CollectionTo::fill_with)                            |                *                |         |                  *                  |           *           |     Y     |
+//! | [`fill_with_hasher`](CollectionTo::fill_with_hasher)              |                                 |         |                  *                  |           *           |     Y     |
 //! | [`for_each`](Collection::for_each)                                |                *                |    *    |                  *                  |           *           |     N     |
 //! | [`repeat`](List::repeat)                                          |                *                |         |                                     |                       |           |
 //! | [`unit`](CollectionTo::unit)                                      |                *                |         |                  *                  |           *           |     Y     |
+//! | [`unit_with_hasher`](CollectionTo::unit_with_hasher)              |                                 |         |                  *                  |           *           |     Y     |
 pub(crate) mod collections;
 pub(crate) mod core;
 pub(crate) mod extensions;
 
+pub use core::arithmetic::{Float, Integer, Number};
+pub use core::constructible::{Constructible, HasherConstructible};
+pub use core::diff::DiffHunk;
 pub use core::iterable::*;
+pub use core::ordering::{NanPolicy, TotalOrder};
+pub use core::parallel::Parallelism;
+pub use core::statistics::{LengthMismatch, Summary};
 
 pub use extensions::*;
+
+/// Iterator extensions which have to be imported explicitly.
+///
+/// ```
+/// use cantrip::traversal::Traversal;
+///
+/// assert_eq!((1..=5).chunked(2).collect::<Vec<_>>(), vec![vec![1, 2], vec![3, 4], vec![5]]);
+/// ```
+pub mod traversal {
+  pub use crate::extensions::traversal::Traversal;
+}
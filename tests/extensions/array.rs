@@ -0,0 +1,74 @@
+use cantrip::{Array, Collection, Sequence};
+
+pub(crate) fn test_array() {
+  let a_source = [1_i64, 2, 3];
+  let b_source = [1_i64, 2, 2, 3];
+  let e_source: [i64; 0] = [];
+
+  // map_ref
+  assert_eq!(a_source.map_ref(|&x| x + 1), [2, 3, 4]);
+  assert_eq!(e_source.map_ref(|&x| x + 1), []);
+
+  // rev
+  assert_eq!(a_source.rev(), [3, 2, 1]);
+  assert_eq!(e_source.rev(), []);
+
+  // scan
+  assert_eq!(
+    a_source.scan(1, |state, x| {
+      *state *= x;
+      -*state
+    }),
+    [-1, -2, -6]
+  );
+  assert_eq!(e_source.scan(1, |state, x| *state * x), []);
+
+  // scan_ref
+  assert_eq!(
+    a_source.scan_ref(1, |state, &x| {
+      *state *= x;
+      -*state
+    }),
+    [-1, -2, -6]
+  );
+  assert_eq!(e_source.scan_ref(1, |state, &x| *state * x), []);
+
+  // sorted
+  assert_eq!([2, 3, 1].sorted(), [1, 2, 3]);
+  assert_eq!(e_source.sorted(), []);
+
+  // sorted_by
+  assert_eq!([2, 3, 1].sorted_by(|x, y| y.cmp(x)), [3, 2, 1]);
+  assert_eq!(e_source.sorted_by(|x, y| y.cmp(x)), []);
+
+  // sorted_by_key
+  assert_eq!([2, 3, 1].sorted_by_key(|&k| -k), [3, 2, 1]);
+  assert_eq!(e_source.sorted_by_key(|&k| -k), []);
+
+  // sorted_unstable
+  assert_eq!([2, 3, 1].sorted_unstable(), [1, 2, 3]);
+  assert_eq!(e_source.sorted_unstable(), []);
+
+  // sorted_unstable_by
+  assert_eq!([2, 3, 1].sorted_unstable_by(|x, y| y.cmp(x)), [3, 2, 1]);
+  assert_eq!(e_source.sorted_unstable_by(|x, y| y.cmp(x)), []);
+
+  // sorted_unstable_by_key
+  assert_eq!([2, 3, 1].sorted_unstable_by_key(|&k| -k), [3, 2, 1]);
+  assert_eq!(e_source.sorted_unstable_by_key(|&k| -k), []);
+
+  // substitute_at
+  assert_eq!(a_source.substitute_at(1, 4), [1, 4, 3]);
+  assert_eq!(b_source.substitute_at(3, 4), [1, 2, 2, 4]);
+
+  // swap_at
+  assert_eq!(a_source.swap_at(0, 2), [3, 2, 1]);
+  assert_eq!(a_source.swap_at(1, 1), [1, 2, 3]);
+
+  // collection and sequence
+  assert_eq!(a_source.fold_ref(0, |acc, x| acc + x), 6);
+  assert_eq!(b_source.count_unique(), 3);
+  assert_eq!(b_source.chunked_array::<2>(), vec![[1, 2], [2, 3]]);
+  assert_eq!(a_source.windowed_array::<2>(), vec![[1, 2], [2, 3]]);
+  assert_eq!(e_source.rposition(|&x| x == 5), None);
+}
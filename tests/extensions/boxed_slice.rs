@@ -0,0 +1,77 @@
+use std::borrow::Cow;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use cantrip::{CollectionTo, Sequence, SequenceTo};
+
+use crate::extensions::collection::test_collection;
+use crate::extensions::sequence::test_sequence;
+
+pub(crate) fn test_boxed_slice() {
+  let a_source: Box<[i64]> = Box::new([1, 2, 3]);
+  let b_source: Box<[i64]> = Box::new([1, 2, 2, 3]);
+  let e_source: Box<[i64]> = Box::new([]);
+  test_collection(true, &a_source, &b_source, &e_source);
+  test_sequence(&a_source, &b_source, &e_source);
+
+  // add
+  let a = a_source.clone();
+  assert_eq!(a.add(3), Box::from([1, 2, 3, 3]));
+
+  // add_at
+  let a = a_source.clone();
+  assert_eq!(a.add_at(0, 3), Box::from([3, 1, 2, 3]));
+
+  // delete
+  let b = b_source.clone();
+  assert_eq!(b.delete(&2), Box::from([1, 2, 3]));
+
+  // delete_at_multi
+  let a = a_source.clone();
+  assert_eq!(a.delete_at_multi(vec![0, 2]), Box::from([2]));
+
+  // init
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_eq!(a.init(), Box::from([1, 2]));
+  assert_eq!(e.init(), Box::from([]));
+
+  // map
+  let a = a_source.clone();
+  assert_eq!(a.map(|x| x + 1), Box::from([2, 3, 4]));
+
+  // move_at
+  let a = a_source.clone();
+  assert_eq!(a.move_at(0, 2), Box::from([2, 3, 1]));
+
+  // rev
+  let a = a_source.clone();
+  assert_eq!(a.rev(), Box::from([3, 2, 1]));
+
+  // sorted_by
+  let a = a_source.clone();
+  assert_eq!(a.sorted_by(|x, y| y.cmp(x)), Box::from([3, 2, 1]));
+
+  // substitute_at_multi
+  let a = a_source.clone();
+  assert_eq!(a.substitute_at_multi(vec![0, 2], vec![4, 5]), Box::from([4, 2, 5]));
+
+  // swap_at
+  let a = a_source.clone();
+  assert_eq!(a.swap_at(0, 2), Box::from([3, 2, 1]));
+
+  // tail
+  let a = a_source.clone();
+  let e = e_source;
+  assert_eq!(a.tail(), Box::from([2, 3]));
+  assert_eq!(e.tail(), Box::from([]));
+
+  // shared slices
+  let b_shared: Rc<[i64]> = Rc::from([1, 2, 2, 3]);
+  let b_atomic: Arc<[i64]> = Arc::from([1, 2, 2, 3]);
+  let b_borrowed: Cow<'_, [i64]> = Cow::Borrowed(&[1, 2, 2, 3]);
+  assert!(a_source.equivalent(&Rc::<[i64]>::from([3, 2, 1])));
+  assert_eq!(b_source.common_prefix_length(&b_shared), 4);
+  assert_eq!(b_source.common_suffix_length(&b_atomic), 4);
+  assert_eq!(b_source.position_sequence(&b_borrowed), Some(0));
+}
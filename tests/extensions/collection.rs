@@ -1,8 +1,11 @@
 use std::collections::HashMap;
 use std::fmt::Debug;
+use std::hash::{BuildHasherDefault, DefaultHasher};
 
-use cantrip::{Collection, Iterable};
+use cantrip::{Collection, Iterable, NanPolicy, Summary};
 
+#[allow(clippy::cast_precision_loss)]
+#[allow(clippy::too_many_lines)]
 pub(crate) fn test_collection<'a, C>(sequence: bool, a: &C, b: &C, e: &C)
 where
   C: Collection<i64> + Iterable<Item<'a> = &'a i64> + Debug + ?Sized + 'a,
@@ -30,6 +33,18 @@ where
   assert!(!a.disjoint(&vec![3, 4]));
   assert!(e.disjoint(&vec![3, 4]));
 
+  // disjoint_eq
+  assert!(a.disjoint_eq(&vec![4, 5]));
+  assert!(a.disjoint_eq(&vec![]));
+  assert!(!a.disjoint_eq(&vec![3, 4]));
+  assert!(e.disjoint_eq(&vec![3, 4]));
+
+  // disjoint_ord
+  assert!(a.disjoint_ord(&vec![4, 5]));
+  assert!(a.disjoint_ord(&vec![]));
+  assert!(!a.disjoint_ord(&vec![3, 4]));
+  assert!(e.disjoint_ord(&vec![3, 4]));
+
   // find
   assert_eq!(a.find(|&x| x == 2), Some(&2));
   assert_eq!(a.find(|&x| x == 5), None);
@@ -54,10 +69,30 @@ where
   assert_eq!(a.group_fold_ref(|x| x % 2, 0, |acc, &x| acc + x), HashMap::from([(0, 2), (1, 4),]));
   assert_eq!(e.group_fold_ref(|x| x % 2, 0, |acc, &x| acc + x), HashMap::new());
 
+  // group_fold_ref_with_hasher
+  assert_eq!(
+    a.group_fold_ref_with_hasher(|x| x % 2, 0, |acc, &x| acc + x, BuildHasherDefault::<DefaultHasher>::default()),
+    HashMap::from_iter([(0, 2), (1, 4)])
+  );
+  assert_eq!(
+    e.group_fold_ref_with_hasher(|x| x % 2, 0, |acc, &x| acc + x, BuildHasherDefault::<DefaultHasher>::default()),
+    HashMap::default()
+  );
+
   // group_reduce_ref
   assert_eq!(a.group_reduce_ref(|x| x % 2, |acc, x| acc + x), HashMap::from([(0, 2), (1, 4),]));
   assert_eq!(e.group_reduce_ref(|x| x % 2, |acc, x| acc + x), HashMap::new());
 
+  // group_reduce_ref_with_hasher
+  assert_eq!(
+    a.group_reduce_ref_with_hasher(|x| x % 2, |acc, x| acc + x, BuildHasherDefault::<DefaultHasher>::default()),
+    HashMap::from_iter([(0, 2), (1, 4)])
+  );
+  assert_eq!(
+    e.group_reduce_ref_with_hasher(|x| x % 2, |acc, x| acc + x, BuildHasherDefault::<DefaultHasher>::default()),
+    HashMap::default()
+  );
+
   // max_by
   assert_eq!(b.max_by(i64::cmp), Some(&3));
   assert_eq!(e.max_by(i64::cmp), None);
@@ -70,6 +105,24 @@ where
   assert_eq!(b.max_of(), Some(&3));
   assert_eq!(e.max_of(), None);
 
+  // mean
+  assert_eq!(a.mean(), Some(2.0));
+  assert_eq!(e.mean(), None);
+
+  // mean_by_key
+  assert_eq!(a.mean_by_key(|&x| x as f64), Some(2.0));
+  assert_eq!(a.mean_by_key(|&x| (x * 1_000_000_000_000) as f64), Some(2_000_000_000_000.0));
+  assert_eq!(e.mean_by_key(|&x| x as f64), None);
+
+  // median
+  assert_eq!(a.median(), Some(2.0));
+  assert_eq!(e.median(), None);
+
+  // median_by_key
+  assert_eq!(a.median_by_key(|&x| x as f64), Some(2.0));
+  assert_eq!(a.median_by_key(|&x| -x as f64), Some(-2.0));
+  assert_eq!(e.median_by_key(|&x| x as f64), None);
+
   // min_by
   assert_eq!(b.min_by(i64::cmp), Some(&1));
   assert_eq!(e.min_by(i64::cmp), None);
@@ -94,10 +147,77 @@ where
   assert_eq!(b.minmax_of(), Some((&1, &3)));
   assert_eq!(e.minmax_of(), None);
 
+  // mode
+  assert_eq!(a.mode(), Some(1.0));
+  if sequence {
+    assert_eq!(b.mode(), Some(2.0));
+  }
+  assert_eq!(e.mode(), None);
+
+  // mode_by_key
+  assert_eq!(a.mode_by_key(|&x| x as f64), Some(1.0));
+  assert_eq!(a.mode_by_key(|&x| (x % 2) as f64), Some(1.0));
+  if sequence {
+    assert_eq!(b.mode_by_key(|&x| x as f64), Some(2.0));
+  }
+  assert_eq!(e.mode_by_key(|&x| x as f64), None);
+
+  // nth_by_key
+  assert_eq!(a.nth_by_key(0, |&x| -x), Some(&3));
+  assert_eq!(a.nth_by_key(2, |&x| -x), Some(&1));
+  assert_eq!(a.nth_by_key(3, |&x| -x), None);
+  assert_eq!(e.nth_by_key(0, |&x| -x), None);
+
+  // nth_largest
+  assert_eq!(a.nth_largest(0), Some(&3));
+  assert_eq!(a.nth_largest(1), Some(&2));
+  assert_eq!(a.nth_largest(3), None);
+  if sequence {
+    assert_eq!(b.nth_largest(2), Some(&2));
+  }
+  assert_eq!(e.nth_largest(0), None);
+
+  // nth_smallest
+  assert_eq!(a.nth_smallest(0), Some(&1));
+  assert_eq!(a.nth_smallest(2), Some(&3));
+  assert_eq!(a.nth_smallest(3), None);
+  if sequence {
+    assert_eq!(b.nth_smallest(2), Some(&2));
+  }
+  assert_eq!(e.nth_smallest(0), None);
+
+  // percentiles
+  assert_eq!(a.percentiles(vec![0.0, 50.0, 100.0]), Some(vec![1.0, 2.0, 3.0]));
+  assert_eq!(e.percentiles(vec![50.0]), None);
+
+  // percentiles_by_key
+  assert_eq!(a.percentiles_by_key(vec![0.0, 25.0, 50.0, 100.0], |&x| x as f64), Some(vec![1.0, 1.5, 2.0, 3.0]));
+  assert_eq!(a.percentiles_by_key(vec![], |&x| x as f64), Some(vec![]));
+  assert_eq!(e.percentiles_by_key(vec![50.0], |&x| x as f64), None);
+
+  // quantile
+  assert_eq!(a.quantile(0.25), Some(1.5));
+  assert_eq!(e.quantile(0.5), None);
+
+  // quantile_by_key
+  assert_eq!(a.quantile_by_key(0.0, |&x| x as f64), Some(1.0));
+  assert_eq!(a.quantile_by_key(0.75, |&x| x as f64), Some(2.5));
+  assert_eq!(a.quantile_by_key(1.0, |&x| x as f64), Some(3.0));
+  assert_eq!(e.quantile_by_key(0.5, |&x| x as f64), None);
+
   // reduce_ref
   assert_eq!(a.reduce_ref(|&acc, &e| acc + e), Some(6));
   assert_eq!(e.reduce_ref(|&acc, &e| acc + e), None);
 
+  // std_dev
+  assert_eq!(a.std_dev(), Some(1.0));
+  assert_eq!(e.std_dev(), None);
+
+  // std_dev_by_key
+  assert_eq!(a.std_dev_by_key(|&x| x as f64), Some(1.0));
+  assert_eq!(a.std_dev_by_key(|&x| (x * 2) as f64), Some(2.0));
+  assert_eq!(e.std_dev_by_key(|&x| x as f64), None);
+
   // subset
   assert!(a.subset(&vec![4, 3, 2, 2, 1]));
   assert!(e.subset(&vec![1]));
@@ -107,6 +227,36 @@ where
   }
   assert!(!a.subset(&vec![3, 4]));
 
+  // summary
+  assert_eq!(
+    a.summary(),
+    Some(Summary {
+      count: 3,
+      min: 1.0,
+      max: 3.0,
+      mean: 2.0,
+      lower_quartile: 1.5,
+      median: 2.0,
+      upper_quartile: 2.5
+    })
+  );
+  assert_eq!(e.summary(), None);
+
+  // summary_by_key
+  assert_eq!(
+    a.summary_by_key(|&x| x as f64),
+    Some(Summary {
+      count: 3,
+      min: 1.0,
+      max: 3.0,
+      mean: 2.0,
+      lower_quartile: 1.5,
+      median: 2.0,
+      upper_quartile: 2.5
+    })
+  );
+  assert_eq!(e.summary_by_key(|&x| x as f64), None);
+
   // superset
   assert!(a.superset(&vec![2, 1]));
   assert!(e.superset(&vec![]));
@@ -115,4 +265,45 @@ where
   }
   assert!(!a.superset(&vec![3, 4]));
   assert!(!e.superset(&vec![1]));
+
+  // try_fold_ref
+  assert_eq!(a.try_fold_ref(0, |acc, &x| if x < 4 { Ok(acc + x) } else { Err(x) }), Ok(6));
+  assert_eq!(a.try_fold_ref(0, |acc, &x| if x == 2 { Err(x) } else { Ok(acc + x) }), Err(2));
+  assert_eq!(e.try_fold_ref(0, |acc, &x| if x < 4 { Ok(acc + x) } else { Err(x) }), Ok(0));
+
+  // variance
+  assert_eq!(a.variance(), Some(1.0));
+  assert_eq!(e.variance(), None);
+
+  // variance_by_key
+  assert_eq!(a.variance_by_key(|&x| x as f64), Some(1.0));
+  assert_eq!(a.variance_by_key(|&x| (x + 1_000_000_000) as f64), Some(1.0));
+  assert_eq!(a.variance_by_key(|_| 1.0), Some(0.0));
+  assert_eq!(e.variance_by_key(|&x| x as f64), None);
+}
+
+pub(crate) fn test_collection_float<C>(a: &C, n: &C, e: &C)
+where
+  C: Collection<f64> + ?Sized,
+  for<'i> &'i C: IntoIterator<Item = &'i f64>,
+{
+  // max_of_float
+  assert_eq!(a.max_of_float(NanPolicy::Total), Some(&3.0));
+  assert!(n.max_of_float(NanPolicy::Total).is_some_and(|x| x.is_nan()));
+  assert_eq!(n.max_of_float(NanPolicy::Skip), Some(&2.0));
+  assert!(n.max_of_float(NanPolicy::Propagate).is_some_and(|x| x.is_nan()));
+  assert_eq!(e.max_of_float(NanPolicy::Total), None);
+
+  // min_of_float
+  assert_eq!(a.min_of_float(NanPolicy::Total), Some(&1.0));
+  assert_eq!(n.min_of_float(NanPolicy::Total), Some(&1.0));
+  assert_eq!(n.min_of_float(NanPolicy::Skip), Some(&1.0));
+  assert!(n.min_of_float(NanPolicy::Propagate).is_some_and(|x| x.is_nan()));
+  assert_eq!(e.min_of_float(NanPolicy::Skip), None);
+
+  // minmax_of_float
+  assert_eq!(a.minmax_of_float(NanPolicy::Total), Some((&1.0, &3.0)));
+  assert_eq!(n.minmax_of_float(NanPolicy::Skip), Some((&1.0, &2.0)));
+  assert!(n.minmax_of_float(NanPolicy::Propagate).is_some_and(|(x, y)| x.is_nan() && y.is_nan()));
+  assert_eq!(e.minmax_of_float(NanPolicy::Propagate), None);
 }
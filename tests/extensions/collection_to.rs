@@ -1,6 +1,7 @@
 use std::collections::{HashMap, HashSet, LinkedList};
+use std::hash::{BuildHasherDefault, DefaultHasher};
 
-use cantrip::CollectionTo;
+use cantrip::{CollectionTo, NanPolicy, Parallelism};
 
 use crate::extensions::util::{
   TestCollectible, TestCollection, assert_map_equal, assert_map_vec_equivalent, assert_seq_equal, assert_set_equal,
@@ -37,6 +38,22 @@ where
   }
   assert_seq_equal(&e.add(1), vec![1]);
 
+  // checked_product
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_eq!(a.checked_product(), Some(6));
+  let a = a_source.clone();
+  assert_eq!(a.add(i64::MAX).checked_product(), None);
+  assert_eq!(e.checked_product(), Some(1));
+
+  // checked_sum
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_eq!(a.checked_sum(), Some(6));
+  let a = a_source.clone();
+  assert_eq!(a.add(i64::MAX).checked_sum(), None);
+  assert_eq!(e.checked_sum(), Some(0));
+
   // collect
   let a = a_source.clone();
   let e = e_source.clone();
@@ -57,6 +74,18 @@ where
   assert_vec_seq_equivalent(a.combinations(4), vec![]);
   assert_vec_seq_equivalent(e.combinations(1), vec![]);
 
+  // combinations_iter
+  let a = a_source.clone();
+  let e = e_source.clone();
+  let mut combinations = a.combinations_iter(2);
+  assert_eq!(combinations.size_hint(), (3, Some(3)));
+  let _unused = combinations.next();
+  assert_eq!(combinations.size_hint(), (2, Some(2)));
+  assert_vec_seq_equivalent(a.combinations_iter(2).collect(), vec![vec![1, 2], vec![1, 3], vec![2, 3]]);
+  assert_eq!(a.combinations_iter(0).size_hint(), (1, Some(1)));
+  assert_eq!(a.combinations_iter(4).size_hint(), (0, Some(0)));
+  assert_eq!(e.combinations_iter(1).size_hint(), (0, Some(0)));
+
   // delete
   let a = a_source.clone();
   let b = b_source.clone();
@@ -93,6 +122,36 @@ where
   }
   assert_seq_equal(&e.delete_multi(&vec![1]), vec![]);
 
+  // delete_multi_eq
+  let a = a_source.clone();
+  let b = b_source.clone();
+  let e = e_source.clone();
+  if sequence {
+    assert_seq_equal(&b.delete_multi_eq(&vec![1, 2]), vec![2, 3]);
+    let b = b_source.clone();
+    assert_seq_equal(&b.delete_multi_eq(&vec![4]), vec![1, 2, 2, 3]);
+  } else {
+    assert_seq_equal(&a.delete_multi_eq(&vec![1, 2]), vec![3]);
+    let a = a_source.clone();
+    assert_seq_equal(&a.delete_multi_eq(&vec![4]), vec![1, 2, 3]);
+  }
+  assert_seq_equal(&e.delete_multi_eq(&vec![1]), vec![]);
+
+  // delete_multi_ord
+  let a = a_source.clone();
+  let b = b_source.clone();
+  let e = e_source.clone();
+  if sequence {
+    assert_seq_equal(&b.delete_multi_ord(&vec![1, 2]), vec![2, 3]);
+    let b = b_source.clone();
+    assert_seq_equal(&b.delete_multi_ord(&vec![4]), vec![1, 2, 2, 3]);
+  } else {
+    assert_seq_equal(&a.delete_multi_ord(&vec![1, 2]), vec![3]);
+    let a = a_source.clone();
+    assert_seq_equal(&a.delete_multi_ord(&vec![4]), vec![1, 2, 3]);
+  }
+  assert_seq_equal(&e.delete_multi_ord(&vec![1]), vec![]);
+
   // fill_with
   let b = b_source.clone();
   if sequence {
@@ -163,18 +222,49 @@ where
   }
   assert_map_vec_equivalent(e.group_by(|x| x % 2), HashMap::new());
 
+  // group_by_with_hasher
+  let a = a_source.clone();
+  let e = e_source.clone();
+  let grouped = a.group_by_with_hasher(|x| x % 2, BuildHasherDefault::<DefaultHasher>::default());
+  assert_map_vec_equivalent(grouped.into_iter().collect(), HashMap::from([(0, vec![2]), (1, vec![1, 3])]));
+  assert!(e.group_by_with_hasher(|x| x % 2, BuildHasherDefault::<DefaultHasher>::default()).is_empty());
+
   // group_fold
   let a = a_source.clone();
   let e = e_source.clone();
   assert_map_equal(&a.group_fold(|x| x % 2, 0, |acc, x| acc + x), HashMap::from([(0, 2), (1, 4)]));
   assert_map_equal(&e.group_fold(|x| x % 2, 0, |acc, x| acc + x), HashMap::new());
 
+  // group_fold_with_hasher
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_eq!(
+    a.group_fold_with_hasher(|x| x % 2, 0, |acc, x| acc + x, BuildHasherDefault::<DefaultHasher>::default()),
+    HashMap::from_iter([(0, 2), (1, 4)])
+  );
+  assert_eq!(
+    e.group_fold_with_hasher(|x| x % 2, 0, |acc, x| acc + x, BuildHasherDefault::<DefaultHasher>::default()),
+    HashMap::default()
+  );
+
   // group_reduce
   let a = a_source.clone();
   let e = e_source.clone();
   assert_eq!(a.group_reduce(|x| x % 2, |acc, x| acc + x), HashMap::from([(0, 2), (1, 4),]));
   assert_eq!(e.group_reduce(|x| x % 2, |acc, x| acc + x), HashMap::new());
 
+  // group_reduce_with_hasher
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_eq!(
+    a.group_reduce_with_hasher(|x| x % 2, |acc, x| acc + x, BuildHasherDefault::<DefaultHasher>::default()),
+    HashMap::from_iter([(0, 2), (1, 4)])
+  );
+  assert_eq!(
+    e.group_reduce_with_hasher(|x| x % 2, |acc, x| acc + x, BuildHasherDefault::<DefaultHasher>::default()),
+    HashMap::default()
+  );
+
   // intersect
   let a = a_source.clone();
   let e = e_source.clone();
@@ -185,6 +275,28 @@ where
   }
   assert_seq_equal(&e.intersect(&vec![1]), vec![]);
 
+  // intersect_eq
+  let a = a_source.clone();
+  let b = b_source.clone();
+  let e = e_source.clone();
+  if sequence {
+    assert_seq_equal(&b.intersect_eq(&vec![4, 3, 2, 2, 5]), vec![2, 2, 3]);
+  } else {
+    assert_seq_equal(&a.intersect_eq(&vec![4, 3, 2, 2, 5]), vec![2, 3]);
+  }
+  assert_seq_equal(&e.intersect_eq(&vec![1]), vec![]);
+
+  // intersect_ord
+  let a = a_source.clone();
+  let b = b_source.clone();
+  let e = e_source.clone();
+  if sequence {
+    assert_seq_equal(&b.intersect_ord(&vec![4, 3, 2, 2, 5]), vec![2, 2, 3]);
+  } else {
+    assert_seq_equal(&a.intersect_ord(&vec![4, 3, 2, 2, 5]), vec![2, 3]);
+  }
+  assert_seq_equal(&e.intersect_ord(&vec![1]), vec![]);
+
   // largest
   let a = a_source.clone();
   let e = e_source.clone();
@@ -193,6 +305,28 @@ where
   assert_seq_equal(&a.largest(4), vec![3, 2, 1]);
   assert_seq_equal(&e.largest(3), vec![]);
 
+  // largest_by
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_seq_equal(&a.largest_by(2, Ord::cmp), vec![3, 2]);
+  let a = a_source.clone();
+  assert_seq_equal(&a.largest_by(4, |x, y| y.cmp(x)), vec![1, 2, 3]);
+  let a = a_source.clone();
+  assert_seq_equal(&a.largest_by(0, Ord::cmp), vec![]);
+  assert_seq_equal(&e.largest_by(3, Ord::cmp), vec![]);
+
+  // largest_by_key
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_seq_equal(&a.largest_by_key(2, |&x| x), vec![3, 2]);
+  let a = a_source.clone();
+  assert_seq_equal(&a.largest_by_key(4, |&x| -x), vec![1, 2, 3]);
+  if sequence {
+    let b = b_source.clone();
+    assert_seq_equal(&b.largest_by_key(2, |&x| x % 2), vec![1, 3]);
+  }
+  assert_seq_equal(&e.largest_by_key(3, |&x| x), vec![]);
+
   // map
   let a = a_source.clone();
   let e = e_source.clone();
@@ -205,7 +339,53 @@ where
   assert_seq_equal(&a.map_ref(|&x| x + 1), vec![2, 3, 4]);
   assert_seq_equal(&e.map_ref(|&x| x + 1), vec![]);
 
+  // map_validated
+  let a = a_source.clone();
+  let b = b_source.clone();
+  let e = e_source.clone();
+  assert_seq_equal(&a.map_validated(|x| if x < 4 { Ok(x + 1) } else { Err(x) }).unwrap(), vec![2, 3, 4]);
+  if sequence {
+    assert_seq_equal(&b.map_validated(|x| if x < 2 { Ok(x) } else { Err(x) }).unwrap_err(), vec![2, 2, 3]);
+  } else {
+    let a = a_source.clone();
+    assert_seq_equal(&a.map_validated(|x| if x < 2 { Ok(x) } else { Err(x) }).unwrap_err(), vec![2, 3]);
+  }
+  assert_seq_equal(&e.map_validated(|x| if x < 4 { Ok(x + 1) } else { Err(x) }).unwrap(), vec![]);
+
+  // par_filter
+  let a = a_source.clone();
+  let e = e_source.clone();
+  let parallelism = Parallelism { threads: 2, threshold: 0 };
+  assert_seq_equal(&a.par_filter(parallelism, |&x| x != 2), vec![1, 3]);
+  assert_seq_equal(&e.par_filter(parallelism, |&x| x != 2), vec![]);
+  let a = a_source.clone();
+  assert_seq_equal(&a.par_filter(Parallelism::default(), |&x| x != 2), vec![1, 3]);
+
+  // par_group_by
+  let e = e_source.clone();
+  if sequence {
+    let b = b_source.clone();
+    assert_map_vec_equivalent(
+      b.par_group_by(parallelism, |x| x % 2),
+      HashMap::from([(0, vec![2, 2]), (1, vec![1, 3])]),
+    );
+  } else {
+    let a = a_source.clone();
+    assert_map_vec_equivalent(a.par_group_by(parallelism, |x| x % 2), HashMap::from([(0, vec![2]), (1, vec![1, 3])]));
+  }
+  assert_map_vec_equivalent(e.par_group_by(parallelism, |x| x % 2), HashMap::new());
+
+  // par_map
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_seq_equal(&a.par_map(parallelism, |x| x + 1), vec![2, 3, 4]);
+  assert_seq_equal(&e.par_map(parallelism, |x| x + 1), vec![]);
+  let a = a_source.clone();
+  assert_seq_equal(&a.par_map(Parallelism { threads: 5, threshold: 0 }, |x| x + 1), vec![2, 3, 4]);
+
   // partition
+  let a = a_source.clone();
+  let e = e_source.clone();
   let (a_even, a_odd) = a.partition(|n| n % 2 == 0);
   assert_seq_equal(&a_even, vec![2]);
   assert_seq_equal(&a_odd, vec![1, 3]);
@@ -232,6 +412,16 @@ where
   }
   assert!(e.partitions().is_empty());
 
+  // partitions_iter
+  let a = a_source.clone();
+  let e = e_source.clone();
+  let mut partitions = a.partitions_iter();
+  assert_eq!(partitions.size_hint(), (5, Some(5)));
+  let _unused = partitions.next();
+  assert_eq!(partitions.size_hint(), (4, Some(4)));
+  assert_eq!(a.partitions_iter().map(|x| x.len()).sum::<usize>(), 10);
+  assert_eq!(e.partitions_iter().size_hint(), (0, Some(0)));
+
   // partition_map
   let a = a_source.clone();
   let e = e_source.clone();
@@ -252,6 +442,14 @@ where
   assert_seq_equal(&e_even, vec![]);
   assert_seq_equal(&e_odd, vec![]);
 
+  // partition_results
+  let (values, errors) = vec![Ok(1), Err(2), Ok(3), Err(4)].partition_results();
+  assert_eq!(values, vec![1, 3]);
+  assert_eq!(errors, vec![2, 4]);
+  let (values, errors) = Vec::<Result<i64, i64>>::new().partition_results();
+  assert_eq!(values, vec![]);
+  assert_eq!(errors, vec![]);
+
   // powerset
   assert_vec_seq_equivalent(
     a.powerset(),
@@ -259,6 +457,17 @@ where
   );
   assert_vec_seq_equivalent(e.powerset(), vec![vec![]]);
 
+  // powerset_iter
+  let mut powerset = a.powerset_iter();
+  assert_eq!(powerset.size_hint(), (8, Some(8)));
+  let _unused = powerset.next();
+  assert_eq!(powerset.size_hint(), (7, Some(7)));
+  assert_vec_seq_equivalent(
+    a.powerset_iter().collect(),
+    vec![vec![], vec![1], vec![2], vec![3], vec![1, 2], vec![1, 3], vec![2, 3], vec![1, 2, 3]],
+  );
+  assert_vec_seq_equivalent(e.powerset_iter().collect(), vec![vec![]]);
+
   // product
   let a = a_source.clone();
   let b = b_source.clone();
@@ -276,6 +485,18 @@ where
   assert_eq!(a.reduce(|acc, e| acc + e), Some(6));
   assert_eq!(e.reduce(|acc, e| acc + e), None);
 
+  // saturating_sum
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_eq!(a.saturating_sum(), 6);
+  let a = a_source.clone();
+  assert_eq!(a.add(i64::MAX).saturating_sum(), i64::MAX);
+  let a = a_source.clone();
+  assert_eq!(a.add_multi([i64::MAX, i64::MAX - 1, i64::MIN]).saturating_sum(), i64::MAX);
+  let a = a_source.clone();
+  assert_eq!(a.add_multi([i64::MAX, i64::MAX - 1, i64::MIN, i64::MIN + 1]).saturating_sum(), 4);
+  assert_eq!(e.saturating_sum(), 0);
+
   // smallest
   let a = a_source.clone();
   let e = e_source.clone();
@@ -283,11 +504,31 @@ where
   let a = a_source.clone();
   assert_seq_equal(&a.smallest(4), vec![1, 2, 3]);
   assert_seq_equal(&e.smallest(3), vec![]);
+
+  // smallest_by
   let a = a_source.clone();
-  let b = b_source.clone();
   let e = e_source.clone();
+  assert_seq_equal(&a.smallest_by(2, Ord::cmp), vec![1, 2]);
+  let a = a_source.clone();
+  assert_seq_equal(&a.smallest_by(4, |x, y| y.cmp(x)), vec![3, 2, 1]);
+  assert_seq_equal(&e.smallest_by(3, Ord::cmp), vec![]);
+
+  // smallest_by_key
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_seq_equal(&a.smallest_by_key(2, |&x| x), vec![1, 2]);
+  let a = a_source.clone();
+  assert_seq_equal(&a.smallest_by_key(4, |&x| -x), vec![3, 2, 1]);
+  if sequence {
+    let b = b_source.clone();
+    assert_seq_equal(&b.smallest_by_key(3, |&x| x % 2), vec![2, 2, 1]);
+  }
+  assert_seq_equal(&e.smallest_by_key(3, |&x| x), vec![]);
 
   // substitute
+  let a = a_source.clone();
+  let b = b_source.clone();
+  let e = e_source.clone();
   if sequence {
     assert_seq_equal(&b.substitute(&2, 4), vec![1, 4, 2, 3]);
     let b = b_source.clone();
@@ -333,6 +574,90 @@ where
   }
   assert_eq!(e.sum(), 0);
 
+  // try_filter
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_seq_equal(&a.try_filter(|&x| if x < 4 { Ok(x != 2) } else { Err(x) }).unwrap(), vec![1, 3]);
+  let a = a_source.clone();
+  assert_eq!(a.try_filter(|&x| if x == 2 { Err(x) } else { Ok(true) }).err(), Some(2));
+  assert_seq_equal(&e.try_filter(|&x| if x < 4 { Ok(x != 2) } else { Err(x) }).unwrap(), vec![]);
+
+  // try_filter_ref
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_seq_equal(&a.try_filter_ref(|&x| if x < 4 { Ok(x != 2) } else { Err(x) }).unwrap(), vec![1, 3]);
+  assert_eq!(a.try_filter_ref(|&x| if x == 2 { Err(x) } else { Ok(true) }).err(), Some(2));
+  assert_seq_equal(&e.try_filter_ref(|&x| if x < 4 { Ok(x != 2) } else { Err(x) }).unwrap(), vec![]);
+
+  // try_fold
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_eq!(a.try_fold(0, |acc, x| if x < 4 { Ok(acc + x) } else { Err(x) }), Ok(6));
+  let a = a_source.clone();
+  assert_eq!(a.try_fold(0, |acc, x| if x == 2 { Err(x) } else { Ok(acc + x) }), Err(2));
+  assert_eq!(e.try_fold(0, |acc, x| if x < 4 { Ok(acc + x) } else { Err(x) }), Ok(0));
+
+  // try_group_by
+  let e = e_source.clone();
+  if sequence {
+    let b = b_source.clone();
+    assert_map_vec_equivalent(
+      b.try_group_by(|x| if *x < 4 { Ok(x % 2) } else { Err(*x) }).unwrap(),
+      HashMap::from([(0, vec![2, 2]), (1, vec![1, 3])]),
+    );
+  } else {
+    let a = a_source.clone();
+    assert_map_vec_equivalent(
+      a.try_group_by(|x| if *x < 4 { Ok(x % 2) } else { Err(*x) }).unwrap(),
+      HashMap::from([(0, vec![2]), (1, vec![1, 3])]),
+    );
+  }
+  let a = a_source.clone();
+  assert_eq!(a.try_group_by(|x| if *x == 2 { Err(*x) } else { Ok(x % 2) }).err(), Some(2));
+  assert_map_vec_equivalent(e.try_group_by(|x| if *x < 4 { Ok(x % 2) } else { Err(*x) }).unwrap(), HashMap::new());
+
+  // try_map
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_seq_equal(&a.try_map(|x| if x < 4 { Ok(x + 1) } else { Err(x) }).unwrap(), vec![2, 3, 4]);
+  let a = a_source.clone();
+  assert_eq!(a.try_map(|x| if x == 2 { Err(x) } else { Ok(x + 1) }).err(), Some(2));
+  assert_seq_equal(&e.try_map(|x| if x < 4 { Ok(x + 1) } else { Err(x) }).unwrap(), vec![]);
+
+  // try_map_ref
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_seq_equal(&a.try_map_ref(|&x| if x < 4 { Ok(x + 1) } else { Err(x) }).unwrap(), vec![2, 3, 4]);
+  assert_eq!(a.try_map_ref(|&x| if x == 2 { Err(x) } else { Ok(x + 1) }).err(), Some(2));
+  assert_seq_equal(&e.try_map_ref(|&x| if x < 4 { Ok(x + 1) } else { Err(x) }).unwrap(), vec![]);
+
   // unit
   assert_seq_equal(&C::unit(1), vec![1]);
 }
+
+pub(crate) fn test_collection_to_float<C>(a: &C, n: &C, e: &C)
+where
+  C: CollectionTo<f64> + IntoIterator<Item = f64> + FromIterator<f64> + Clone,
+  for<'i> &'i C: IntoIterator<Item = &'i f64>,
+{
+  // largest_floats
+  assert_eq!(a.clone().largest_floats(2, NanPolicy::Total).into_iter().collect::<Vec<_>>(), vec![3.0, 2.0]);
+  assert_eq!(a.clone().largest_floats(4, NanPolicy::Total).into_iter().collect::<Vec<_>>(), vec![3.0, 2.0, 1.0]);
+  assert!(n.clone().largest_floats(1, NanPolicy::Total).into_iter().all(f64::is_nan));
+  assert_eq!(n.clone().largest_floats(2, NanPolicy::Skip).into_iter().collect::<Vec<_>>(), vec![2.0, 1.0]);
+  let largest = n.clone().add(-f64::NAN).largest_floats(3, NanPolicy::Propagate).into_iter().collect::<Vec<_>>();
+  assert_eq!(
+    largest.iter().map(|x| x.to_bits()).collect::<Vec<_>>(),
+    vec![f64::NAN.to_bits(), (-f64::NAN).to_bits(), 2.0_f64.to_bits()]
+  );
+  assert_eq!(e.clone().largest_floats(3, NanPolicy::Total).into_iter().collect::<Vec<_>>(), vec![]);
+
+  // smallest_floats
+  assert_eq!(a.clone().smallest_floats(2, NanPolicy::Total).into_iter().collect::<Vec<_>>(), vec![1.0, 2.0]);
+  assert_eq!(a.clone().smallest_floats(4, NanPolicy::Total).into_iter().collect::<Vec<_>>(), vec![1.0, 2.0, 3.0]);
+  assert_eq!(n.clone().smallest_floats(2, NanPolicy::Total).into_iter().collect::<Vec<_>>(), vec![1.0, 2.0]);
+  assert_eq!(n.clone().smallest_floats(3, NanPolicy::Skip).into_iter().collect::<Vec<_>>(), vec![1.0, 2.0]);
+  let smallest = n.clone().smallest_floats(2, NanPolicy::Propagate).into_iter().collect::<Vec<_>>();
+  assert_eq!(smallest.iter().map(|x| x.to_bits()).collect::<Vec<_>>(), vec![f64::NAN.to_bits(), 1.0_f64.to_bits()]);
+  assert_eq!(e.clone().smallest_floats(3, NanPolicy::Total).into_iter().collect::<Vec<_>>(), vec![]);
+}
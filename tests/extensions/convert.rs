@@ -1,4 +1,5 @@
 use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+use std::hash::{BuildHasherDefault, DefaultHasher};
 
 use cantrip::Convert;
 
@@ -53,12 +54,27 @@ where
   assert_eq!(g.to_map(), HashMap::from([(1, 1), (2, 2), (3, 3)]));
   assert_eq!(e.to_map(), HashMap::new());
 
+  // to_map_with_hasher
+  let g = g_source.clone();
+  let e = g_source.clone().into_iter().filter(|_| false).collect::<G>();
+  assert_eq!(
+    g.to_map_with_hasher(BuildHasherDefault::<DefaultHasher>::default()),
+    HashMap::from_iter([(1, 1), (2, 2), (3, 3)])
+  );
+  assert_eq!(e.to_map_with_hasher(BuildHasherDefault::<DefaultHasher>::default()), HashMap::default());
+
   // to_set
   let a = a_source.clone();
   let e = e_source.clone();
   assert_eq!(a.to_set(), HashSet::from([1, 2, 3]));
   assert_eq!(e.to_set(), HashSet::new());
 
+  // to_set_with_hasher
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_eq!(a.to_set_with_hasher(BuildHasherDefault::<DefaultHasher>::default()), HashSet::from_iter([1, 2, 3]));
+  assert_eq!(e.to_set_with_hasher(BuildHasherDefault::<DefaultHasher>::default()), HashSet::default());
+
   // to_vec
   let a = a_source.clone();
   let e = e_source.clone();
@@ -1,9 +1,10 @@
 use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
 
-use cantrip::{Convert, Map};
+use cantrip::{Convert, Map, NanPolicy, Summary};
 
 use crate::extensions::util::{TestCollection, TestMap, assert_map_equal, assert_set_equal};
 
+#[allow(clippy::cast_precision_loss)]
 #[allow(clippy::too_many_lines)]
 pub(crate) fn test_map<'a, C>(a_source: &C, b_source: &C, e_source: &C)
 where
@@ -41,7 +42,41 @@ where
   assert!(!a.any(|(&k, _)| k > 5));
   assert!(!e.any(|(&k, _)| k > 0));
 
+  // checked_product_keys
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_eq!(a.checked_product_keys(), Some(6));
+  let a = a_source.clone();
+  assert_eq!(a.add(i64::MAX, 1).checked_product_keys(), None);
+  assert_eq!(e.checked_product_keys(), Some(1));
+
+  // checked_product_values
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_eq!(a.checked_product_values(), Some(6));
+  let a = a_source.clone();
+  assert_eq!(a.add(4, i64::MAX).checked_product_values(), None);
+  assert_eq!(e.checked_product_values(), Some(1));
+
+  // checked_sum_keys
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_eq!(a.checked_sum_keys(), Some(6));
+  let a = a_source.clone();
+  assert_eq!(a.add(i64::MAX, 1).checked_sum_keys(), None);
+  assert_eq!(e.checked_sum_keys(), Some(0));
+
+  // checked_sum_values
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_eq!(a.checked_sum_values(), Some(6));
+  let a = a_source.clone();
+  assert_eq!(a.add(4, i64::MAX).checked_sum_values(), None);
+  assert_eq!(e.checked_sum_values(), Some(0));
+
   // collect
+  let a = a_source.clone();
+  let e = e_source.clone();
   assert_eq!(a.collect::<BTreeSet<(i64, i64)>>(), BTreeSet::from([(1, 1), (2, 2), (3, 3)]));
   assert_eq!(e.collect::<BTreeSet<(i64, i64)>>(), BTreeSet::new());
 
@@ -172,6 +207,14 @@ where
   assert_map_equal(&a.intersect(&vec![(4, 4), (2, 2), (3, 4), (4, 5)]), HashMap::from([(2, 2)]));
   assert_map_equal(&e.intersect(&vec![(1, 1)]), HashMap::new());
 
+  // largest_by_value
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_eq!(a.largest_by_value(2), vec![(3, 3), (2, 2)]);
+  let a = a_source.clone();
+  assert_eq!(a.largest_by_value(4), vec![(3, 3), (2, 2), (1, 1)]);
+  assert_eq!(e.largest_by_value(3), vec![]);
+
   // map
   let a = a_source.clone();
   let e = e_source.clone();
@@ -188,6 +231,20 @@ where
   assert_map_equal(&a.map_keys(|&k| k + 1), HashMap::from([(2, 1), (3, 2), (4, 3)]));
   assert_map_equal(&e.map_keys(|&k| k + 1), HashMap::new());
 
+  // map_validated
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_map_equal(
+    &a.map_validated(|(k, v)| if v < 4 { Ok((k, k + v)) } else { Err(v) }).unwrap(),
+    HashMap::from([(1, 2), (2, 4), (3, 6)]),
+  );
+  let a = a_source.clone();
+  assert_map_equal(
+    &a.map_validated(|(k, v)| if v < 2 { Ok((k, v)) } else { Err(-v) }).unwrap_err(),
+    HashMap::from([(2, -2), (3, -3)]),
+  );
+  assert_map_equal(&e.map_validated(|(k, v)| if v < 4 { Ok((k, k + v)) } else { Err(v) }).unwrap(), HashMap::new());
+
   // map_values
   let a = a_source.clone();
   let e = e_source.clone();
@@ -208,6 +265,22 @@ where
   assert_eq!(a.max_of(), Some((&3, &3)));
   assert_eq!(e.max_of(), None);
 
+  // mean_by_key
+  assert_eq!(a.mean_by_key(|(&k, &v)| (k + v) as f64), Some(4.0));
+  assert_eq!(e.mean_by_key(|(&k, &v)| (k + v) as f64), None);
+
+  // mean_values
+  assert_eq!(a.mean_values(), Some(2.0));
+  assert_eq!(e.mean_values(), None);
+
+  // median_by_key
+  assert_eq!(a.median_by_key(|(_, &v)| v as f64), Some(2.0));
+  assert_eq!(e.median_by_key(|(_, &v)| v as f64), None);
+
+  // median_values
+  assert_eq!(a.median_values(), Some(2.0));
+  assert_eq!(e.median_values(), None);
+
   // min_by
   assert_eq!(a.min_by(|x, y| x.0.cmp(y.0)), Some((&1, &1)));
   assert_eq!(e.min_by(|x, y| x.0.cmp(y.0)), None);
@@ -232,6 +305,14 @@ where
   assert_eq!(a.minmax_of(), Some(((&1, &1), (&3, &3))));
   assert_eq!(e.minmax_of(), None);
 
+  // mode_by_key
+  assert_eq!(a.mode_by_key(|(_, &v)| (v % 2) as f64), Some(1.0));
+  assert_eq!(e.mode_by_key(|(_, &v)| v as f64), None);
+
+  // mode_values
+  assert_eq!(a.mode_values(), Some(1.0));
+  assert_eq!(e.mode_values(), None);
+
   // partition
   let a = a_source.clone();
   let e = e_source.clone();
@@ -262,6 +343,24 @@ where
   assert_map_equal(&e_even, HashMap::new());
   assert_map_equal(&e_odd, HashMap::new());
 
+  // partition_results
+  let (values, errors) = HashMap::from([(1, Ok(1)), (2, Err(2)), (3, Ok(3))]).partition_results();
+  assert_eq!(values, HashMap::from([(1, 1), (3, 3)]));
+  assert_eq!(errors, HashMap::from([(2, 2)]));
+  let (values, errors) = HashMap::<i64, Result<i64, i64>>::new().partition_results();
+  assert_eq!(values, HashMap::new());
+  assert_eq!(errors, HashMap::new());
+
+  // percentiles_by_key
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_eq!(a.percentiles_by_key(vec![0.0, 50.0, 75.0], |(_, &v)| v as f64), Some(vec![1.0, 2.0, 2.5]));
+  assert_eq!(e.percentiles_by_key(vec![50.0], |(_, &v)| v as f64), None);
+
+  // percentiles_values
+  assert_eq!(a.percentiles_values(vec![0.0, 50.0, 100.0]), Some(vec![1.0, 2.0, 3.0]));
+  assert_eq!(e.percentiles_values(vec![50.0]), None);
+
   // product_keys
   assert_eq!(a.product_keys(), 6);
   assert_eq!(e.product_keys(), 1);
@@ -272,6 +371,16 @@ where
   assert_eq!(a.product_values(), 6);
   assert_eq!(e.product_values(), 1);
 
+  // quantile_by_key
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_eq!(a.quantile_by_key(0.25, |(&k, _)| k as f64), Some(1.5));
+  assert_eq!(e.quantile_by_key(0.25, |(&k, _)| k as f64), None);
+
+  // quantile_values
+  assert_eq!(a.quantile_values(0.25), Some(1.5));
+  assert_eq!(e.quantile_values(0.5), None);
+
   // reduce
   let a = a_source.clone();
   let e = e_source.clone();
@@ -284,6 +393,42 @@ where
   assert_eq!(a.reduce_ref(|(&a, &b), (&k, &v)| (a + k, b + v)), Some((6, 6)));
   assert_eq!(e.reduce_ref(|(&a, &b), (&k, &v)| (a + k, b + v)), None);
 
+  // saturating_sum_keys
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_eq!(a.saturating_sum_keys(), 6);
+  let a = a_source.clone();
+  assert_eq!(a.add(i64::MAX, 1).saturating_sum_keys(), i64::MAX);
+  assert_eq!(e.saturating_sum_keys(), 0);
+
+  // saturating_sum_values
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_eq!(a.saturating_sum_values(), 6);
+  let a = a_source.clone();
+  assert_eq!(a.add(4, i64::MIN).saturating_sum_values(), i64::MIN + 6);
+  let a = a_source.clone();
+  assert_eq!(a.add_multi([(4, i64::MIN), (5, -7), (6, i64::MAX)]).saturating_sum_values(), -2);
+  assert_eq!(e.saturating_sum_values(), 0);
+
+  // smallest_by_value
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_eq!(a.smallest_by_value(2), vec![(1, 1), (2, 2)]);
+  let a = a_source.clone();
+  assert_eq!(a.smallest_by_value(4), vec![(1, 1), (2, 2), (3, 3)]);
+  assert_eq!(e.smallest_by_value(3), vec![]);
+
+  // std_dev_by_key
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_eq!(a.std_dev_by_key(|(_, &v)| v as f64), Some(1.0));
+  assert_eq!(e.std_dev_by_key(|(_, &v)| v as f64), None);
+
+  // std_dev_values
+  assert_eq!(a.std_dev_values(), Some(1.0));
+  assert_eq!(e.std_dev_values(), None);
+
   // subset
   assert!(a.subset(&vec![4, 3, 2, 2, 1]));
   assert!(e.subset(&vec![1]));
@@ -322,6 +467,38 @@ where
   assert_eq!(a.sum_values(), 6);
   assert_eq!(e.sum_values(), 0);
 
+  // summary_by_key
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_eq!(
+    a.summary_by_key(|(_, &v)| v as f64),
+    Some(Summary {
+      count: 3,
+      min: 1.0,
+      max: 3.0,
+      mean: 2.0,
+      lower_quartile: 1.5,
+      median: 2.0,
+      upper_quartile: 2.5
+    })
+  );
+  assert_eq!(e.summary_by_key(|(_, &v)| v as f64), None);
+
+  // summary_values
+  assert_eq!(
+    a.summary_values(),
+    Some(Summary {
+      count: 3,
+      min: 1.0,
+      max: 3.0,
+      mean: 2.0,
+      lower_quartile: 1.5,
+      median: 2.0,
+      upper_quartile: 2.5
+    })
+  );
+  assert_eq!(e.summary_values(), None);
+
   // to_bmap
   let a = a_source.clone();
   let e = e_source.clone();
@@ -385,6 +562,96 @@ where
   assert_set_equal(a.to_vec(), vec![(1, 1), (2, 2), (3, 3)]);
   assert_eq!(e.to_vec(), vec![]);
 
+  // try_filter
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_map_equal(
+    &a.try_filter(|(&k, &v)| if v < 4 { Ok(k != 2) } else { Err(k) }).unwrap(),
+    HashMap::from([(1, 1), (3, 3)]),
+  );
+  let a = a_source.clone();
+  assert_eq!(a.try_filter(|(&k, _)| if k == 2 { Err(k) } else { Ok(true) }).err(), Some(2));
+  assert_map_equal(&e.try_filter(|(&k, &v)| if v < 4 { Ok(k != 2) } else { Err(k) }).unwrap(), HashMap::new());
+
+  // try_filter_ref
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_map_equal(
+    &a.try_filter_ref(|(&k, &v)| if v < 4 { Ok(k != 2) } else { Err(k) }).unwrap(),
+    HashMap::from([(1, 1), (3, 3)]),
+  );
+  assert_eq!(a.try_filter_ref(|(&k, _)| if k == 2 { Err(k) } else { Ok(true) }).err(), Some(2));
+  assert_map_equal(&e.try_filter_ref(|(&k, &v)| if v < 4 { Ok(k != 2) } else { Err(k) }).unwrap(), HashMap::new());
+
+  // try_fold
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_eq!(a.try_fold(0, |acc, (k, v)| if v < 4 { Ok(acc + k + v) } else { Err(k) }), Ok(12));
+  let a = a_source.clone();
+  assert_eq!(a.try_fold(0, |acc, (k, v)| if k == 2 { Err(k) } else { Ok(acc + k + v) }), Err(2));
+  assert_eq!(e.try_fold(0, |acc, (k, v)| if v < 4 { Ok(acc + k + v) } else { Err(k) }), Ok(0));
+
+  // try_fold_ref
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_eq!(a.try_fold_ref(0, |acc, (&k, &v)| if v < 4 { Ok(acc + k + v) } else { Err(k) }), Ok(12));
+  assert_eq!(a.try_fold_ref(0, |acc, (&k, &v)| if k == 2 { Err(k) } else { Ok(acc + k + v) }), Err(2));
+  assert_eq!(e.try_fold_ref(0, |acc, (&k, &v)| if v < 4 { Ok(acc + k + v) } else { Err(k) }), Ok(0));
+
+  // try_map
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_map_equal(
+    &a.try_map(|(k, v)| if v < 4 { Ok((k, k + v)) } else { Err(k) }).unwrap(),
+    HashMap::from([(1, 2), (2, 4), (3, 6)]),
+  );
+  let a = a_source.clone();
+  assert_eq!(a.try_map(|(k, v)| if k == 2 { Err(k) } else { Ok((k, v)) }).err(), Some(2));
+  assert_map_equal(&e.try_map(|(k, v)| if v < 4 { Ok((k, k + v)) } else { Err(k) }).unwrap(), HashMap::new());
+
+  // try_map_ref
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_map_equal(
+    &a.try_map_ref(|(&k, &v)| if v < 4 { Ok((k, k + v)) } else { Err(k) }).unwrap(),
+    HashMap::from([(1, 2), (2, 4), (3, 6)]),
+  );
+  assert_eq!(a.try_map_ref(|(&k, &v)| if k == 2 { Err(k) } else { Ok((k, v)) }).err(), Some(2));
+  assert_map_equal(&e.try_map_ref(|(&k, &v)| if v < 4 { Ok((k, k + v)) } else { Err(k) }).unwrap(), HashMap::new());
+
   // unit
   assert_map_equal(&HashMap::unit(1, 1), HashMap::from([(1, 1)]));
+
+  // variance_by_key
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_eq!(a.variance_by_key(|(_, &v)| v as f64), Some(1.0));
+  assert_eq!(e.variance_by_key(|(_, &v)| v as f64), None);
+
+  // variance_values
+  assert_eq!(a.variance_values(), Some(1.0));
+  assert_eq!(e.variance_values(), None);
+}
+
+pub(crate) fn test_map_float<'a, C>(a: &C, n: &C, e: &C)
+where
+  C: Map<i64, f64> + 'a,
+  for<'i> &'i C: IntoIterator<Item = (&'i i64, &'i f64)>,
+{
+  // max_of_float_values
+  assert_eq!(a.max_of_float_values(NanPolicy::Total), Some((&3, &3.0)));
+  assert!(n.max_of_float_values(NanPolicy::Total).is_some_and(|(&k, v)| k == 2 && v.is_nan()));
+  assert_eq!(n.max_of_float_values(NanPolicy::Skip), Some((&1, &2.0)));
+  assert_eq!(e.max_of_float_values(NanPolicy::Propagate), None);
+
+  // min_of_float_values
+  assert_eq!(a.min_of_float_values(NanPolicy::Total), Some((&2, &1.0)));
+  assert_eq!(n.min_of_float_values(NanPolicy::Skip), Some((&3, &1.0)));
+  assert!(n.min_of_float_values(NanPolicy::Propagate).is_some_and(|(&k, v)| k == 2 && v.is_nan()));
+  assert_eq!(e.min_of_float_values(NanPolicy::Total), None);
+
+  // minmax_of_float_values
+  assert_eq!(a.minmax_of_float_values(NanPolicy::Total), Some(((&2, &1.0), (&3, &3.0))));
+  assert_eq!(n.minmax_of_float_values(NanPolicy::Skip), Some(((&3, &1.0), (&1, &2.0))));
+  assert_eq!(e.minmax_of_float_values(NanPolicy::Skip), None);
 }
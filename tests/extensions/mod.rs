@@ -1,10 +1,17 @@
+pub(crate) mod array;
+pub(crate) mod boxed_slice;
 pub(crate) mod collection;
 pub(crate) mod collection_to;
 pub(crate) mod convert;
 pub(crate) mod list;
 pub(crate) mod map;
+pub(crate) mod option;
+pub(crate) mod result;
 pub(crate) mod sequence;
+pub(crate) mod sequence_mut;
 pub(crate) mod sequence_to;
 pub(crate) mod slice;
+pub(crate) mod text;
 pub(crate) mod traits;
+pub(crate) mod traversal;
 pub(crate) mod util;
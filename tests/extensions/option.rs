@@ -0,0 +1,52 @@
+use std::collections::{HashMap, HashSet};
+
+use cantrip::{Collection, CollectionTo, Convert};
+
+pub(crate) fn test_option() {
+  let a_source = Some(2_i64);
+  let e_source = None::<i64>;
+
+  // all
+  assert!(a_source.all(|&x| x > 1));
+  assert!(e_source.all(|&x| x > 1));
+
+  // any
+  assert!(a_source.any(|&x| x > 1));
+  assert!(!e_source.any(|&x| x > 1));
+
+  // count_by
+  assert_eq!(a_source.count_by(|&x| x == 2), 1);
+  assert_eq!(e_source.count_by(|&x| x == 2), 0);
+
+  // find_map
+  assert_eq!(a_source.find_map(|x| Some(x + 1)), Some(3));
+  assert_eq!(e_source.find_map(|x| Some(x + 1)), None);
+
+  // fold
+  assert_eq!(a_source.fold(1, |r, x| r + x), 3);
+  assert_eq!(e_source.fold(1, |r, x| r + x), 1);
+
+  // fold_ref
+  assert_eq!(a_source.fold_ref(1, |r, x| r + x), 3);
+  assert_eq!(e_source.fold_ref(1, |r, x| r + x), 1);
+
+  // group_fold
+  assert_eq!(a_source.group_fold(|x| x % 2, 0, |r, x| r + x), HashMap::from([(0, 2)]));
+  assert_eq!(e_source.group_fold(|x| x % 2, 0, |r, x| r + x), HashMap::new());
+
+  // max_by_key
+  assert_eq!(a_source.max_by_key(|&x| -x), Some(&2));
+  assert_eq!(e_source.max_by_key(|&x| -x), None);
+
+  // sum
+  assert_eq!(a_source.sum(), 2);
+  assert_eq!(e_source.sum(), 0);
+
+  // to_set
+  assert_eq!(a_source.to_set(), HashSet::from([2]));
+  assert_eq!(e_source.to_set(), HashSet::new());
+
+  // try_fold
+  assert_eq!(a_source.try_fold(0, |r, x| if x == 2 { Err(x) } else { Ok(r + x) }), Err(2));
+  assert_eq!(e_source.try_fold(0, |r, x| if x == 2 { Err(x) } else { Ok(r + x) }), Ok(0));
+}
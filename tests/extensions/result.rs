@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+
+use cantrip::{Collection, CollectionTo, Convert};
+
+pub(crate) fn test_result() {
+  let a_source: Result<i64, String> = Ok(2);
+  let e_source: Result<i64, String> = Err("error".to_string());
+
+  // all
+  assert!(a_source.all(|&x| x > 1));
+  assert!(e_source.all(|&x| x > 1));
+
+  // any
+  assert!(a_source.any(|&x| x > 1));
+  assert!(!e_source.any(|&x| x > 1));
+
+  // count_by
+  assert_eq!(a_source.count_by(|&x| x == 2), 1);
+  assert_eq!(e_source.count_by(|&x| x == 2), 0);
+
+  // fold
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_eq!(a.fold(1, |r, x| r + x), 3);
+  assert_eq!(e.fold(1, |r, x| r + x), 1);
+
+  // fold_ref
+  assert_eq!(a_source.fold_ref(1, |r, x| r + x), 3);
+  assert_eq!(e_source.fold_ref(1, |r, x| r + x), 1);
+
+  // group_fold
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_eq!(a.group_fold(|x| x % 2, 0, |r, x| r + x), HashMap::from([(0, 2)]));
+  assert_eq!(e.group_fold(|x| x % 2, 0, |r, x| r + x), HashMap::new());
+
+  // product
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_eq!(a.product(), 2);
+  assert_eq!(e.product(), 1);
+
+  // to_vec
+  let a = a_source;
+  let e = e_source;
+  assert_eq!(a.to_vec(), vec![2]);
+  assert_eq!(e.to_vec(), Vec::<i64>::new());
+}
@@ -1,15 +1,36 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::Debug;
+use std::hash::{BuildHasherDefault, DefaultHasher};
 
-use cantrip::{Iterable, Sequence};
+use cantrip::{DiffHunk, Iterable, LengthMismatch, Parallelism, Sequence};
 
 use crate::extensions::util::Equal;
 
+#[allow(clippy::too_many_lines)]
 pub(crate) fn test_sequence<'a, C>(a: &C, b: &C, e: &C)
 where
   C: Sequence<i64> + Iterable<Item<'a> = &'a i64> + Equal + Debug + ?Sized + 'a,
   for<'i> &'i C: IntoIterator<Item = &'i i64>,
 {
+  // binary_position_by_key
+  assert_eq!(b.binary_position_by_key(&2, |&x| x), Some(1));
+  assert_eq!(b.binary_position_by_key(&4, |&x| x), None);
+  assert_eq!(e.binary_position_by_key(&2, |&x| x), None);
+
+  // binary_position_of
+  assert_eq!(a.binary_position_of(&1), Some(0));
+  assert_eq!(a.binary_position_of(&3), Some(2));
+  assert_eq!(b.binary_position_of(&2), Some(1));
+  assert_eq!(a.binary_position_of(&0), None);
+  assert_eq!(a.binary_position_of(&4), None);
+  assert_eq!(e.binary_position_of(&1), None);
+
+  // chunked_array
+  assert_eq!(a.chunked_array::<2>(), vec![[1, 2]]);
+  assert_eq!(a.chunked_array::<1>(), vec![[1], [2], [3]]);
+  assert_eq!(b.chunked_array::<2>(), vec![[1, 2], [2, 3]]);
+  assert_eq!(e.chunked_array::<2>(), Vec::<[i64; 2]>::new());
+
   // common_prefix_length
   assert_eq!(a.common_prefix_length(&vec![1, 2, 3, 4]), 3);
   assert_eq!(a.common_prefix_length(&vec![1, 2]), 2);
@@ -22,10 +43,103 @@ where
   assert_eq!(a.common_suffix_length(&vec![]), 0);
   assert_eq!(e.common_prefix_length(&vec![]), 0);
 
+  // contains_sequence
+  assert!(b.contains_sequence(&vec![2, 3]));
+  assert!(b.contains_sequence(&vec![]));
+  assert!(!b.contains_sequence(&vec![1, 3]));
+  assert!(!b.contains_sequence(&vec![3, 4]));
+  assert!(!e.contains_sequence(&vec![1]));
+
   // count_unique
   assert_eq!(b.count_unique(), 3);
   assert_eq!(e.count_unique(), 0);
 
+  // count_unique_eq
+  assert_eq!(b.count_unique_eq(), 3);
+  assert_eq!(e.count_unique_eq(), 0);
+
+  // count_unique_ord
+  assert_eq!(b.count_unique_ord(), 3);
+  assert_eq!(e.count_unique_ord(), 0);
+
+  // damerau_levenshtein_distance
+  assert_eq!(a.damerau_levenshtein_distance(&vec![2, 1, 3]), 1);
+  assert_eq!(a.damerau_levenshtein_distance(&vec![3, 1]), 2);
+  assert_eq!(b.damerau_levenshtein_distance(&vec![1, 2, 3]), 1);
+  assert_eq!(a.damerau_levenshtein_distance(&vec![1, 2, 3]), 0);
+  assert_eq!(e.damerau_levenshtein_distance(&vec![1, 2]), 2);
+
+  // diff
+  assert_eq!(
+    a.diff(&vec![1, 2, 2, 3]),
+    vec![
+      DiffHunk::Equal {
+        source_index: 0,
+        target_index: 0,
+        length: 2
+      },
+      DiffHunk::Insert {
+        source_index: 2,
+        target_index: 2,
+        elements: vec![2]
+      },
+      DiffHunk::Equal {
+        source_index: 2,
+        target_index: 3,
+        length: 1
+      },
+    ]
+  );
+  assert_eq!(
+    b.diff(&vec![2, 4]),
+    vec![
+      DiffHunk::Delete {
+        source_index: 0,
+        target_index: 0,
+        length: 1
+      },
+      DiffHunk::Equal {
+        source_index: 1,
+        target_index: 0,
+        length: 1
+      },
+      DiffHunk::Replace {
+        source_index: 2,
+        target_index: 1,
+        length: 2,
+        elements: vec![4]
+      },
+    ]
+  );
+  assert_eq!(
+    a.diff(&vec![]),
+    vec![DiffHunk::Delete {
+      source_index: 0,
+      target_index: 0,
+      length: 3
+    }]
+  );
+  assert_eq!(
+    e.diff(&vec![1]),
+    vec![DiffHunk::Insert {
+      source_index: 0,
+      target_index: 0,
+      elements: vec![1]
+    }]
+  );
+  assert_eq!(e.diff(&vec![]), vec![]);
+
+  // equal_range
+  assert_eq!(b.equal_range(&2), 1..3);
+  assert_eq!(b.equal_range(&0), 0..0);
+  assert_eq!(b.equal_range(&4), 4..4);
+  assert_eq!(e.equal_range(&1), 0..0);
+
+  // equal_range_by_key
+  assert_eq!(b.equal_range_by_key(&2, |&x| x), 1..3);
+  assert_eq!(b.equal_range_by_key(&1, |&x| x / 2), 1..4);
+  assert_eq!(e.equal_range_by_key(&1, |&x| x), 0..0);
+
   // equivalent
   assert!(b.equivalent(&vec![3, 2, 1, 2]));
   assert!(!b.equivalent(&vec![1, 3, 3]));
@@ -33,6 +147,20 @@ where
   assert!(!b.equivalent(&vec![]));
   assert!(e.equivalent(&vec![]));
 
+  // equivalent_eq
+  assert!(b.equivalent_eq(&vec![3, 2, 1, 2]));
+  assert!(!b.equivalent_eq(&vec![1, 3, 3]));
+  assert!(!b.equivalent_eq(&vec![1, 1, 2, 2, 3]));
+  assert!(!b.equivalent_eq(&vec![]));
+  assert!(e.equivalent_eq(&vec![]));
+
+  // equivalent_ord
+  assert!(b.equivalent_ord(&vec![3, 2, 1, 2]));
+  assert!(!b.equivalent_ord(&vec![1, 3, 3]));
+  assert!(!b.equivalent_ord(&vec![1, 1, 2, 2, 3]));
+  assert!(!b.equivalent_ord(&vec![]));
+  assert!(e.equivalent_ord(&vec![]));
+
   // find_position
   assert_eq!(a.find_position(|&x| x == 2), Some((1, &2)));
   assert_eq!(a.find_position(|&x| x == 5), None);
@@ -42,14 +170,110 @@ where
   assert_eq!(b.frequencies(), HashMap::from([(&1, 1), (&2, 2), (&3, 1),]));
   assert_eq!(e.frequencies(), HashMap::new());
 
+  // frequencies_eq
+  assert_eq!(b.frequencies_eq(), vec![(&1, 1), (&2, 2), (&3, 1)]);
+  assert_eq!(e.frequencies_eq(), vec![]);
+
+  // frequencies_ord
+  assert_eq!(b.frequencies_ord(), BTreeMap::from([(&1, 1), (&2, 2), (&3, 1)]));
+  assert_eq!(e.frequencies_ord(), BTreeMap::new());
+
+  // frequencies_with_hasher
+  assert_eq!(
+    b.frequencies_with_hasher(BuildHasherDefault::<DefaultHasher>::default()),
+    HashMap::from_iter([(&1, 1), (&2, 2), (&3, 1)])
+  );
+  assert_eq!(e.frequencies_with_hasher(BuildHasherDefault::<DefaultHasher>::default()), HashMap::default());
+
   // frequencies_by
   assert_eq!(b.frequencies_by(|x| x % 2), HashMap::from([(0, 2), (1, 2),]));
   assert_eq!(e.frequencies_by(|x| x % 2), HashMap::new());
 
+  // frequencies_by_with_hasher
+  assert_eq!(
+    b.frequencies_by_with_hasher(|x| x % 2, BuildHasherDefault::<DefaultHasher>::default()),
+    HashMap::from_iter([(0, 2), (1, 2)])
+  );
+  assert_eq!(
+    e.frequencies_by_with_hasher(|x| x % 2, BuildHasherDefault::<DefaultHasher>::default()),
+    HashMap::default()
+  );
+
+  // hamming_distance
+  assert_eq!(a.hamming_distance(&vec![1, 3, 3]), Ok(1));
+  assert_eq!(a.hamming_distance(&vec![3, 2, 1]), Ok(2));
+  assert_eq!(a.hamming_distance(&vec![1, 2]), Err(LengthMismatch { left: 3, right: 2 }));
+  assert_eq!(e.hamming_distance(&vec![1]), Err(LengthMismatch { left: 0, right: 1 }));
+  assert_eq!(e.hamming_distance(&vec![]), Ok(0));
+
+  // is_sorted
+  assert!(a.is_sorted());
+  assert!(b.is_sorted());
+  assert!(e.is_sorted());
+
+  // is_sorted_by_key
+  assert!(a.is_sorted_by_key(|&x| x / 2));
+  assert!(!a.is_sorted_by_key(|&x| -x));
+  assert!(e.is_sorted_by_key(|&x| -x));
+
+  // is_subsequence_of
+  assert!(a.is_subsequence_of(&vec![0, 1, 2, 4, 3]));
+  assert!(a.is_subsequence_of(&vec![1, 2, 3]));
+  assert!(b.is_subsequence_of(&vec![1, 2, 5, 2, 3]));
+  assert!(e.is_subsequence_of(&vec![]));
+  assert!(!a.is_subsequence_of(&vec![1, 3, 2]));
+  assert!(!b.is_subsequence_of(&vec![1, 2, 3]));
+
   // joined
   assert_eq!(a.joined(", "), "1, 2, 3");
   assert_eq!(e.joined(", "), "");
 
+  // levenshtein_distance
+  assert_eq!(a.levenshtein_distance(&vec![2, 1, 3]), 2);
+  assert_eq!(a.levenshtein_distance(&vec![1, 3]), 1);
+  assert_eq!(b.levenshtein_distance(&vec![1, 2, 3]), 1);
+  assert_eq!(a.levenshtein_distance(&vec![1, 2, 3]), 0);
+  assert_eq!(e.levenshtein_distance(&vec![1, 2]), 2);
+
+  // longest_common_subsequence
+  assert_eq!(a.longest_common_subsequence(&vec![0, 1, 3]), vec![(0, 1), (2, 2)]);
+  assert_eq!(b.longest_common_subsequence(&vec![1, 2, 3]), vec![(0, 0), (1, 1), (3, 2)]);
+  assert_eq!(a.longest_common_subsequence(&vec![4, 5]), vec![]);
+  assert_eq!(e.longest_common_subsequence(&vec![1]), vec![]);
+
+  // longest_increasing_subsequence
+  assert_eq!(a.longest_increasing_subsequence(), vec![0, 1, 2]);
+  assert_eq!(b.longest_increasing_subsequence().len(), 3);
+  assert_eq!(e.longest_increasing_subsequence(), vec![]);
+
+  // longest_increasing_subsequence_by_key
+  assert_eq!(a.longest_increasing_subsequence_by_key(|&x| -x).len(), 1);
+  assert_eq!(b.longest_increasing_subsequence_by_key(|&x| x % 2).len(), 2);
+  assert_eq!(e.longest_increasing_subsequence_by_key(|&x| -x), vec![]);
+
+  // longest_run_by
+  assert_eq!(a.longest_run_by(|&p, &n| n == p + 1), 0..3);
+  assert_eq!(b.longest_run_by(|&p, &n| n == p + 1), 0..2);
+  assert_eq!(b.longest_run_by(|&p, &n| n == p), 1..3);
+  assert_eq!(e.longest_run_by(|_, _| true), 0..0);
+
+  // lower_bound
+  assert_eq!(b.lower_bound(&2), 1);
+  assert_eq!(b.lower_bound(&0), 0);
+  assert_eq!(b.lower_bound(&4), 4);
+  assert_eq!(e.lower_bound(&1), 0);
+
+  // lower_bound_by_key
+  assert_eq!(b.lower_bound_by_key(&2, |&x| x), 1);
+  assert_eq!(b.lower_bound_by_key(&1, |&x| x / 2), 1);
+  assert_eq!(e.lower_bound_by_key(&1, |&x| x), 0);
+
+  // par_frequencies
+  let parallelism = Parallelism { threads: 2, threshold: 0 };
+  assert_eq!(b.par_frequencies(parallelism), HashMap::from([(&1, 1), (&2, 2), (&3, 1)]));
+  assert_eq!(b.par_frequencies(Parallelism::default()), HashMap::from([(&1, 1), (&2, 2), (&3, 1)]));
+  assert_eq!(e.par_frequencies(parallelism), HashMap::new());
+
   // position
   assert_eq!(b.position(|&x| x == 2), Some(1));
   assert_eq!(b.position(|&x| x == 5), None);
@@ -77,9 +301,18 @@ where
   // position_of_sequence
   assert_eq!(b.position_sequence(&vec![2, 2]), Some(1));
   assert_eq!(b.position_sequence(&vec![]), Some(0));
+  assert_eq!(b.position_sequence(&vec![2, 3]), Some(2));
   assert_eq!(b.position_sequence(&vec![1, 3]), None);
+  assert_eq!(b.position_sequence(&vec![3, 4]), None);
   assert_eq!(e.position_sequence(&vec![1, 3]), None);
 
+  // position_sequence_multi
+  assert_eq!(b.position_sequence_multi(&vec![2]), vec![1, 2]);
+  assert_eq!(b.position_sequence_multi(&vec![2, 3]), vec![2]);
+  assert_eq!(a.position_sequence_multi(&vec![]), vec![0, 1, 2, 3]);
+  assert_eq!(b.position_sequence_multi(&vec![1, 3]), vec![]);
+  assert_eq!(e.position_sequence_multi(&vec![]), vec![0]);
+
   // rfind
   assert_eq!(a.rfind(|&x| x % 2 == 1), Some(&3));
   assert_eq!(a.rfind(|&x| x == 5), None);
@@ -93,4 +326,77 @@ where
   assert_eq!(a.rposition(|&x| x % 2 == 1), Some(2));
   assert_eq!(a.rposition(|&x| x == 5), None);
   assert_eq!(e.rposition(|&x| x == 5), None);
+
+  // rposition_sequence
+  assert_eq!(b.rposition_sequence(&vec![2]), Some(2));
+  assert_eq!(b.rposition_sequence(&vec![1, 2]), Some(0));
+  assert_eq!(b.rposition_sequence(&vec![]), Some(4));
+  assert_eq!(b.rposition_sequence(&vec![1, 3]), None);
+  assert_eq!(e.rposition_sequence(&vec![]), Some(0));
+  assert_eq!(e.rposition_sequence(&vec![1]), None);
+
+  // upper_bound
+  assert_eq!(b.upper_bound(&2), 3);
+  assert_eq!(b.upper_bound(&0), 0);
+  assert_eq!(b.upper_bound(&3), 4);
+  assert_eq!(e.upper_bound(&1), 0);
+
+  // upper_bound_by_key
+  assert_eq!(b.upper_bound_by_key(&2, |&x| x), 3);
+  assert_eq!(b.upper_bound_by_key(&0, |&x| x / 2), 1);
+  assert_eq!(e.upper_bound_by_key(&1, |&x| x), 0);
+
+  // windowed_array
+  assert_eq!(a.windowed_array::<2>(), vec![[1, 2], [2, 3]]);
+  assert_eq!(a.windowed_array::<4>(), Vec::<[i64; 4]>::new());
+  assert_eq!(b.windowed_array::<3>(), vec![[1, 2, 2], [2, 2, 3]]);
+  assert_eq!(e.windowed_array::<1>(), Vec::<[i64; 1]>::new());
+}
+
+pub(crate) fn test_sequence_statistics<'a, C>(a: &'a C, b: &'a C, e: &'a C)
+where
+  C: Sequence<f64> + Iterable<Item<'a> = &'a f64> + 'a,
+  for<'i> &'i C: IntoIterator<Item = &'i f64>,
+{
+  // covariance
+  assert_eq!(a.covariance(a), Ok(Some(1.0)));
+  assert_eq!(a.covariance(&vec![3.0, 2.0, 1.0]), Ok(Some(-1.0)));
+  assert_eq!(a.covariance(&vec![1_000_000_001.0, 1_000_000_002.0, 1_000_000_003.0]), Ok(Some(1.0)));
+  assert_eq!(a.covariance(&vec![1.0]), Err(LengthMismatch { left: 3, right: 1 }));
+  assert_eq!(e.covariance(&vec![1.0]), Err(LengthMismatch { left: 0, right: 1 }));
+  assert_eq!(e.covariance(e), Ok(None));
+
+  // dot
+  assert_eq!(a.dot(a), Ok(14.0));
+  assert_eq!(a.dot(b), Err(LengthMismatch { left: 3, right: 4 }));
+  assert_eq!(e.dot(e), Ok(0.0));
+
+  // mean_absolute_error
+  assert_eq!(a.mean_absolute_error(a), Ok(Some(0.0)));
+  assert_eq!(a.mean_absolute_error(&vec![2.0, 4.0, 6.0]), Ok(Some(2.0)));
+  assert_eq!(b.mean_absolute_error(a), Err(LengthMismatch { left: 4, right: 3 }));
+  assert_eq!(e.mean_absolute_error(e), Ok(None));
+
+  // pearson_correlation
+  assert_eq!(a.pearson_correlation(a), Ok(Some(1.0)));
+  assert_eq!(a.pearson_correlation(&vec![-2.0, -4.0, -6.0]), Ok(Some(-1.0)));
+  assert_eq!(a.pearson_correlation(&vec![1.0, 3.0, 2.0]), Ok(Some(0.5)));
+  assert_eq!(a.pearson_correlation(&vec![2.0, 2.0, 2.0]), Ok(None));
+  assert_eq!(a.pearson_correlation(b), Err(LengthMismatch { left: 3, right: 4 }));
+  assert_eq!(e.pearson_correlation(e), Ok(None));
+
+  // root_mean_squared_error
+  assert_eq!(a.root_mean_squared_error(a), Ok(Some(0.0)));
+  assert_eq!(a.root_mean_squared_error(&vec![3.0, 4.0, 5.0]), Ok(Some(2.0)));
+  assert_eq!(a.root_mean_squared_error(b), Err(LengthMismatch { left: 3, right: 4 }));
+  assert_eq!(e.root_mean_squared_error(e), Ok(None));
+
+  // spearman_correlation
+  assert_eq!(a.spearman_correlation(&vec![1.0, 8.0, 27.0]), Ok(Some(1.0)));
+  assert_eq!(a.spearman_correlation(&vec![1.0, 3.0, 2.0]), Ok(Some(0.5)));
+  assert_eq!(b.spearman_correlation(b), Ok(Some(1.0)));
+  assert_eq!(b.spearman_correlation(&vec![1.0, 2.0, 3.0, 4.0]), Ok(Some(0.948_683_298_050_513_8)));
+  assert_eq!(a.spearman_correlation(&vec![2.0, 2.0, 2.0]), Ok(None));
+  assert_eq!(a.spearman_correlation(b), Err(LengthMismatch { left: 3, right: 4 }));
+  assert_eq!(e.spearman_correlation(e), Ok(None));
 }
@@ -0,0 +1,147 @@
+use std::panic;
+use std::panic::UnwindSafe;
+
+use cantrip::SequenceMut;
+
+use crate::extensions::util::{TestCollection, assert_seq_equal};
+
+#[allow(clippy::too_many_lines)]
+pub(crate) fn test_sequence_mut<C>(a_source: &C, b_source: &C, e_source: &C)
+where
+  C: SequenceMut<i64> + TestCollection<i64> + UnwindSafe,
+{
+  // add_at_mut
+  let mut a = a_source.clone();
+  let mut e = e_source.clone();
+  a.add_at_mut(0, 4);
+  assert_seq_equal(&a, vec![4, 1, 2, 3]);
+  let mut a = a_source.clone();
+  a.add_at_mut(3, 4);
+  assert_seq_equal(&a, vec![1, 2, 3, 4]);
+  e.add_at_mut(0, 1);
+  assert_seq_equal(&e, vec![1]);
+  let mut a = a_source.clone();
+  assert!(panic::catch_unwind(move || a.add_at_mut(4, 1)).is_err());
+
+  // add_at_multi_mut
+  let mut a = a_source.clone();
+  let mut e = e_source.clone();
+  a.add_at_multi_mut(1, vec![4, 5]);
+  assert_seq_equal(&a, vec![1, 4, 5, 2, 3]);
+  let mut a = a_source.clone();
+  a.add_at_multi_mut(3, vec![4, 5]);
+  assert_seq_equal(&a, vec![1, 2, 3, 4, 5]);
+  e.add_at_multi_mut(0, vec![1, 2]);
+  assert_seq_equal(&e, vec![1, 2]);
+  let mut a = a_source.clone();
+  assert!(panic::catch_unwind(move || a.add_at_multi_mut(4, vec![1, 2])).is_err());
+
+  // delete_at_mut
+  let mut a = a_source.clone();
+  a.delete_at_mut(0);
+  assert_seq_equal(&a, vec![2, 3]);
+  let mut a = a_source.clone();
+  a.delete_at_mut(2);
+  assert_seq_equal(&a, vec![1, 2]);
+  let mut e = e_source.clone();
+  assert!(panic::catch_unwind(move || e.delete_at_mut(0)).is_err());
+
+  // delete_at_multi_mut
+  let mut a = a_source.clone();
+  a.delete_at_multi_mut(vec![0, 2]);
+  assert_seq_equal(&a, vec![2]);
+  let mut a = a_source.clone();
+  a.delete_at_multi_mut(vec![2, 0, 0]);
+  assert_seq_equal(&a, vec![2]);
+  let mut a = a_source.clone();
+  a.delete_at_multi_mut(vec![0, 1, 2]);
+  assert_seq_equal(&a, vec![]);
+  let mut a = a_source.clone();
+  assert!(panic::catch_unwind(move || a.delete_at_multi_mut(vec![0, 3])).is_err());
+
+  // delete_mut
+  let mut b = b_source.clone();
+  let mut e = e_source.clone();
+  b.delete_mut(&2);
+  assert_seq_equal(&b, vec![1, 2, 3]);
+  e.delete_mut(&2);
+  assert_seq_equal(&e, vec![]);
+
+  // move_at_mut
+  let mut a = a_source.clone();
+  a.move_at_mut(0, 2);
+  assert_seq_equal(&a, vec![2, 3, 1]);
+  let mut a = a_source.clone();
+  a.move_at_mut(2, 1);
+  assert_seq_equal(&a, vec![1, 3, 2]);
+  let mut a = a_source.clone();
+  a.move_at_mut(1, 1);
+  assert_seq_equal(&a, vec![1, 2, 3]);
+  let mut e = e_source.clone();
+  assert!(panic::catch_unwind(move || e.move_at_mut(0, 0)).is_err());
+
+  // pad_right_mut
+  let mut a = a_source.clone();
+  let mut e = e_source.clone();
+  a.pad_right_mut(5, 4);
+  assert_seq_equal(&a, vec![1, 2, 3, 4, 4]);
+  let mut a = a_source.clone();
+  a.pad_right_mut(2, 4);
+  assert_seq_equal(&a, vec![1, 2, 3]);
+  e.pad_right_mut(1, 1);
+  assert_seq_equal(&e, vec![1]);
+
+  // sorted_mut
+  let mut b = C::from_iter(vec![2, 3, 1, 2]);
+  let mut e = e_source.clone();
+  b.sorted_mut();
+  assert_seq_equal(&b, vec![1, 2, 2, 3]);
+  e.sorted_mut();
+  assert_seq_equal(&e, vec![]);
+
+  // sorted_by_mut
+  let mut a = a_source.clone();
+  a.sorted_by_mut(|x, y| y.cmp(x));
+  assert_seq_equal(&a, vec![3, 2, 1]);
+
+  // sorted_by_key_mut
+  let mut a = a_source.clone();
+  a.sorted_by_key_mut(|&x| -x);
+  assert_seq_equal(&a, vec![3, 2, 1]);
+
+  // substitute_mut
+  let mut b = b_source.clone();
+  let mut e = e_source.clone();
+  b.substitute_mut(&2, 4);
+  assert_seq_equal(&b, vec![1, 4, 2, 3]);
+  e.substitute_mut(&2, 4);
+  assert_seq_equal(&e, vec![]);
+
+  // substitute_at_mut
+  let mut a = a_source.clone();
+  a.substitute_at_mut(1, 4);
+  assert_seq_equal(&a, vec![1, 4, 3]);
+  let mut a = a_source.clone();
+  assert!(panic::catch_unwind(move || a.substitute_at_mut(3, 4)).is_err());
+
+  // swap_at_mut
+  let mut a = a_source.clone();
+  a.swap_at_mut(0, 2);
+  assert_seq_equal(&a, vec![3, 2, 1]);
+  let mut a = a_source.clone();
+  a.swap_at_mut(2, 1);
+  assert_seq_equal(&a, vec![1, 3, 2]);
+  let mut a = a_source.clone();
+  a.swap_at_mut(1, 1);
+  assert_seq_equal(&a, vec![1, 2, 3]);
+  let mut a = a_source.clone();
+  assert!(panic::catch_unwind(move || a.swap_at_mut(0, 3)).is_err());
+
+  // unique_mut
+  let mut b = b_source.clone();
+  let mut e = e_source.clone();
+  b.unique_mut();
+  assert_seq_equal(&b, vec![1, 2, 3]);
+  e.unique_mut();
+  assert_seq_equal(&e, vec![]);
+}
@@ -1,7 +1,7 @@
 use std::panic;
 use std::panic::UnwindSafe;
 
-use cantrip::SequenceTo;
+use cantrip::{CollectionTo, DiffHunk, NanPolicy, Parallelism, SequenceTo};
 
 use crate::extensions::util::{TestCollectible, TestCollection, TestSequence, assert_seq_equal, assert_vec_seq_equal};
 
@@ -45,6 +45,37 @@ where
   let a = a_source.clone();
   assert!(panic::catch_unwind(|| { a.add_at_multi(4, vec![1, 2]) }).is_err());
 
+  // apply_diff
+  let a = a_source.clone();
+  let b = b_source.clone();
+  let e = e_source.clone();
+  let script = a.diff(&vec![1, 2, 2, 3]);
+  assert_seq_equal(&a.apply_diff(&script), vec![1, 2, 2, 3]);
+  let script = b.diff(&vec![3, 2, 1]);
+  assert_seq_equal(&b.apply_diff(&script), vec![3, 2, 1]);
+  let a = a_source.clone();
+  assert_seq_equal(
+    &a.apply_diff(&vec![DiffHunk::Replace {
+      source_index: 1,
+      target_index: 1,
+      length: 2,
+      elements: vec![4],
+    }]),
+    vec![1, 4],
+  );
+  let a = a_source.clone();
+  assert!(
+    panic::catch_unwind(|| {
+      a.apply_diff(&vec![DiffHunk::Delete {
+        source_index: 2,
+        target_index: 2,
+        length: 2,
+      }])
+    })
+    .is_err()
+  );
+  assert_seq_equal(&e.apply_diff(&vec![]), vec![]);
+
   // cartesian_product
   let a = a_source.clone();
   let e = e_source.clone();
@@ -54,8 +85,23 @@ where
     a.cartesian_product(2),
     &vec![vec![1, 1], vec![1, 2], vec![1, 3], vec![2, 1], vec![2, 2], vec![2, 3], vec![3, 1], vec![3, 2], vec![3, 3]],
   );
+  assert_eq!(a.cartesian_product(4).len(), 81);
+  assert_vec_seq_equal(e.cartesian_product(0), &vec![vec![]]);
   assert_vec_seq_equal(e.cartesian_product(2), &vec![]);
 
+  // cartesian_product_iter
+  let a = a_source.clone();
+  let e = e_source.clone();
+  let mut product = a.cartesian_product_iter(2);
+  assert_eq!(product.size_hint(), (9, Some(9)));
+  let _unused = product.next();
+  assert_eq!(product.size_hint(), (8, Some(8)));
+  assert_vec_seq_equal(a.cartesian_product_iter(1).collect(), &vec![vec![1], vec![2], vec![3]]);
+  assert_vec_seq_equal(a.cartesian_product_iter(0).collect(), &vec![vec![]]);
+  assert_vec_seq_equal(e.cartesian_product_iter(0).collect(), &vec![vec![]]);
+  assert_eq!(a.cartesian_product_iter(4).count(), 81);
+  assert_eq!(e.cartesian_product_iter(2).size_hint(), (0, Some(0)));
+
   // chunked
   let a = a_source.clone();
   let e = e_source.clone();
@@ -120,8 +166,24 @@ where
       vec![3, 3, 3],
     ],
   );
+  assert_eq!(a.combinations_multi(4).len(), 15);
+  assert_vec_seq_equal(e.combinations_multi(0), &vec![vec![]]);
   assert_vec_seq_equal(e.combinations_multi(1), &vec![]);
 
+  // combinations_multi_iter
+  let a = a_source.clone();
+  let e = e_source.clone();
+  let mut combinations = a.combinations_multi_iter(3);
+  assert_eq!(combinations.size_hint(), (10, Some(10)));
+  let _unused = combinations.next();
+  assert_eq!(combinations.size_hint(), (9, Some(9)));
+  assert_vec_seq_equal(
+    a.combinations_multi_iter(2).collect(),
+    &vec![vec![1, 1], vec![1, 2], vec![1, 3], vec![2, 2], vec![2, 3], vec![3, 3]],
+  );
+  assert_eq!(a.combinations_multi_iter(4).count(), 15);
+  assert_eq!(e.combinations_multi_iter(1).size_hint(), (0, Some(0)));
+
   // delete_at
   let a = a_source.clone();
   assert_seq_equal(&a.delete_at(0), vec![2, 3]);
@@ -154,6 +216,22 @@ where
   let b = b_source.clone();
   assert_vec_seq_equal(b.divide_by(|x| x % 2 == 0), &vec![vec![1], vec![], vec![3]]);
 
+  // divide_sequence
+  let b = b_source.clone();
+  let e = e_source.clone();
+  assert_vec_seq_equal(b.divide_sequence(&vec![2, 2]), &vec![vec![1], vec![3]]);
+  let b = b_source.clone();
+  assert_vec_seq_equal(b.divide_sequence(&vec![1, 2]), &vec![vec![], vec![2, 3]]);
+  let b = b_source.clone();
+  assert_vec_seq_equal(b.divide_sequence(&vec![2]), &vec![vec![1], vec![], vec![3]]);
+  let b = b_source.clone();
+  assert_vec_seq_equal(b.divide_sequence(&vec![3, 4]), &vec![vec![1, 2, 2, 3]]);
+  let b = b_source.clone();
+  assert_vec_seq_equal(b.divide_sequence(&vec![]), &vec![vec![], vec![1], vec![2], vec![2], vec![3], vec![]]);
+  assert_vec_seq_equal(e.divide_sequence(&vec![1]), &vec![vec![]]);
+  let e = e_source.clone();
+  assert_vec_seq_equal(e.divide_sequence(&vec![]), &vec![vec![], vec![]]);
+
   // duplicates
   let a = a_source.clone();
   let e = e_source.clone();
@@ -172,6 +250,18 @@ where
   assert_seq_equal(&a.duplicates_by(|x| x % 2), vec![1, 3]);
   assert_seq_equal(&e.duplicates_by(|x| x % 2), vec![]);
 
+  // duplicates_eq
+  let b = b_source.clone();
+  let e = e_source.clone();
+  assert_seq_equal(&b.duplicates_eq(), vec![2]);
+  assert_seq_equal(&e.duplicates_eq(), vec![]);
+
+  // duplicates_ord
+  let b = b_source.clone();
+  let e = e_source.clone();
+  assert_seq_equal(&b.duplicates_ord(), vec![2]);
+  assert_seq_equal(&e.duplicates_ord(), vec![]);
+
   // enumerate
   let a = a_source.clone();
   let e = e_source.clone();
@@ -232,6 +322,14 @@ where
   assert_seq_equal(&a.map_while(|&x| if x < 2 { Some(x + 1) } else { None }), vec![2, 3]);
   assert_seq_equal(&e.map_while(|&x| if x < 2 { Some(x + 1) } else { None }), vec![]);
 
+  // map_validated_indexed
+  let a = a_source.clone();
+  let b = b_source.clone();
+  let e = e_source.clone();
+  assert_seq_equal(&a.map_validated_indexed(|x| if x < 4 { Ok(x + 1) } else { Err(x) }).unwrap(), vec![2, 3, 4]);
+  assert_eq!(b.map_validated_indexed(|x| if x < 2 { Ok(x) } else { Err(x) }).err(), Some(vec![(1, 2), (2, 2), (3, 3)]));
+  assert_seq_equal(&e.map_validated_indexed(|x| if x < 4 { Ok(x + 1) } else { Err(x) }).unwrap(), vec![]);
+
   // merge
   let a = a_source.clone();
   let e = e_source.clone();
@@ -282,6 +380,60 @@ where
   assert_seq_equal(&a.pad_right_with(5, |i| 2 * i as i64), vec![1, 2, 3, 6, 8]);
   assert_seq_equal(&e.pad_right_with(1, |i| 2 * i as i64), vec![0]);
 
+  // par_sorted
+  let c = c_source.clone();
+  let e = e_source.clone();
+  let parallelism = Parallelism { threads: 2, threshold: 0 };
+  assert_seq_equal(&c.par_sorted(parallelism), vec![1, 2, 3]);
+  assert_seq_equal(&e.par_sorted(parallelism), vec![]);
+  let values = (0..100).map(|x| (x * 37) % 100).collect::<Vec<i64>>();
+  assert_eq!(values.clone().par_sorted(Parallelism { threads: 3, threshold: 10 }), values.sorted());
+
+  // par_sorted_by
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_seq_equal(&a.par_sorted_by(parallelism, |x, y| y.cmp(x)), vec![3, 2, 1]);
+  assert_seq_equal(&e.par_sorted_by(parallelism, |x, y| y.cmp(x)), vec![]);
+
+  // par_sorted_by_key
+  let a = a_source.clone();
+  let e = e_source.clone();
+  assert_seq_equal(&a.par_sorted_by_key(parallelism, |&x| -x), vec![3, 2, 1]);
+  assert_seq_equal(&e.par_sorted_by_key(parallelism, |&x| -x), vec![]);
+  let values = (0..100).map(|x| ((x * 37) % 10, x)).collect::<Vec<(i64, i64)>>();
+  assert_eq!(
+    values.clone().par_sorted_by_key(Parallelism { threads: 4, threshold: 10 }, |x| x.0),
+    values.sorted_by_key(|x| x.0)
+  );
+
+  // replace_sequence
+  let b = b_source.clone();
+  let e = e_source.clone();
+  assert_seq_equal(&b.replace_sequence(&vec![2, 2], vec![4, 5, 6]), vec![1, 4, 5, 6, 3]);
+  let b = b_source.clone();
+  assert_seq_equal(&b.replace_sequence(&vec![2], vec![0]), vec![1, 0, 0, 3]);
+  let b = b_source.clone();
+  assert_seq_equal(&b.replace_sequence(&vec![1, 2], vec![]), vec![2, 3]);
+  let b = b_source.clone();
+  assert_seq_equal(&b.replace_sequence(&vec![3, 4], vec![0]), vec![1, 2, 2, 3]);
+  let b = b_source.clone();
+  assert_seq_equal(&b.replace_sequence(&vec![], vec![0]), vec![0, 1, 0, 2, 0, 2, 0, 3, 0]);
+  assert_seq_equal(&e.replace_sequence(&vec![1], vec![0]), vec![]);
+  let e = e_source.clone();
+  assert_seq_equal(&e.replace_sequence(&vec![], vec![0]), vec![0]);
+
+  // replace_sequence_n
+  let b = b_source.clone();
+  let e = e_source.clone();
+  assert_seq_equal(&b.replace_sequence_n(&vec![2], vec![0], 1), vec![1, 0, 2, 3]);
+  let b = b_source.clone();
+  assert_seq_equal(&b.replace_sequence_n(&vec![2], vec![0], 0), vec![1, 2, 2, 3]);
+  let b = b_source.clone();
+  assert_seq_equal(&b.replace_sequence_n(&vec![2], vec![0], 5), vec![1, 0, 0, 3]);
+  let b = b_source.clone();
+  assert_seq_equal(&b.replace_sequence_n(&vec![], vec![0], 2), vec![0, 1, 0, 2, 2, 3]);
+  assert_seq_equal(&e.replace_sequence_n(&vec![1], vec![0], 1), vec![]);
+
   // rev
   let a = a_source.clone();
   let b = b_source.clone();
@@ -326,6 +478,12 @@ where
   );
   assert_seq_equal(&e.scan_ref(1, |_, &x| Some(x)), vec![]);
 
+  // select_nth
+  let a = a_source.clone();
+  assert_seq_equal(&a.select_nth(1), vec![1, 2, 3]);
+  let a = a_source.clone();
+  assert!(panic::catch_unwind(|| { a.select_nth(3) }).is_err());
+
   // skip
   let a = a_source.clone();
   let e = e_source.clone();
@@ -378,6 +536,16 @@ where
   assert_seq_equal(&c.sorted_by_key(|&k| -k), vec![3, 2, 1]);
   assert_seq_equal(&e.sorted_by_key(|&k| -k), vec![]);
 
+  // sorted_insert
+  let b = b_source.clone();
+  let e = e_source.clone();
+  assert_seq_equal(&b.sorted_insert(2), vec![1, 2, 2, 2, 3]);
+  let b = b_source.clone();
+  assert_seq_equal(&b.sorted_insert(0), vec![0, 1, 2, 2, 3]);
+  let b = b_source.clone();
+  assert_seq_equal(&b.sorted_insert(4), vec![1, 2, 2, 3, 4]);
+  assert_seq_equal(&e.sorted_insert(1), vec![1]);
+
   // sorted_unstable
   let c = c_source.clone();
   let e = e_source.clone();
@@ -466,6 +634,18 @@ where
   assert_seq_equal(&b.unique_by(|x| x % 2), vec![1, 2]);
   assert_seq_equal(&e.unique_by(|x| x % 2), vec![]);
 
+  // unique_eq
+  let b = b_source.clone();
+  let e = e_source.clone();
+  assert_seq_equal(&b.unique_eq(), vec![1, 2, 3]);
+  assert_seq_equal(&e.unique_eq(), vec![]);
+
+  // unique_ord
+  let b = b_source.clone();
+  let e = e_source.clone();
+  assert_seq_equal(&b.unique_ord(), vec![1, 2, 3]);
+  assert_seq_equal(&e.unique_ord(), vec![]);
+
   // unzip
   let g = g_source.clone();
   let e = g_source.clone().filter(|_| false);
@@ -488,6 +668,20 @@ where
   );
   assert_vec_seq_equal(e.variations(1), &vec![]);
 
+  // variations_iter
+  let a = a_source.clone();
+  let e = e_source.clone();
+  let mut variations = a.variations_iter(3);
+  assert_eq!(variations.size_hint(), (6, Some(6)));
+  let _unused = variations.next();
+  assert_eq!(variations.size_hint(), (5, Some(5)));
+  assert_vec_seq_equal(
+    a.variations_iter(2).collect(),
+    &vec![vec![1, 2], vec![1, 3], vec![2, 1], vec![2, 3], vec![3, 1], vec![3, 2]],
+  );
+  assert_eq!(a.variations_iter(4).size_hint(), (0, Some(0)));
+  assert_eq!(e.variations_iter(1).size_hint(), (0, Some(0)));
+
   // windowed
   let a = a_source.clone();
   let e = e_source.clone();
@@ -524,3 +718,32 @@ where
   assert_seq_equal(&a.zip_padded(vec![4_i64, 5], || 1, || 2), vec![(1, 4), (2, 5), (3, 2)]);
   assert_seq_equal(&e.zip_padded(vec![1_i64], || 1, || 2), vec![(1, 1)]);
 }
+
+pub(crate) fn test_sequence_to_float<C>(a: &C, n: &C, e: &C)
+where
+  C: SequenceTo<f64> + CollectionTo<f64> + IntoIterator<Item = f64> + FromIterator<f64> + Clone,
+  for<'i> &'i C: IntoIterator<Item = &'i f64>,
+{
+  // sorted_floats
+  assert_eq!(a.clone().sorted_floats(NanPolicy::Total).into_iter().collect::<Vec<_>>(), vec![1.0, 2.0, 3.0]);
+  let sorted =
+    n.clone().add(-0.0).add(0.0).add(-f64::NAN).sorted_floats(NanPolicy::Total).into_iter().collect::<Vec<_>>();
+  assert_eq!(
+    sorted.iter().map(|x| x.to_bits()).collect::<Vec<_>>(),
+    vec![
+      (-f64::NAN).to_bits(),
+      (-0.0_f64).to_bits(),
+      0.0_f64.to_bits(),
+      1.0_f64.to_bits(),
+      2.0_f64.to_bits(),
+      f64::NAN.to_bits(),
+    ]
+  );
+  assert_eq!(n.clone().sorted_floats(NanPolicy::Skip).into_iter().collect::<Vec<_>>(), vec![1.0, 2.0]);
+  let sorted = n.clone().add(-f64::NAN).sorted_floats(NanPolicy::Propagate).into_iter().collect::<Vec<_>>();
+  assert_eq!(
+    sorted.iter().map(|x| x.to_bits()).collect::<Vec<_>>(),
+    vec![f64::NAN.to_bits(), (-f64::NAN).to_bits(), 1.0_f64.to_bits(), 2.0_f64.to_bits()]
+  );
+  assert_eq!(e.clone().sorted_floats(NanPolicy::Total).into_iter().collect::<Vec<_>>(), vec![]);
+}
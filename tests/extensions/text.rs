@@ -0,0 +1,223 @@
+use std::collections::HashMap;
+use std::panic;
+
+use cantrip::Text;
+
+#[allow(clippy::too_many_lines)]
+pub(crate) fn test_text() {
+  let a_source = String::from("abcbca");
+  let b_source = "čaj a káva";
+  let e_source = "";
+
+  // add_at
+  assert_eq!(a_source.add_at(6, 'd'), "abcbcad");
+  assert_eq!(b_source.add_at(1, 'ř'), "čřaj a káva");
+  assert_eq!(e_source.add_at(0, 'd'), "d");
+  assert!(panic::catch_unwind(|| e_source.add_at(1, 'd')).is_err());
+
+  // all
+  assert!(a_source.all(|x| x.is_alphabetic()));
+  assert!(!b_source.all(|x| x.is_alphabetic()));
+  assert!(e_source.all(|x| x.is_alphabetic()));
+
+  // any
+  assert!(a_source.any(|&x| x == 'c'));
+  assert!(b_source.any(|&x| x == 'á'));
+  assert!(!e_source.any(|&x| x == 'c'));
+
+  // chunked
+  assert_eq!(a_source.chunked(4), vec!["abcb", "ca"]);
+  assert_eq!(b_source.chunked(4), vec!["čaj ", "a ká", "va"]);
+  assert_eq!(e_source.chunked(4), Vec::<String>::new());
+
+  // chunked_exact
+  assert_eq!(a_source.chunked_exact(4), vec!["abcb"]);
+  assert_eq!(b_source.chunked_exact(4), vec!["čaj ", "a ká"]);
+  assert_eq!(e_source.chunked_exact(4), Vec::<String>::new());
+
+  // count_by
+  assert_eq!(a_source.count_by(|&x| x == 'b'), 2);
+  assert_eq!(b_source.count_by(|x| x.is_whitespace()), 2);
+  assert_eq!(e_source.count_by(|&x| x == 'b'), 0);
+
+  // count_unique
+  assert_eq!(a_source.count_unique(), 3);
+  assert_eq!(b_source.count_unique(), 7);
+  assert_eq!(e_source.count_unique(), 0);
+
+  // delete
+  assert_eq!(a_source.delete('b'), "acbca");
+  assert_eq!(b_source.delete('č'), "aj a káva");
+  assert_eq!(a_source.delete('d'), "abcbca");
+  assert_eq!(e_source.delete('b'), "");
+
+  // delete_at
+  assert_eq!(a_source.delete_at(5), "abcbc");
+  assert_eq!(b_source.delete_at(0), "aj a káva");
+  assert!(panic::catch_unwind(|| e_source.delete_at(0)).is_err());
+
+  // duplicates
+  assert_eq!(a_source.duplicates(), "bca");
+  assert_eq!(b_source.duplicates(), "a ");
+  assert_eq!(e_source.duplicates(), "");
+
+  // filter
+  assert_eq!(a_source.filter(|&x| x != 'b'), "acca");
+  assert_eq!(b_source.filter(|x| !x.is_whitespace()), "čajakáva");
+  assert_eq!(e_source.filter(|&x| x != 'b'), "");
+
+  // filter_map
+  assert_eq!(a_source.filter_map(|&x| if x == 'b' { None } else { Some(x.to_ascii_uppercase()) }), "ACCA");
+  assert_eq!(e_source.filter_map(|&x| Some(x)), "");
+
+  // find_map
+  assert_eq!(a_source.find_map(|&x| if x > 'a' { Some(x.to_ascii_uppercase()) } else { None }), Some('B'));
+  assert_eq!(b_source.find_map(|&x| if x.is_ascii() { None } else { Some(x) }), Some('č'));
+  assert_eq!(e_source.find_map(|&x| Some(x)), None);
+
+  // fold
+  assert_eq!(a_source.fold(0, |r, &x| if x == 'a' { r + 1 } else { r }), 2);
+  assert_eq!(e_source.fold(0, |r, _| r + 1), 0);
+
+  // frequencies
+  assert_eq!(a_source.frequencies(), HashMap::from([('a', 2), ('b', 2), ('c', 2)]));
+  assert_eq!(b_source.frequencies()[&'a'], 3);
+  assert_eq!(e_source.frequencies(), HashMap::new());
+
+  // group_by
+  assert_eq!(a_source.group_by(|&x| x == 'a'), HashMap::from([(true, "aa".to_string()), (false, "bcbc".to_string())]));
+  assert_eq!(b_source.group_by(char::is_ascii)[&false], "čá");
+  assert_eq!(e_source.group_by(|&x| x == 'a'), HashMap::new());
+
+  // init
+  assert_eq!(a_source.init(), "abcbc");
+  assert_eq!(b_source.init(), "čaj a káv");
+  assert_eq!(e_source.init(), "");
+
+  // intersperse
+  assert_eq!(a_source.intersperse(2, '-'), "ab-cb-ca");
+  assert_eq!(b_source.intersperse(4, '|'), "čaj |a ká|va");
+  assert_eq!(e_source.intersperse(2, '-'), "");
+
+  // map
+  assert_eq!(a_source.map(char::to_ascii_uppercase), "ABCBCA");
+  assert_eq!(b_source.map(|&x| if x == ' ' { '_' } else { x }), "čaj_a_káva");
+  assert_eq!(e_source.map(char::to_ascii_uppercase), "");
+
+  // max_by
+  assert_eq!(a_source.max_by(Ord::cmp), Some('c'));
+  assert_eq!(b_source.max_by(Ord::cmp), Some('č'));
+  assert_eq!(e_source.max_by(Ord::cmp), None);
+
+  // max_by_key
+  assert_eq!(a_source.max_by_key(|&x| x != 'c'), Some('a'));
+  assert_eq!(e_source.max_by_key(|&x| x), None);
+
+  // max_of
+  assert_eq!(a_source.max_of(), Some('c'));
+  assert_eq!(b_source.max_of(), Some('č'));
+  assert_eq!(e_source.max_of(), None);
+
+  // min_by
+  assert_eq!(a_source.min_by(Ord::cmp), Some('a'));
+  assert_eq!(b_source.min_by(Ord::cmp), Some(' '));
+  assert_eq!(e_source.min_by(Ord::cmp), None);
+
+  // min_by_key
+  assert_eq!(a_source.min_by_key(|&x| x == 'a'), Some('b'));
+  assert_eq!(e_source.min_by_key(|&x| x), None);
+
+  // min_of
+  assert_eq!(a_source.min_of(), Some('a'));
+  assert_eq!(b_source.min_of(), Some(' '));
+  assert_eq!(e_source.min_of(), None);
+
+  // minmax_by
+  assert_eq!(a_source.minmax_by(Ord::cmp), Some(('a', 'c')));
+  assert_eq!(b_source.minmax_by(Ord::cmp), Some((' ', 'č')));
+  assert_eq!(e_source.minmax_by(Ord::cmp), None);
+
+  // minmax_by_key
+  assert_eq!(a_source.minmax_by_key(|&x| x == 'b'), Some(('a', 'b')));
+  assert_eq!(e_source.minmax_by_key(|&x| x), None);
+
+  // minmax_of
+  assert_eq!(a_source.minmax_of(), Some(('a', 'c')));
+  assert_eq!(b_source.minmax_of(), Some((' ', 'č')));
+  assert_eq!(e_source.minmax_of(), None);
+
+  // pad_left
+  assert_eq!(a_source.pad_left(8, '_'), "__abcbca");
+  assert_eq!(b_source.pad_left(11, 'ř'), "řčaj a káva");
+  assert_eq!(e_source.pad_left(2, '_'), "__");
+
+  // pad_right
+  assert_eq!(a_source.pad_right(8, '_'), "abcbca__");
+  assert_eq!(b_source.pad_right(11, 'ř'), "čaj a kávař");
+  assert_eq!(e_source.pad_right(2, '_'), "__");
+
+  // position
+  assert_eq!(a_source.position(|&x| x == 'c'), Some(2));
+  assert_eq!(b_source.position(|&x| x == 'k'), Some(6));
+  assert_eq!(e_source.position(|&x| x == 'c'), None);
+
+  // position_multi
+  assert_eq!(a_source.position_multi(|&x| x == 'c'), vec![2, 4]);
+  assert_eq!(b_source.position_multi(|x| x.is_whitespace()), vec![3, 5]);
+  assert_eq!(e_source.position_multi(|&x| x == 'c'), Vec::<usize>::new());
+
+  // position_sequence
+  assert_eq!(a_source.position_sequence("bc"), Some(1));
+  assert_eq!(b_source.position_sequence("káva"), Some(6));
+  assert_eq!(a_source.position_sequence("d"), None);
+  assert_eq!(e_source.position_sequence("a"), None);
+
+  // position_sequence_multi
+  assert_eq!(a_source.position_sequence_multi("bc"), vec![1, 3]);
+  assert_eq!(b_source.position_sequence_multi("a"), vec![1, 4, 9]);
+  assert_eq!(e_source.position_sequence_multi("a"), Vec::<usize>::new());
+
+  // rev
+  assert_eq!(a_source.rev(), "acbcba");
+  assert_eq!(b_source.rev(), "avák a jač");
+  assert_eq!(e_source.rev(), "");
+
+  // rposition
+  assert_eq!(a_source.rposition(|&x| x == 'c'), Some(4));
+  assert_eq!(b_source.rposition(|&x| x == 'á'), Some(7));
+  assert_eq!(e_source.rposition(|&x| x == 'c'), None);
+
+  // sorted
+  assert_eq!(a_source.sorted(), "aabbcc");
+  assert_eq!(e_source.sorted(), "");
+
+  // substitute
+  assert_eq!(a_source.substitute('b', 'd'), "adcbca");
+  assert_eq!(b_source.substitute('á', 'a'), "čaj a kava");
+  assert_eq!(e_source.substitute('b', 'd'), "");
+
+  // substitute_at
+  assert_eq!(a_source.substitute_at(0, 'd'), "dbcbca");
+  assert_eq!(b_source.substitute_at(0, 'c'), "caj a káva");
+  assert!(panic::catch_unwind(|| e_source.substitute_at(0, 'd')).is_err());
+
+  // swap_at
+  assert_eq!(a_source.swap_at(0, 1), "bacbca");
+  assert_eq!(b_source.swap_at(0, 9), "aaj a kávč");
+  assert!(panic::catch_unwind(|| e_source.swap_at(0, 1)).is_err());
+
+  // tail
+  assert_eq!(a_source.tail(), "bcbca");
+  assert_eq!(b_source.tail(), "aj a káva");
+  assert_eq!(e_source.tail(), "");
+
+  // unique
+  assert_eq!(a_source.unique(), "abc");
+  assert_eq!(b_source.unique(), "čaj káv");
+  assert_eq!(e_source.unique(), "");
+
+  // windowed
+  assert_eq!(a_source.windowed(5, 1), vec!["abcbc", "bcbca"]);
+  assert_eq!(b_source.windowed(4, 3), vec!["čaj ", " a k", "káva"]);
+  assert_eq!(e_source.windowed(1, 1), Vec::<String>::new());
+}
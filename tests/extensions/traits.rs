@@ -1,6 +1,6 @@
 use std::panic::UnwindSafe;
 
-use cantrip::{Collection, CollectionTo, Convert, List, Map, SequenceTo};
+use cantrip::{Collection, CollectionTo, Convert, List, Map, SequenceMut, SequenceTo};
 
 use crate::extensions::collection::test_collection;
 use crate::extensions::collection_to::test_collection_to;
@@ -8,6 +8,7 @@ use crate::extensions::convert::test_convert;
 use crate::extensions::list::test_list;
 use crate::extensions::map::test_map;
 use crate::extensions::sequence::test_sequence;
+use crate::extensions::sequence_mut::test_sequence_mut;
 use crate::extensions::sequence_to::test_sequence_to;
 use crate::extensions::slice::test_slice;
 use crate::extensions::util::{TestCollectible, TestCollection, TestMap, TestSequence};
@@ -15,7 +16,7 @@ use crate::extensions::util::{TestCollectible, TestCollection, TestMap, TestSequ
 #[allow(clippy::many_single_char_names)]
 pub(crate) fn test_list_traits<'a, C, D, G>(a: &C, b: &C, c: &C, d: &D, g: &G, e: &C)
 where
-  C: List<i64> + TestSequence<'a, i64> + TestCollectible<'a, i64> + UnwindSafe,
+  C: List<i64> + SequenceMut<i64> + TestSequence<'a, i64> + TestCollectible<'a, i64> + UnwindSafe,
   <C as IntoIterator>::IntoIter: DoubleEndedIterator<Item = i64> + ExactSizeIterator<Item = i64>,
   for<'i> &'i C: IntoIterator<Item = &'i i64>,
   <C as CollectionTo<i64>>::This<i64>: TestCollection<i64>,
@@ -34,6 +35,7 @@ where
   test_collection_to(true, a, b, d, e);
   test_sequence(a, b, e);
   test_sequence_to(a, b, c, g, e);
+  test_sequence_mut(a, b, e);
   test_list(a, e);
   test_convert(true, a, g, e);
 }
@@ -67,7 +69,7 @@ where
 #[allow(clippy::many_single_char_names)]
 pub(crate) fn test_sequence_traits<'a, C, D, G>(a: &C, b: &C, c: &C, d: &D, g: &G, e: &C)
 where
-  C: TestSequence<'a, i64> + TestCollectible<'a, i64> + UnwindSafe,
+  C: SequenceMut<i64> + TestSequence<'a, i64> + TestCollectible<'a, i64> + UnwindSafe,
   <C as IntoIterator>::IntoIter: DoubleEndedIterator<Item = i64> + ExactSizeIterator<Item = i64>,
   for<'i> &'i C: IntoIterator<Item = &'i i64>,
   <C as CollectionTo<i64>>::This<i64>: TestCollection<i64>,
@@ -86,6 +88,7 @@ where
   test_collection_to(true, a, b, d, e);
   test_sequence(a, b, e);
   test_sequence_to(a, b, c, g, e);
+  test_sequence_mut(a, b, e);
   test_convert(true, a, g, e);
 }
 
@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+use cantrip::traversal::Traversal;
+
+pub(crate) fn test_traversal() {
+  let a_source = vec![1_i64, 2, 3];
+  let b_source = vec![1_i64, 2, 2, 3];
+  let e_source = Vec::<i64>::new();
+  let a = || a_source.clone().into_iter();
+  let b = || b_source.clone().into_iter();
+  let e = || e_source.clone().into_iter();
+
+  // chunked
+  assert_eq!(a().chunked(2).collect::<Vec<_>>(), vec![vec![1, 2], vec![3]]);
+  assert_eq!(a().chunked(3).collect::<Vec<_>>(), vec![vec![1, 2, 3]]);
+  assert_eq!(e().chunked(1).collect::<Vec<_>>(), Vec::<Vec<i64>>::new());
+  assert_eq!((1..).chunked(2).take(2).collect::<Vec<_>>(), vec![vec![1, 2], vec![3, 4]]);
+
+  // coalesce
+  assert_eq!(b().coalesce(|p, c| if p == c { Ok(p + c) } else { Err((p, c)) }).collect::<Vec<_>>(), vec![1, 4, 3]);
+  assert_eq!(e().coalesce(|p, c| if p == c { Ok(p + c) } else { Err((p, c)) }).collect::<Vec<_>>(), vec![]);
+
+  // divide_by
+  assert_eq!(b().divide_by(|x| x % 2 == 0).collect::<Vec<_>>(), vec![vec![1], vec![], vec![3]]);
+  assert_eq!(e().divide_by(|x| x % 2 == 0).collect::<Vec<_>>(), vec![vec![]]);
+
+  // duplicates
+  assert_eq!(b().duplicates().collect::<Vec<_>>(), vec![2]);
+  assert_eq!(a().duplicates().collect::<Vec<_>>(), vec![]);
+  assert_eq!(e().duplicates().collect::<Vec<_>>(), vec![]);
+
+  // frequencies_by
+  assert_eq!(b().frequencies_by(|x| x % 2), HashMap::from([(0, 2), (1, 2)]));
+  assert_eq!(e().frequencies_by(|x| x % 2), HashMap::new());
+
+  // group_by
+  assert_eq!(a().group_by(|x| x % 2), HashMap::from([(0, vec![2]), (1, vec![1, 3])]));
+  assert_eq!(e().group_by(|x| x % 2), HashMap::new());
+
+  // interleave
+  assert_eq!(a().interleave(vec![4, 5]).collect::<Vec<_>>(), vec![1, 4, 2, 5, 3]);
+  assert_eq!(e().interleave(vec![4, 5]).collect::<Vec<_>>(), vec![4, 5]);
+
+  // intersperse_every
+  assert_eq!(a().intersperse_every(1, 0).collect::<Vec<_>>(), vec![1, 0, 2, 0, 3]);
+  assert_eq!(a().intersperse_every(3, 0).collect::<Vec<_>>(), vec![1, 2, 3]);
+  assert_eq!(e().intersperse_every(1, 0).collect::<Vec<_>>(), vec![]);
+
+  // joined
+  assert_eq!(a().joined(", "), "1, 2, 3");
+  assert_eq!(e().joined(", "), "");
+
+  // minmax_by
+  assert_eq!(a().minmax_by(Ord::cmp), Some((1, 3)));
+  assert_eq!(b().map(|x| (x % 2, x)).minmax_by(|x, y| x.0.cmp(&y.0)), Some(((0, 2), (1, 3))));
+  assert_eq!(e().minmax_by(Ord::cmp), None);
+
+  // pad_right
+  assert_eq!(a().pad_right(5, 4).collect::<Vec<_>>(), vec![1, 2, 3, 4, 4]);
+  assert_eq!(a().pad_right(2, 4).collect::<Vec<_>>(), vec![1, 2, 3]);
+  assert_eq!(e().pad_right(1, 1).collect::<Vec<_>>(), vec![1]);
+
+  // unique_by
+  assert_eq!(b().unique_by(|x| x % 2).collect::<Vec<_>>(), vec![1, 2]);
+  assert_eq!(e().unique_by(|x| x % 2).collect::<Vec<_>>(), vec![]);
+
+  // windowed
+  assert_eq!(a().windowed(2, 1).collect::<Vec<_>>(), vec![vec![1, 2], vec![2, 3]]);
+  assert_eq!(a().windowed(2, 2).collect::<Vec<_>>(), vec![vec![1, 2]]);
+  assert_eq!(a().windowed(4, 1).collect::<Vec<_>>(), Vec::<Vec<i64>>::new());
+  assert_eq!(e().windowed(1, 1).collect::<Vec<_>>(), Vec::<Vec<i64>>::new());
+
+  // zip_padded
+  assert_eq!(a().zip_padded(vec![4, 5], || 1, || 2).collect::<Vec<_>>(), vec![(1, 4), (2, 5), (3, 2)]);
+  assert_eq!(e().zip_padded(vec![4], || 1, || 2).collect::<Vec<_>>(), vec![(1, 4)]);
+}
@@ -2,7 +2,7 @@ use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedL
 use std::fmt::Debug;
 use std::hash::Hash;
 
-use cantrip::{Collection, CollectionTo, Iterable, Map, Sequence, SequenceTo};
+use cantrip::{Collection, CollectionTo, Constructible, Iterable, Map, Sequence, SequenceTo};
 
 use crate::assert_equal;
 
@@ -34,6 +34,12 @@ impl<Item: Equal> Equal for [Item] {
   }
 }
 
+impl<Item: Equal> Equal for Box<[Item]> {
+  fn equal(&self, other: &Self) -> bool {
+    self.iter().zip(other.iter()).all(|(x, y)| x.equal(y))
+  }
+}
+
 impl<Item: Equal> Equal for LinkedList<Item> {
   fn equal(&self, other: &Self) -> bool {
     self.iter().zip(other.iter()).all(|(x, y)| x.equal(y))
@@ -87,7 +93,7 @@ impl<Key: PartialEq, Value: PartialEq> Equal for BTreeMap<Key, Value> {
 pub(crate) trait TestCollection<T>: FromIterator<T> + Default + Extend<T> + Clone + Equal + Debug {}
 
 pub(crate) trait TestCollectible<'a, T: 'a>:
-  CollectionTo<T> + TestCollection<T> + IntoIterator<Item = T> + Iterable<Item<'a> = &'a T>
+  CollectionTo<T> + Constructible + TestCollection<T> + IntoIterator<Item = T> + Iterable<Item<'a> = &'a T>
 where
   Self: 'a,
   for<'i> &'i Self: IntoIterator<Item = &'i T>,
@@ -129,7 +135,7 @@ impl<T, C> TestCollection<T> for C where C: FromIterator<T> + Default + Extend<T
 
 impl<'a, T: 'a, C> TestCollectible<'a, T> for C
 where
-  C: TestCollection<T> + CollectionTo<T> + IntoIterator<Item = T> + Iterable<Item<'a> = &'a T> + 'a,
+  C: TestCollection<T> + CollectionTo<T> + Constructible + IntoIterator<Item = T> + Iterable<Item<'a> = &'a T> + 'a,
   for<'i> &'i C: IntoIterator<Item = &'i T>,
 {
 }
@@ -1,12 +1,27 @@
 #![deny(warnings)]
 #![allow(missing_docs)]
 #![allow(unused_crate_dependencies)]
+use crate::extensions::array::test_array;
+use crate::extensions::boxed_slice::test_boxed_slice;
+use crate::extensions::collection::test_collection_float;
+use crate::extensions::collection_to::test_collection_to_float;
+use crate::extensions::map::test_map_float;
+use crate::extensions::option::test_option;
+use crate::extensions::result::test_result;
+use crate::extensions::sequence::test_sequence_statistics;
+use crate::extensions::sequence_to::test_sequence_to_float;
+use crate::extensions::text::test_text;
 use crate::extensions::traits::*;
-use cantrip::Collection;
+use crate::extensions::traversal::test_traversal;
+use cantrip::{Collection, CollectionTo, DiffHunk, LengthMismatch, Map, Sequence, SequenceTo};
 use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+use std::hash::{BuildHasherDefault, DefaultHasher};
 
 mod extensions;
 
+type FixedHashSet<T> = HashSet<T, BuildHasherDefault<DefaultHasher>>;
+type FixedHashMap<K, V> = HashMap<K, V, BuildHasherDefault<DefaultHasher>>;
+
 #[test]
 fn collectibles() {
   let a_hash_set = HashSet::from([1_i64, 2, 3]);
@@ -55,6 +70,176 @@ fn sequences() {
   test_list_traits(&a_linked_list, &b_linked_list, &c_linked_list, &d_linked_list, &g_linkeg_list, &e_linked_list);
 }
 
+#[test]
+fn sorted_sequences() {
+  let mut a_vec_deque = VecDeque::from([4_i64, 5, 5, 6]);
+  for x in [3, 2, 2, 1] {
+    a_vec_deque.push_front(x);
+  }
+  assert_eq!(a_vec_deque.as_slices().0.len(), 4);
+  assert_eq!(a_vec_deque.lower_bound(&2), 1);
+  assert_eq!(a_vec_deque.upper_bound(&5), 7);
+  assert_eq!(a_vec_deque.equal_range(&5), 5..7);
+  assert_eq!(a_vec_deque.binary_position_of(&4), Some(4));
+  assert_eq!(a_vec_deque.binary_position_by_key(&3, |&x| x), Some(3));
+  assert!(a_vec_deque.is_sorted());
+  assert_eq!(a_vec_deque.sorted_insert(3), VecDeque::from([1, 2, 2, 3, 3, 4, 5, 5, 6]));
+}
+
+#[test]
+fn subsequences() {
+  let a_vec = vec![1_i64, 2, 1, 3, 1, 1, 1, 2];
+  let a_vec_deque = VecDeque::from(a_vec.clone());
+  let a_linked_list = LinkedList::from_iter(a_vec.clone());
+  assert_eq!(a_vec.position_sequence(&vec![1, 3]), Some(2));
+  assert_eq!(a_vec_deque.position_sequence(&vec![1, 1, 2]), Some(5));
+  assert_eq!(a_linked_list.position_sequence(&vec![1, 1, 2]), Some(5));
+  assert_eq!(a_vec[..].position_sequence_multi(&vec![1, 1]), vec![4, 5]);
+  assert_eq!(a_vec_deque.position_sequence_multi(&vec![1]), vec![0, 2, 4, 5, 6]);
+  assert_eq!(a_linked_list.rposition_sequence(&vec![1, 2]), Some(6));
+  assert_eq!(a_vec_deque.rposition_sequence(&vec![2, 1, 3]), Some(1));
+  assert!(a_linked_list.contains_sequence(&vec![3, 1, 1]));
+  assert!(!a_vec.contains_sequence(&vec![1, 1, 1, 1]));
+  let b_vec = b"a\r\nbc\r\n\r\n".to_vec();
+  assert_eq!(b_vec.clone().divide_sequence(&vec![b'\r', b'\n']), vec![b"a".to_vec(), b"bc".to_vec(), vec![], vec![]]);
+  assert_eq!(b_vec.replace_sequence(&vec![b'\r', b'\n'], vec![b'\n']), b"a\nbc\n\n".to_vec());
+  let c_linked_list = LinkedList::from([1_i64, 1, 1, 1, 1]);
+  assert_eq!(c_linked_list.replace_sequence_n(&vec![1, 1], vec![0], 2), LinkedList::from([0, 0, 1]));
+}
+
+#[test]
+fn diffs() {
+  let values = (0..40_i64).map(|x| (x * 7 + 3) % 5).collect::<Vec<_>>();
+  for source_size in [0, 1, 7, 20, 40] {
+    for target_start in [0, 3, 11] {
+      let source = values[..source_size].to_vec();
+      let target = values[target_start..].iter().map(|x| x % 3).collect::<Vec<_>>();
+      let script = source.diff(&target);
+      let changed = script.iter().fold(0, |result, hunk| match hunk {
+        DiffHunk::Equal { .. } => result,
+        DiffHunk::Delete { length, .. } => result + length,
+        DiffHunk::Insert { elements, .. } => result + elements.len(),
+        DiffHunk::Replace { length, elements, .. } => result + length + elements.len(),
+      });
+      assert_eq!(changed, source.len() + target.len() - 2 * common_subsequence_length(&source, &target));
+      assert_eq!(VecDeque::from(source.clone()).apply_diff(&script), VecDeque::from(target.clone()));
+      assert_eq!(LinkedList::from_iter(source.clone()).diff(&target), script);
+      let pairs = source.longest_common_subsequence(&target);
+      assert_eq!(pairs.len(), common_subsequence_length(&source, &target));
+      assert!(pairs.iter().all(|&(i, j)| source[i] == target[j]));
+      assert!(pairs.windows(2).all(|pair| pair[0].0 < pair[1].0 && pair[0].1 < pair[1].1));
+      assert_eq!(source.levenshtein_distance(&target), edit_distance(&source, &target, false));
+      assert!(source.damerau_levenshtein_distance(&target) <= edit_distance(&source, &target, true));
+      assert_eq!(source.longest_increasing_subsequence().len(), increasing_subsequence_length(&source));
+      assert!(source.longest_increasing_subsequence().windows(2).all(|pair| source[pair[0]] < source[pair[1]]));
+      assert!(source.is_subsequence_of(&values));
+      assert_eq!(source.apply_diff(&script), target);
+    }
+  }
+  let source = (0..5000_i64).collect::<Vec<_>>();
+  let target = (5000..10000_i64).collect::<Vec<_>>();
+  let script = source.diff(&target);
+  assert_eq!(script.len(), 1);
+  assert_eq!(source.apply_diff(&script), target);
+}
+
+fn edit_distance(source: &[i64], target: &[i64], transpositions: bool) -> usize {
+  let mut distances = vec![vec![0_usize; target.len() + 1]; source.len() + 1];
+  for (i, row) in distances.iter_mut().enumerate() {
+    row[0] = i;
+  }
+  for (j, distance) in distances[0].iter_mut().enumerate() {
+    *distance = j;
+  }
+  for i in 1..=source.len() {
+    for j in 1..=target.len() {
+      let cost = usize::from(source[i - 1] != target[j - 1]);
+      let mut distance = (distances[i - 1][j - 1] + cost).min(distances[i - 1][j] + 1).min(distances[i][j - 1] + 1);
+      if transpositions && i > 1 && j > 1 && source[i - 1] == target[j - 2] && source[i - 2] == target[j - 1] {
+        distance = distance.min(distances[i - 2][j - 2] + 1);
+      }
+      distances[i][j] = distance;
+    }
+  }
+  distances[source.len()][target.len()]
+}
+
+fn increasing_subsequence_length(values: &[i64]) -> usize {
+  let mut lengths = vec![1_usize; values.len()];
+  for i in 0..values.len() {
+    for j in 0..i {
+      if values[j] < values[i] {
+        lengths[i] = lengths[i].max(lengths[j] + 1);
+      }
+    }
+  }
+  lengths.into_iter().max().unwrap_or(0)
+}
+
+fn common_subsequence_length(source: &[i64], target: &[i64]) -> usize {
+  let mut lengths = vec![vec![0_usize; target.len() + 1]; source.len() + 1];
+  for (i, x) in source.iter().enumerate() {
+    for (j, y) in target.iter().enumerate() {
+      lengths[i + 1][j + 1] = if x == y { lengths[i][j] + 1 } else { lengths[i][j + 1].max(lengths[i + 1][j]) };
+    }
+  }
+  lengths[source.len()][target.len()]
+}
+
+#[test]
+fn distances() {
+  let a_vec = vec!['c', 'a'];
+  let a_linked_list = LinkedList::from(['c', 'a']);
+  assert_eq!(a_vec.damerau_levenshtein_distance(&vec!['a', 'b', 'c']), 2);
+  assert_eq!(a_linked_list.damerau_levenshtein_distance(&vec!['a', 'b', 'c']), 2);
+  assert_eq!(a_vec.levenshtein_distance(&vec!['a', 'b', 'c']), 3);
+  assert_eq!(vec!['a', 'b', 'c', 'd'].damerau_levenshtein_distance(&vec!['b', 'a', 'd', 'c']), 2);
+  assert_eq!(VecDeque::from(['a', 'b', 'c']).hamming_distance(&vec!['a', 'c', 'b']), Ok(2));
+}
+
+#[test]
+fn combinatorics() {
+  let a_vec = (0_i64..70).collect::<Vec<_>>();
+  let b_vec = vec![0_i64, 1];
+  let c_vec = a_vec[..36].to_vec();
+  let d_vec = a_vec[..26].to_vec();
+  let f_vec = a_vec[..21].to_vec();
+  let combinations = a_vec.combinations_iter(35);
+  assert_eq!(combinations.size_hint(), (0, None));
+  assert_eq!(combinations.take(2).collect::<Vec<_>>().len(), 2);
+  let mut powerset = a_vec.powerset_iter();
+  assert_eq!(powerset.size_hint(), (0, None));
+  assert_eq!(powerset.next(), Some(vec![]));
+  assert_eq!(powerset.size_hint(), (0, None));
+  assert_eq!(powerset.map(|x| x.len()).take(2).collect::<Vec<_>>(), vec![1, 1]);
+  let mut powerset = b_vec.powerset_iter();
+  assert_eq!(powerset.size_hint(), (4, Some(4)));
+  assert_eq!(powerset.next(), Some(vec![]));
+  assert_eq!(powerset.size_hint(), (3, Some(3)));
+  let partitions = d_vec.partitions_iter();
+  assert_eq!(partitions.size_hint(), (0, None));
+  assert_eq!(partitions.take(2).count(), 2);
+  let product = b_vec.cartesian_product_iter(64);
+  assert_eq!(product.size_hint(), (0, None));
+  assert_eq!(product.take(2).collect::<Vec<_>>().len(), 2);
+  let combinations_multi = c_vec.combinations_multi_iter(35);
+  assert_eq!(combinations_multi.size_hint(), (0, None));
+  assert_eq!(combinations_multi.take(2).count(), 2);
+  let variations = f_vec.variations_iter(21);
+  assert_eq!(variations.size_hint(), (0, None));
+  assert_eq!(variations.take(2).collect::<Vec<_>>().len(), 2);
+}
+
+#[test]
+fn arrays() {
+  test_array();
+}
+
+#[test]
+fn boxed_slices() {
+  test_boxed_slice();
+}
+
 #[test]
 fn maps() {
   let a_hash_map = HashMap::from([(1_i64, 1_i64), (2, 2), (3, 3)]);
@@ -67,6 +252,110 @@ fn maps() {
   test_map_traits(&a_btree_map, &b_btree_map, &e_btree_map);
 }
 
+#[test]
+fn hashers() {
+  let a_set = FixedHashSet::from_iter([1_i64, 2, 3]);
+  let e_set = FixedHashSet::<i64>::default();
+  assert!(a_set.any(|&x| x > 2));
+  assert_eq!(a_set.clone().add(4), FixedHashSet::from_iter([1, 2, 3, 4]));
+  assert_eq!(a_set.clone().filter(|&x| x > 1), FixedHashSet::from_iter([2, 3]));
+  assert_eq!(a_set.map_ref(|&x| x + 1), FixedHashSet::from_iter([2, 3, 4]));
+  assert_eq!(e_set.clone().map(|x| x + 1), FixedHashSet::default());
+  let a_set = HashSet::unit(1);
+  assert_eq!(a_set, HashSet::from([1]));
+  let a_set = HashSet::fill_with(|| 1, 2);
+  assert_eq!(a_set, HashSet::from([1]));
+  let a_set = FixedHashSet::unit_with_hasher(1, BuildHasherDefault::default());
+  assert_eq!(a_set, FixedHashSet::from_iter([1]));
+  let a_set = FixedHashSet::fill_with_hasher(|| 1, 2, BuildHasherDefault::default());
+  assert_eq!(a_set, FixedHashSet::from_iter([1]));
+
+  let a_map = FixedHashMap::from_iter([(1_i64, 1_i64), (2, 2), (3, 3)]);
+  let e_map = FixedHashMap::<i64, i64>::default();
+  assert!(a_map.any(|(&k, _)| k > 2));
+  assert_eq!(a_map.clone().add(4, 4), FixedHashMap::from_iter([(1, 1), (2, 2), (3, 3), (4, 4)]));
+  assert_eq!(a_map.clone().filter(|(&k, _)| k > 1), FixedHashMap::from_iter([(2, 2), (3, 3)]));
+  assert_eq!(a_map.map_ref(|(&k, &v)| (k, v + 1)), FixedHashMap::from_iter([(1, 2), (2, 3), (3, 4)]));
+  assert_eq!(e_map.clone().map(|(k, v)| (k, v + 1)), FixedHashMap::default());
+  let a_map = HashMap::unit(1, 1);
+  assert_eq!(a_map, HashMap::from([(1, 1)]));
+  let a_map = HashMap::fill_with(|| (1, 1), 2);
+  assert_eq!(a_map, HashMap::from([(1, 1)]));
+  let a_map = FixedHashMap::unit_with_hasher(1, 1, BuildHasherDefault::default());
+  assert_eq!(a_map, FixedHashMap::from_iter([(1, 1)]));
+  let a_map = FixedHashMap::fill_with_hasher(|| (1, 1), 2, BuildHasherDefault::default());
+  assert_eq!(a_map, FixedHashMap::from_iter([(1, 1)]));
+}
+
+#[test]
+fn options() {
+  test_option();
+}
+
+#[test]
+fn results() {
+  test_result();
+}
+
+#[test]
+fn statistics() {
+  let a_vec = vec![1.0, 2.0, 3.0];
+  let b_vec = vec![1.0, 2.0, 2.0, 3.0];
+  let e_vec = Vec::<f64>::new();
+  test_sequence_statistics(&a_vec, &b_vec, &e_vec);
+  test_sequence_statistics(&VecDeque::from(a_vec.clone()), &VecDeque::from(b_vec.clone()), &VecDeque::new());
+  test_sequence_statistics(&LinkedList::from_iter(a_vec), &LinkedList::from_iter(b_vec), &LinkedList::new());
+  assert_eq!(vec![1_u64, 2, 3].dot(&vec![3, 2, 1]), Ok(10.0));
+  assert_eq!(vec![1_usize, 2, 3].covariance(&vec![2, 4, 6]), Ok(Some(2.0)));
+  assert_eq!(LengthMismatch { left: 3, right: 2 }.to_string(), "sequence lengths differ (left is 3, right is 2)");
+}
+
+#[test]
+fn floats() {
+  let a_vec = vec![2.0, 1.0, 3.0];
+  let n_vec = vec![2.0, f64::NAN, 1.0];
+  let e_vec = Vec::<f64>::new();
+  let a_deque = VecDeque::from(a_vec.clone());
+  let n_deque = VecDeque::from(n_vec.clone());
+  let e_deque = VecDeque::<f64>::new();
+  let a_list = LinkedList::from_iter(a_vec.clone());
+  let n_list = LinkedList::from_iter(n_vec.clone());
+  let e_list = LinkedList::<f64>::new();
+  test_collection_float(&a_vec, &n_vec, &e_vec);
+  test_collection_float(a_vec.as_slice(), n_vec.as_slice(), e_vec.as_slice());
+  test_collection_float(&a_deque, &n_deque, &e_deque);
+  test_collection_float(&a_list, &n_list, &e_list);
+  test_collection_to_float(&a_vec, &n_vec, &e_vec);
+  test_collection_to_float(&a_deque, &n_deque, &e_deque);
+  test_collection_to_float(&a_list, &n_list, &e_list);
+  test_sequence_to_float(&a_vec, &n_vec, &e_vec);
+  test_sequence_to_float(&a_deque, &n_deque, &e_deque);
+  test_sequence_to_float(&a_list, &n_list, &e_list);
+
+  let a_hash_map = HashMap::from([(1, 2.0), (2, 1.0), (3, 3.0)]);
+  let n_hash_map = HashMap::from([(1, 2.0), (2, f64::NAN), (3, 1.0)]);
+  let e_hash_map = HashMap::<i64, f64>::new();
+  test_map_float(&a_hash_map, &n_hash_map, &e_hash_map);
+  test_map_float(&BTreeMap::from_iter(a_hash_map), &BTreeMap::from_iter(n_hash_map), &BTreeMap::new());
+
+  // Arithmetic traits do not shadow operator methods
+  {
+    use std::ops::{Add, Sub};
+    assert_eq!(1.0_f64.add(2.0).total_cmp(&3.0), std::cmp::Ordering::Equal);
+    assert_eq!(3.0_f32.sub(2.0).total_cmp(&1.0), std::cmp::Ordering::Equal);
+  }
+}
+
+#[test]
+fn strings() {
+  test_text();
+}
+
+#[test]
+fn iterators() {
+  test_traversal();
+}
+
 #[test]
 fn x() {
   let a = vec![1, 2, 3];